@@ -3,21 +3,22 @@
 // This is where Godot integration happens. The BlockotNode:
 // - Owns the BlockotGeometry (source of truth)
 // - Rebuilds ArrayMesh when geometry is dirty
-// - Provides test methods for undo spike verification
+// - Owns the CommandRegistry used by undo/redo callbacks
 
 use godot::classes::mesh::ArrayType;
 use godot::classes::mesh::PrimitiveType;
 use godot::classes::notify::Node3DNotification;
 use godot::classes::{
-    ArrayMesh, Engine, IMeshInstance3D, ImmediateMesh, Material, MeshInstance3D, Object,
-    StandardMaterial3D,
+    ArrayMesh, IMeshInstance3D, ImmediateMesh, Material, MeshInstance3D, StandardMaterial3D,
 };
 use godot::prelude::*;
 
+use super::history::{execute_with_undo, CommandRegistry};
 use crate::geometry::primitives::unit_cube;
 use crate::geometry::serialization::{from_packed_arrays, to_packed_arrays};
-use crate::geometry::BlockotGeometry;
-use crate::selection::Selection;
+use crate::geometry::{BlockotGeometry, MeshTopology, TopologyCache};
+use crate::selection::{SelectOp, Selection, SelectionMode};
+use crate::tools::commands::MoveVertices;
 
 /// A custom node for blockout geometry editing.
 /// Extends MeshInstance3D and displays editable geometry.
@@ -32,6 +33,12 @@ pub struct BlockotNode {
     /// Current selection state (vertex-canonical model)
     selection: Selection,
 
+    /// Commands referenced by EditorUndoRedoManager actions (session-only)
+    command_registry: CommandRegistry,
+
+    /// Derived edge/face adjacency, rebuilt lazily from geometry
+    topology: TopologyCache,
+
     /// Cached default material
     #[var]
     default_material: Option<Gd<Material>>,
@@ -68,6 +75,8 @@ impl IMeshInstance3D for BlockotNode {
             base,
             geometry: BlockotGeometry::new(), // Start empty, load in ready()
             selection: Selection::default(),
+            command_registry: CommandRegistry::new(),
+            topology: TopologyCache::new(),
             default_material: None,
             is_in_edit_mode: false,
             handle_mesh_instance: None,
@@ -85,6 +94,8 @@ impl IMeshInstance3D for BlockotNode {
             self.geometry = unit_cube();
             self.sync_geometry_to_export(); // Populate export fields
         }
+        // Undo history is session-only: freshly loaded geometry invalidates old commands
+        self.command_registry.clear();
 
         self.setup_default_material();
         self.rebuild_array_mesh();
@@ -96,6 +107,9 @@ impl IMeshInstance3D for BlockotNode {
     }
 
     fn exit_tree(&mut self) {
+        // A transform preview never outlives the scene
+        self.geometry.cancel_preview();
+
         // Exit edit mode when node leaves the tree
         if self.is_in_edit_mode {
             self.is_in_edit_mode = false;
@@ -106,6 +120,11 @@ impl IMeshInstance3D for BlockotNode {
     fn on_notification(&mut self, what: Node3DNotification) {
         // Sync geometry to export fields before scene is saved
         if what == Node3DNotification::EDITOR_PRE_SAVE {
+            // Only committed geometry is saved; drop any transform preview
+            if self.geometry.is_previewing() {
+                self.geometry.cancel_preview();
+                self.refresh_after_geometry_change();
+            }
             self.sync_geometry_to_export();
         }
     }
@@ -132,66 +151,49 @@ impl BlockotNode {
     }
 
 
-    /// Test method for undo spike - moves a single vertex.
-    /// This is temporary for verification, will be removed/replaced in Epic 2.
+    /// Test method for undo verification - moves a single vertex.
+    /// Goes through the generic command path (MoveVertices + execute_with_undo).
     #[func]
     pub fn test_move_vertex(&mut self, index: i32, offset: Vector3) {
-        use godot::classes::EditorInterface;
-
-        let idx = index as usize;
-        if idx >= self.geometry.vertices.len() {
+        let cmd = match MoveVertices::new(vec![index as usize], offset) {
+            Ok(cmd) => cmd,
+            Err(err) => {
+                godot_error!("BlockotNode: {}", err);
+                return;
+            }
+        };
+        if cmd.validate_indices(&self.geometry).is_err() {
             godot_error!("Invalid vertex index: {}", index);
             return;
         }
 
-        // Try to use undo/redo in editor
-        if Engine::singleton().is_editor_hint() {
-            if let Some(mut undo_redo) = EditorInterface::singleton().get_editor_undo_redo() {
-                let action_name = GString::from("Move Vertex");
-                undo_redo.create_action(&action_name);
-
-                // Get self as object for method registration
-                let obj: Gd<Object> = self.base().clone().upcast();
-                let method_name = StringName::from("_apply_vertex_move");
-
-                // Register do method (apply the move)
-                undo_redo.add_do_method(
-                    &obj,
-                    &method_name,
-                    &[index.to_variant(), offset.to_variant()],
-                );
-
-                // Register undo method (reverse the move)
-                undo_redo.add_undo_method(
-                    &obj,
-                    &method_name,
-                    &[index.to_variant(), (-offset).to_variant()],
-                );
-
-                // Commit WITHOUT executing (execute=false) to avoid borrow conflict
-                // We'll apply the change ourselves after registering
-                undo_redo.commit_action_ex().execute(false).done();
-
-                godot_print!("Registered undo action: Move Vertex {}", index);
-            }
-        }
-
-        // Apply the move directly (either as fallback or after undo registration)
-        self.geometry.vertices[idx] += offset;
-        self.geometry.dirty = true;
-        self.rebuild_array_mesh();
+        execute_with_undo(self, cmd);
         godot_print!("Applied vertex move: {} by {:?}", index, offset);
     }
 
-    /// Internal method called by undo/redo system to apply vertex movement.
+    /// Internal method called by the undo/redo system to re-apply a registered command.
     #[func]
-    pub fn _apply_vertex_move(&mut self, index: i32, offset: Vector3) {
-        let idx = index as usize;
-        if idx < self.geometry.vertices.len() {
-            self.geometry.vertices[idx] += offset;
-            self.geometry.dirty = true;
-            self.rebuild_array_mesh();
-            godot_print!("Applied vertex move: {} by {:?}", index, offset);
+    pub fn _redo_command(&mut self, id: i64) {
+        if self.command_registry.redo(id, &mut self.geometry) {
+            self.command_registry
+                .swap_selection(id, &mut self.selection);
+            self.prune_selection();
+            self.refresh_after_geometry_change();
+        } else {
+            godot_warn!("BlockotNode: No registered command with id {} to redo", id);
+        }
+    }
+
+    /// Internal method called by the undo/redo system to revert a registered command.
+    #[func]
+    pub fn _undo_command(&mut self, id: i64) {
+        if self.command_registry.undo(id, &mut self.geometry) {
+            self.command_registry
+                .swap_selection(id, &mut self.selection);
+            self.prune_selection();
+            self.refresh_after_geometry_change();
+        } else {
+            godot_warn!("BlockotNode: No registered command with id {} to undo", id);
         }
     }
 
@@ -210,7 +212,8 @@ impl BlockotNode {
             return;
         }
         self.is_in_edit_mode = true;
-        self.selection.clear();
+        // Fresh selection in Vertex mode, matching EditModeState::enter_edit_mode
+        self.selection = Selection::default();
         self.show_vertex_handles();
         self.base_mut()
             .emit_signal("edit_mode_entered", &[]);
@@ -223,6 +226,10 @@ impl BlockotNode {
             return;
         }
         self.is_in_edit_mode = false;
+        if self.geometry.is_previewing() {
+            self.geometry.cancel_preview();
+            self.rebuild_array_mesh();
+        }
         self.selection.clear();
         self.hide_vertex_handles();
         self.base_mut()
@@ -230,6 +237,37 @@ impl BlockotNode {
         godot_print!("BlockotNode: Exited edit mode");
     }
 
+    /// Switch selection mode, converting the selection hints and refreshing handles.
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        let topology = self.topology.get(&self.geometry);
+        self.selection.set_mode(mode, &self.geometry, topology);
+        self.refresh_vertex_handles();
+    }
+
+    /// Select every vertex and refresh the handles.
+    pub fn select_all(&mut self) {
+        let topology = self.topology.get(&self.geometry);
+        self.selection.select_all(&self.geometry, topology);
+        self.refresh_vertex_handles();
+    }
+
+    /// Apply a face pick to the selection (needs the face's vertices).
+    pub fn pick_face(&mut self, face_index: usize, op: SelectOp) {
+        self.selection.pick_face(face_index, &self.geometry, op);
+    }
+
+    /// Recompute edge/face rendering hints from the selected vertices.
+    pub fn update_selection_hints(&mut self) {
+        let topology = self.topology.get(&self.geometry);
+        self.selection.update_hints(&self.geometry, topology);
+    }
+
+    /// Drop selected vertices that no longer exist (undo/redo can shrink the geometry).
+    fn prune_selection(&mut self) {
+        let topology = self.topology.get(&self.geometry);
+        self.selection.retain_valid(&self.geometry, topology);
+    }
+
     /// Get read access to the selection state.
     pub fn selection(&self) -> &Selection {
         &self.selection
@@ -240,41 +278,98 @@ impl BlockotNode {
         &mut self.selection
     }
 
-    /// Create and show vertex handles as small crosses at each vertex position.
-    /// Selected vertices are drawn in white at 1.5x size, unselected in orange.
+    /// Create and show selection handles for the current selection mode:
+    /// crosses at vertices (Vertex), all edges as lines (Edge), or crosses at
+    /// face centres (Face). Selected elements are white, unselected orange.
+    /// Selected edges are drawn as white lines, selected faces as a translucent overlay.
     fn show_vertex_handles(&mut self) {
         self.hide_vertex_handles(); // Clean up any existing handles
 
+        // Handles follow the transform preview while a modal tool runs
+        let vertices = self.geometry.render_vertices().into_owned();
+
         let mut immediate_mesh = ImmediateMesh::new_gd();
 
-        // Draw vertex points using small cross shapes for visibility
         immediate_mesh.surface_begin(PrimitiveType::LINES);
 
         let unselected_color = Color::from_rgb(1.0, 0.5, 0.0); // Orange
         let selected_color = Color::from_rgb(1.0, 1.0, 1.0); // White
         let base_handle_size = 0.03_f32;
 
-        for (i, vertex) in self.geometry.vertices.iter().enumerate() {
-            let is_selected = self.selection.vertex_indices.contains(&i);
-            let color = if is_selected { selected_color } else { unselected_color };
-            let handle_size = if is_selected { base_handle_size * 1.5 } else { base_handle_size };
-
-            immediate_mesh.surface_set_color(color);
-
-            // Draw a small 3D cross at each vertex
-            // X axis
-            immediate_mesh.surface_add_vertex(*vertex + Vector3::new(-handle_size, 0.0, 0.0));
-            immediate_mesh.surface_add_vertex(*vertex + Vector3::new(handle_size, 0.0, 0.0));
-            // Y axis
-            immediate_mesh.surface_add_vertex(*vertex + Vector3::new(0.0, -handle_size, 0.0));
-            immediate_mesh.surface_add_vertex(*vertex + Vector3::new(0.0, handle_size, 0.0));
-            // Z axis
-            immediate_mesh.surface_add_vertex(*vertex + Vector3::new(0.0, 0.0, -handle_size));
-            immediate_mesh.surface_add_vertex(*vertex + Vector3::new(0.0, 0.0, handle_size));
+        match self.selection.mode {
+            SelectionMode::Vertex => {
+                // Draw vertex points using small cross shapes for visibility
+                for (i, vertex) in vertices.iter().enumerate() {
+                    let is_selected = self.selection.vertex_indices.contains(&i);
+                    let (color, handle_size) = if is_selected {
+                        (selected_color, base_handle_size * 1.5)
+                    } else {
+                        (unselected_color, base_handle_size)
+                    };
+
+                    immediate_mesh.surface_set_color(color);
+                    add_cross(&mut immediate_mesh, *vertex, handle_size);
+                }
+            }
+            SelectionMode::Edge => {
+                // Draw every edge so the pickable elements are visible
+                let edges = self.topology.get(&self.geometry).edges().to_vec();
+                immediate_mesh.surface_set_color(unselected_color);
+                for (a, b) in edges {
+                    immediate_mesh.surface_add_vertex(vertices[a]);
+                    immediate_mesh.surface_add_vertex(vertices[b]);
+                }
+            }
+            SelectionMode::Face => {
+                // Draw a cross at each face centre
+                for (i, face) in self.geometry.faces.iter().enumerate() {
+                    let corners: Vec<Vector3> = face
+                        .vertex_indices
+                        .iter()
+                        .filter_map(|&v| vertices.get(v).copied())
+                        .collect();
+                    if corners.is_empty() {
+                        continue;
+                    }
+                    let centre = corners.iter().fold(Vector3::ZERO, |acc, v| acc + *v)
+                        / corners.len() as f32;
+
+                    let is_selected = self.selection.selected_faces.contains(&i);
+                    let (color, handle_size) = if is_selected {
+                        (selected_color, base_handle_size * 1.5)
+                    } else {
+                        (unselected_color, base_handle_size)
+                    };
+
+                    immediate_mesh.surface_set_color(color);
+                    add_cross(&mut immediate_mesh, centre, handle_size);
+                }
+            }
+        }
+
+        // Highlight selected edges (Edge mode rendering hint)
+        immediate_mesh.surface_set_color(selected_color);
+        for &(a, b) in &self.selection.selected_edges {
+            if let (Some(&va), Some(&vb)) = (vertices.get(a), vertices.get(b)) {
+                immediate_mesh.surface_add_vertex(va);
+                immediate_mesh.surface_add_vertex(vb);
+            }
         }
 
         immediate_mesh.surface_end();
 
+        // Translucent overlay on selected faces (Face mode rendering hint).
+        // Same fan triangulation as rebuild_array_mesh so culling matches the mesh.
+        let overlay_triangles = self.selected_face_triangles(&vertices);
+        if !overlay_triangles.is_empty() {
+            immediate_mesh.surface_begin(PrimitiveType::TRIANGLES);
+            immediate_mesh.surface_set_color(Color::from_rgba(1.0, 0.5, 0.0, 0.3));
+            for vertex in overlay_triangles {
+                immediate_mesh.surface_add_vertex(vertex);
+            }
+            immediate_mesh.surface_end();
+        }
+
         // Create a MeshInstance3D child to display the handles
         let mut mesh_instance = MeshInstance3D::new_alloc();
         mesh_instance.set_mesh(&immediate_mesh);
@@ -292,12 +387,33 @@ impl BlockotNode {
             godot::classes::base_material_3d::Flags::DISABLE_DEPTH_TEST,
             true,
         );
+        // Alpha blending for the face overlay (handles and edges stay opaque)
+        handle_material.set_transparency(godot::classes::base_material_3d::Transparency::ALPHA);
         mesh_instance.set_material_override(&handle_material.upcast::<Material>());
 
         self.base_mut().add_child(&mesh_instance);
         self.handle_mesh_instance = Some(mesh_instance);
     }
 
+    /// Fan-triangulated vertex positions of the selected faces, three per triangle.
+    fn selected_face_triangles(&self, vertices: &[Vector3]) -> Vec<Vector3> {
+        let mut triangles = Vec::new();
+        for &face_idx in &self.selection.selected_faces {
+            let Some(face) = self.geometry.faces.get(face_idx) else {
+                continue;
+            };
+            let corners: Vec<Vector3> = face
+                .vertex_indices
+                .iter()
+                .filter_map(|&i| vertices.get(i).copied())
+                .collect();
+            for i in 1..corners.len().saturating_sub(1) {
+                triangles.extend_from_slice(&[corners[0], corners[i], corners[i + 1]]);
+            }
+        }
+        triangles
+    }
+
     /// Rebuild vertex handles to reflect current selection state.
     /// Call this after any selection change.
     pub fn refresh_vertex_handles(&mut self) {
@@ -320,9 +436,10 @@ impl BlockotNode {
         self.default_material = Some(material.upcast());
     }
 
-    /// Rebuild the ArrayMesh from the current geometry.
+    /// Rebuild the ArrayMesh from the current geometry (including any preview).
     /// Called when geometry.dirty is true.
     pub fn rebuild_array_mesh(&mut self) {
+        let source = self.geometry.render_vertices().into_owned();
         let mut vertices = PackedVector3Array::new();
         let mut normals = PackedVector3Array::new();
         let mut indices = PackedInt32Array::new();
@@ -335,19 +452,19 @@ impl BlockotNode {
             }
 
             // Calculate flat normal for the face
-            let normal = self.calculate_face_normal(face);
+            let normal = face.normal(&source);
 
             // Triangulate the face (works for triangles, quads, and n-gons)
             // Fan triangulation: (0, 1, 2), (0, 2, 3), (0, 3, 4), ...
             let first_idx = face.vertex_indices[0];
-            let first_vertex = self.geometry.vertices[first_idx];
+            let first_vertex = source[first_idx];
 
             for i in 1..(face.vertex_indices.len() - 1) {
                 let idx1 = face.vertex_indices[i];
                 let idx2 = face.vertex_indices[i + 1];
 
-                let v1 = self.geometry.vertices[idx1];
-                let v2 = self.geometry.vertices[idx2];
+                let v1 = source[idx1];
+                let v2 = source[idx2];
 
                 // Add three vertices for this triangle (with flat shading normals)
                 vertices.push(first_vertex);
@@ -388,34 +505,23 @@ impl BlockotNode {
         }
 
         self.base_mut().set_mesh(&mesh);
+        // Clearing dirty consumes the change, so cached topology must go too
+        self.topology.invalidate();
         self.geometry.dirty = false;
     }
 
-    /// Calculate the flat normal for a face.
-    /// Uses counter-clockwise winding convention (when viewed from outside).
-    fn calculate_face_normal(&self, face: &crate::geometry::Face) -> Vector3 {
-        if face.vertex_indices.len() < 3 {
-            return Vector3::UP;
-        }
-
-        let v0 = self.geometry.vertices[face.vertex_indices[0]];
-        let v1 = self.geometry.vertices[face.vertex_indices[1]];
-        let v2 = self.geometry.vertices[face.vertex_indices[2]];
-
-        let edge1 = v1 - v0;
-        let edge2 = v2 - v0;
-
-        // Cross product order: edge2 × edge1 for outward-facing normals
-        // with counter-clockwise vertex winding
-        let cross = edge2.cross(edge1);
-
-        // Handle degenerate case (collinear vertices)
-        let length_sq = cross.length_squared();
-        if length_sq < 1e-10 {
-            return Vector3::UP;
+    /// Rebuild the mesh (if geometry changed) and the vertex handles.
+    /// Call this after a command has mutated the geometry.
+    pub fn refresh_after_geometry_change(&mut self) {
+        if self.geometry.dirty {
+            self.rebuild_array_mesh();
         }
+        self.refresh_vertex_handles();
+    }
 
-        cross / length_sq.sqrt()
+    /// Get mutable access to the command registry (for undo/redo registration).
+    pub fn command_registry_mut(&mut self) -> &mut CommandRegistry {
+        &mut self.command_registry
     }
 
     /// Get mutable access to geometry (for commands).
@@ -428,6 +534,18 @@ impl BlockotNode {
         &self.geometry
     }
 
+    /// Get up-to-date edge/face adjacency for the geometry.
+    pub fn topology(&mut self) -> &MeshTopology {
+        self.topology.get(&self.geometry)
+    }
+
+    /// Get the geometry together with its up-to-date topology, for commands
+    /// that need both.
+    pub fn geometry_and_topology(&mut self) -> (&BlockotGeometry, &MeshTopology) {
+        let topology = self.topology.get(&self.geometry);
+        (&self.geometry, topology)
+    }
+
     /// Sync internal geometry to export fields (called before save).
     /// This populates the #[export] fields that get saved to .tscn files.
     fn sync_geometry_to_export(&mut self) {
@@ -460,3 +578,16 @@ impl BlockotNode {
         }
     }
 }
+
+/// Add a small axis-aligned 3D cross (three line segments) to a LINES surface.
+fn add_cross(mesh: &mut Gd<ImmediateMesh>, center: Vector3, size: f32) {
+    // X axis
+    mesh.surface_add_vertex(center + Vector3::new(-size, 0.0, 0.0));
+    mesh.surface_add_vertex(center + Vector3::new(size, 0.0, 0.0));
+    // Y axis
+    mesh.surface_add_vertex(center + Vector3::new(0.0, -size, 0.0));
+    mesh.surface_add_vertex(center + Vector3::new(0.0, size, 0.0));
+    // Z axis
+    mesh.surface_add_vertex(center + Vector3::new(0.0, 0.0, -size));
+    mesh.surface_add_vertex(center + Vector3::new(0.0, 0.0, size));
+}
@@ -0,0 +1,165 @@
+// editor/cut_tool.rs - Modal knife/bisect cut (C)
+//
+// C starts a cut on the node in edit mode. The first click anchors a line
+// that follows the mouse; the second click cuts along the plane through that
+// screen-space line and the view direction (tools/commands/cut_faces.rs),
+// as one undoable command. Clicking on the surface therefore cuts straight
+// through the clicked points. Only the faces seen under the line are cut
+// (the selected ones among them, when faces are selected), so walls hidden
+// behind them are left alone. Holding Shift on the second click bisects
+// instead: every face the plane crosses is cut (every selected face, when
+// faces are selected), seen or not. The cut vertices become the selection.
+// Esc/RMB cancels; nothing is changed until the second click.
+
+use godot::classes::{
+    Camera3D, InputEvent, InputEventKey, InputEventMouseButton, InputEventMouseMotion,
+};
+use godot::global::{Key, MouseButton};
+use godot::prelude::*;
+
+use super::blockot_node::BlockotNode;
+use super::history::execute_with_undo;
+use super::input_handler::ModalOutcome;
+use crate::selection::SelectOp;
+use crate::tools::commands::{faces_under_line, CutFaces, CutPlane};
+
+/// Shortest cut line that is applied, in pixels.
+const MIN_CUT_LENGTH_PX: f32 = 4.0;
+
+/// A running cut: waiting for the first click, then for the second.
+#[derive(Debug, Clone, Copy)]
+pub struct CutTool {
+    /// Where the line starts, once the first click happened
+    start: Option<Vector2>,
+    /// Latest mouse position, the line's free end
+    mouse: Vector2,
+}
+
+impl CutTool {
+    /// Start a cut with the mouse at `mouse`.
+    pub fn new(mouse: Vector2) -> Self {
+        Self { start: None, mouse }
+    }
+
+    /// The cut line on screen, once its start is placed.
+    pub fn line(&self) -> Option<(Vector2, Vector2)> {
+        self.start.map(|start| (start, self.mouse))
+    }
+
+    /// Feed an input event to the cut.
+    pub fn handle_input(
+        &mut self,
+        node: &mut BlockotNode,
+        camera: &Gd<Camera3D>,
+        event: &Gd<InputEvent>,
+    ) -> ModalOutcome {
+        if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
+            self.mouse = motion.get_position();
+            // Motion is shared with the editor so viewport navigation keeps working
+            return ModalOutcome::Ignored;
+        }
+
+        if let Ok(mb) = event.clone().try_cast::<InputEventMouseButton>() {
+            let button = mb.get_button_index();
+            if button != MouseButton::LEFT && button != MouseButton::RIGHT {
+                return ModalOutcome::Ignored;
+            }
+            if !mb.is_pressed() {
+                return ModalOutcome::Running;
+            }
+            if button == MouseButton::RIGHT {
+                return ModalOutcome::Finished;
+            }
+            self.mouse = mb.get_position();
+            let Some(start) = self.start else {
+                self.start = Some(self.mouse);
+                return ModalOutcome::Running;
+            };
+            apply_cut(node, camera, start, self.mouse, mb.is_shift_pressed());
+            return ModalOutcome::Finished;
+        }
+
+        if let Ok(key) = event.clone().try_cast::<InputEventKey>() {
+            if key.is_pressed() && key.get_keycode() == Key::ESCAPE {
+                return ModalOutcome::Finished;
+            }
+            // Other keys are swallowed while the cut runs
+            return ModalOutcome::Running;
+        }
+
+        ModalOutcome::Ignored
+    }
+}
+
+/// Cut along the plane through the screen line `from`-`to` and the view
+/// direction, then select the cut. Cuts the faces seen under the line, or
+/// with `bisect` every face the plane crosses.
+fn apply_cut(
+    node: &mut BlockotNode,
+    camera: &Gd<Camera3D>,
+    from: Vector2,
+    to: Vector2,
+    bisect: bool,
+) {
+    if from.distance_to(to) < MIN_CUT_LENGTH_PX {
+        godot_print!("BlockotPlugin: Cut line too short");
+        return;
+    }
+
+    let world_to_local = node.base().get_global_transform().affine_inverse();
+    let ray = |point: Vector2| {
+        (
+            world_to_local * camera.project_ray_origin(point),
+            world_to_local.basis * camera.project_ray_normal(point),
+        )
+    };
+    let ((origin_a, direction_a), (origin_b, direction_b)) = (ray(from), ray(to));
+    let Some(plane) = CutPlane::through_rays(origin_a, direction_a, origin_b, direction_b) else {
+        return;
+    };
+
+    node.update_selection_hints();
+    let selected = &node.selection().selected_faces;
+    let faces = if bisect && selected.is_empty() {
+        (0..node.geometry().faces.len()).collect()
+    } else if bisect {
+        selected.clone()
+    } else {
+        // One ray per pixel along the line finds every face at least a pixel wide
+        let samples = from.distance_to(to).ceil() as usize;
+        let mut faces = faces_under_line(
+            node.geometry(),
+            origin_a,
+            direction_a,
+            origin_b,
+            direction_b,
+            samples,
+        );
+        if !selected.is_empty() {
+            faces.retain(|f| selected.contains(f));
+        }
+        faces
+    };
+    if faces.is_empty() {
+        godot_print!("BlockotPlugin: Cut line misses the mesh");
+        return;
+    }
+    let command = match CutFaces::new(node.geometry(), faces, plane) {
+        Ok(command) => command,
+        Err(e) => {
+            godot_warn!("BlockotPlugin: cut not applied: {}", e);
+            return;
+        }
+    };
+    if command.split_count() == 0 {
+        godot_print!("BlockotPlugin: Cut line misses the mesh");
+        return;
+    }
+
+    let cut_vertices = command.cut_vertices().to_vec();
+    execute_with_undo(node, command);
+    node.selection_mut()
+        .pick_vertices(&cut_vertices, SelectOp::Replace);
+    node.update_selection_hints();
+    node.refresh_vertex_handles();
+}
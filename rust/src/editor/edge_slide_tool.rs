@@ -0,0 +1,137 @@
+// editor/edge_slide_tool.rs - Modal edge slide (Shift+V)
+//
+// Shift+V slides the selected edge loops along their adjacent edges
+// (tools/commands/edge_slide.rs). Dragging the mouse along the on-screen
+// direction of a rail sets the slide factor, shown as a geometry preview;
+// LMB/Enter confirms as one undoable command and Esc/RMB cancels.
+
+use std::collections::HashMap;
+
+use godot::classes::{
+    Camera3D, InputEvent, InputEventKey, InputEventMouseButton, InputEventMouseMotion,
+};
+use godot::global::{Key, MouseButton};
+use godot::prelude::*;
+
+use super::blockot_node::BlockotNode;
+use super::history::execute_with_undo;
+use super::input_handler::ModalOutcome;
+use crate::tools::commands::EdgeSlide;
+
+/// Shortest on-screen rail that still gives a stable factor, in pixels.
+const MIN_RAIL_LENGTH_PX: f32 = 2.0;
+
+/// A running edge slide.
+pub struct EdgeSlideTool {
+    /// The slide, its factor following the mouse
+    slide: EdgeSlide,
+    /// Mouse position when the tool started
+    start_mouse: Vector2,
+}
+
+impl EdgeSlideTool {
+    /// Start sliding the node's selected edge loops.
+    /// Returns None (and starts nothing) when no selected edge can slide.
+    pub fn begin(node: &mut BlockotNode, mouse: Vector2) -> Option<Self> {
+        let mut vertices: Vec<usize> = node.selection().vertex_indices.iter().copied().collect();
+        vertices.sort_unstable();
+        let (geo, topology) = node.geometry_and_topology();
+        let slide = EdgeSlide::new(geo, topology, &vertices, 0.0).ok()?;
+
+        node.geometry_mut().begin_preview(slide.vertices().to_vec());
+        node.refresh_after_geometry_change();
+        Some(Self {
+            slide,
+            start_mouse: mouse,
+        })
+    }
+
+    /// Feed an input event to the slide.
+    pub fn handle_input(
+        &mut self,
+        node: &mut BlockotNode,
+        camera: &Gd<Camera3D>,
+        event: &Gd<InputEvent>,
+    ) -> ModalOutcome {
+        if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
+            self.update_preview(node, camera, motion.get_position());
+            // Motion is shared with the editor so viewport navigation keeps working
+            return ModalOutcome::Ignored;
+        }
+
+        if let Ok(mb) = event.clone().try_cast::<InputEventMouseButton>() {
+            let button = mb.get_button_index();
+            if button != MouseButton::LEFT && button != MouseButton::RIGHT {
+                return ModalOutcome::Ignored;
+            }
+            if !mb.is_pressed() {
+                return ModalOutcome::Running;
+            }
+            if button == MouseButton::LEFT {
+                self.confirm(node);
+            } else {
+                self.cancel(node);
+            }
+            return ModalOutcome::Finished;
+        }
+
+        if let Ok(key) = event.clone().try_cast::<InputEventKey>() {
+            if !key.is_pressed() || key.is_echo() {
+                return ModalOutcome::Running;
+            }
+            match key.get_keycode() {
+                Key::ESCAPE => {
+                    self.cancel(node);
+                    return ModalOutcome::Finished;
+                }
+                Key::ENTER | Key::KP_ENTER => {
+                    self.confirm(node);
+                    return ModalOutcome::Finished;
+                }
+                // Other keys are swallowed while the tool runs
+                _ => return ModalOutcome::Running,
+            }
+        }
+
+        ModalOutcome::Ignored
+    }
+
+    /// Abort the slide: drop the preview, leaving the geometry as it was.
+    pub fn cancel(&self, node: &mut BlockotNode) {
+        node.geometry_mut().cancel_preview();
+        node.refresh_after_geometry_change();
+    }
+
+    /// Commit the previewed slide as one undoable command.
+    fn confirm(&self, node: &mut BlockotNode) {
+        node.geometry_mut().cancel_preview();
+        if self.slide.factor() == 0.0 {
+            node.refresh_after_geometry_change();
+            return;
+        }
+        execute_with_undo(node, self.slide.clone());
+    }
+
+    /// Turn the mouse travel along the reference rail into a factor and preview it.
+    fn update_preview(&mut self, node: &mut BlockotNode, camera: &Gd<Camera3D>, mouse: Vector2) {
+        let Some((origin, end)) = self.slide.reference_rail() else {
+            return;
+        };
+        let global = node.base().get_global_transform();
+        let (origin, end) = (global * origin, global * end);
+        if camera.is_position_behind(origin) || camera.is_position_behind(end) {
+            return;
+        }
+        let rail = camera.unproject_position(end) - camera.unproject_position(origin);
+        if rail.length() < MIN_RAIL_LENGTH_PX {
+            return;
+        }
+
+        self.slide
+            .set_factor((mouse - self.start_mouse).dot(rail) / rail.length_squared());
+        let targets: HashMap<usize, Vector3> = self.slide.targets().collect();
+        node.geometry_mut()
+            .update_preview(|index, position| targets.get(&index).copied().unwrap_or(position));
+        node.refresh_after_geometry_change();
+    }
+}
@@ -53,6 +53,14 @@ impl EditModeState {
         };
     }
 
+    /// Change the selection mode of the active edit session.
+    /// Does nothing when edit mode is inactive.
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        if let EditModeState::Active { selection_mode, .. } = self {
+            *selection_mode = mode;
+        }
+    }
+
     /// Exit edit mode, returning to Inactive.
     pub fn exit_edit_mode(&mut self) {
         *self = EditModeState::Inactive;
@@ -161,6 +169,26 @@ mod tests {
         assert_eq!(state.selection_mode(), Some(SelectionMode::Vertex));
     }
 
+    #[test]
+    fn test_set_selection_mode() {
+        let mut state = EditModeState::default();
+        state.enter_edit_mode(42);
+
+        state.set_selection_mode(SelectionMode::Edge);
+        assert_eq!(state.selection_mode(), Some(SelectionMode::Edge));
+        assert_eq!(state.active_node_id(), Some(42));
+
+        state.set_selection_mode(SelectionMode::Face);
+        assert_eq!(state.selection_mode(), Some(SelectionMode::Face));
+    }
+
+    #[test]
+    fn test_set_selection_mode_inactive_is_noop() {
+        let mut state = EditModeState::default();
+        state.set_selection_mode(SelectionMode::Face);
+        assert_eq!(state, EditModeState::Inactive);
+    }
+
     #[test]
     fn test_toggle_returns_previous_state() {
         let mut state = EditModeState::default();
@@ -3,88 +3,292 @@
 // Bridges the Command trait to Godot's undo system.
 // Commands are executed immediately, then registered with EditorUndoRedoManager.
 //
-// NOTE: This module provides scaffolding for the undo integration pattern.
-// The actual undo spike for Story 1.2 is implemented directly in BlockotNode::test_move_vertex()
-// which demonstrates the working pattern. This module will be fully implemented in later stories
-// when we need generic command undo support.
+// Godot's undo system can only call methods on Objects with Variant arguments,
+// so commands are kept in a per-node CommandRegistry keyed by a numeric id.
+// The undo action calls BlockotNode::_undo_command(id) / _redo_command(id),
+// which look the command up and replay it on the node's geometry.
+//
+// Each entry also keeps the selected vertices from the other side of the
+// command, swapped with the live selection on every undo/redo, so undoing a
+// command that removed or renumbered vertices brings the old selection back.
+//
+// Committing a new action discards whatever was undone before it, so the
+// registry drops its undone commands whenever one is registered; outside
+// the editor nothing can undo a command, so none is stored.
+// History is session-only: the registry lives on the BlockotNode and starts
+// empty whenever the scene is (re)loaded.
+// [Source: architecture.md#External-Modification-Pattern]
 
+use std::collections::{HashMap, HashSet};
+
+use godot::classes::{EditorInterface, Engine, Object};
 use godot::prelude::*;
 
 use crate::editor::BlockotNode;
 use crate::geometry::BlockotGeometry;
+use crate::selection::Selection;
 use crate::tools::Command;
 
-/// Execute a command on geometry with undo/redo support.
+/// Stores executed commands so Godot's undo callbacks can replay them by id.
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: HashMap<i64, Box<dyn Command>>,
+    /// Selected vertices on the other side of each command (before it while
+    /// applied, after it while undone)
+    selections: HashMap<i64, HashSet<usize>>,
+    /// Commands currently undone, discarded once a new one is registered
+    undone: HashSet<i64>,
+    next_id: i64,
+}
+
+impl CommandRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a command and return the id used to reference it from undo callbacks.
+    /// Drops the commands currently undone: the new action replaces them in
+    /// the undo history, so they can no longer be redone.
+    pub fn register(&mut self, cmd: Box<dyn Command>) -> i64 {
+        for id in self.undone.drain() {
+            self.commands.remove(&id);
+            self.selections.remove(&id);
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.commands.insert(id, cmd);
+        id
+    }
+
+    /// Re-execute the command with the given id. Returns false if the id is unknown.
+    pub fn redo(&mut self, id: i64, geo: &mut BlockotGeometry) -> bool {
+        match self.commands.get(&id) {
+            Some(cmd) => {
+                cmd.execute(geo);
+                self.undone.remove(&id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Undo the command with the given id. Returns false if the id is unknown.
+    pub fn undo(&mut self, id: i64, geo: &mut BlockotGeometry) -> bool {
+        match self.commands.get(&id) {
+            Some(cmd) => {
+                cmd.undo(geo);
+                self.undone.insert(id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remember the vertices selected before the command with the given id ran.
+    pub fn remember_selection(&mut self, id: i64, vertices: HashSet<usize>) {
+        if self.commands.contains_key(&id) {
+            self.selections.insert(id, vertices);
+        }
+    }
+
+    /// Exchange the selected vertices with the ones remembered for the command
+    /// with the given id. Called on every undo/redo, so each call lands on the
+    /// selection from the other side of the command. No-op if none is stored.
+    pub fn swap_selection(&mut self, id: i64, selection: &mut Selection) {
+        if let Some(stored) = self.selections.get_mut(&id) {
+            *stored = selection.replace_vertices(std::mem::take(stored));
+        }
+    }
+
+    /// Returns the name of the command with the given id, if registered.
+    pub fn name(&self, id: i64) -> Option<&'static str> {
+        self.commands.get(&id).map(|cmd| cmd.name())
+    }
+
+    /// Returns the number of registered commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if no commands are registered.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Drop all registered commands (scene reload invalidates history).
+    pub fn clear(&mut self) {
+        self.commands.clear();
+        self.selections.clear();
+        self.undone.clear();
+    }
+}
+
+/// Execute a command on a node's geometry with undo/redo support.
 ///
 /// This function:
-/// 1. Optionally validates the command against the geometry
-/// 2. Executes the command immediately on the geometry
-/// 3. Registers the action with Godot's EditorUndoRedoManager (when available)
+/// 1. Executes the command immediately on the geometry and rebuilds the mesh
+/// 2. Stores the command in the node's `CommandRegistry`, along with the
+///    selection from before it so undo can restore it
+/// 3. Registers `_redo_command` / `_undo_command` with Godot's EditorUndoRedoManager
 ///
-/// # Warning
-/// This is scaffolding for future implementation. The undo registration is incomplete.
-/// For Story 1.2, use `BlockotNode::test_move_vertex()` which has working undo support.
+/// Outside the editor (no undo manager available) the command is still executed,
+/// just without an undo entry, and is not kept.
+///
+/// Callers should validate the command against the geometry before calling this,
+/// since execution is infallible and skips invalid indices silently.
 ///
 /// # Arguments
 /// * `node` - The BlockotNode containing the geometry
 /// * `cmd` - The command to execute
 pub fn execute_with_undo<C: Command + 'static>(node: &mut BlockotNode, cmd: C) {
+    execute_boxed_with_undo(node, Box::new(cmd));
+}
+
+/// Same as `execute_with_undo`, for commands only known as `Box<dyn Command>`
+/// (e.g. a tool that builds a single command or a composite depending on input).
+pub fn execute_boxed_with_undo(node: &mut BlockotNode, cmd: Box<dyn Command>) {
+    let action_name = GString::from(cmd.name());
+
     // Execute immediately on the geometry
+    let selection = node.selection().vertex_indices.clone();
     cmd.execute(node.geometry_mut());
+    node.refresh_after_geometry_change();
+
+    if !Engine::singleton().is_editor_hint() {
+        return;
+    }
+    let Some(mut undo_redo) = EditorInterface::singleton().get_editor_undo_redo() else {
+        return;
+    };
+    let id = node.command_registry_mut().register(cmd);
+    node.command_registry_mut()
+        .remember_selection(id, selection);
+
+    undo_redo.create_action(&action_name);
 
-    godot_warn!(
-        "execute_with_undo: Command '{}' executed, but undo registration is incomplete. \
-         Use BlockotNode::test_move_vertex() for working undo support.",
-        cmd.name()
-    );
+    let obj: Gd<Object> = node.base().clone().upcast();
+    undo_redo.add_do_method(&obj, &StringName::from("_redo_command"), &[id.to_variant()]);
+    undo_redo.add_undo_method(&obj, &StringName::from("_undo_command"), &[id.to_variant()]);
+
+    // Commit WITHOUT executing (execute=false): the command already ran above,
+    // and executing here would re-enter the node while it is mutably bound.
+    undo_redo.commit_action_ex().execute(false).done();
 }
 
 /// Execute a command directly on geometry without undo support.
 /// Use this for testing or when undo is not needed.
-pub fn execute_without_undo<C: Command>(geo: &mut BlockotGeometry, cmd: &C) {
+pub fn execute_without_undo<C: Command + ?Sized>(geo: &mut BlockotGeometry, cmd: &C) {
     cmd.execute(geo);
 }
 
 /// Undo a command directly on geometry.
 /// Use this for testing or direct undo operations.
-pub fn undo_command<C: Command>(geo: &mut BlockotGeometry, cmd: &C) {
+pub fn undo_command<C: Command + ?Sized>(geo: &mut BlockotGeometry, cmd: &C) {
     cmd.undo(geo);
 }
 
-// =============================================================================
-// FUTURE IMPLEMENTATION NOTES
-// =============================================================================
-//
-// Full undo/redo implementation requires storing commands in a way that Godot's
-// callable system can access. The pattern would be:
-//
-// ```ignore
-// use std::sync::Mutex;
-// use std::collections::HashMap;
-//
-// static COMMAND_REGISTRY: Lazy<Mutex<HashMap<u64, Box<dyn Command>>>> = ...;
-//
-// pub fn execute_with_undo(
-//     undo_redo: Gd<EditorUndoRedoManager>,
-//     node: &mut BlockotNode,
-//     cmd: impl Command + 'static,
-// ) {
-//     let id = next_command_id();
-//     COMMAND_REGISTRY.lock().unwrap().insert(id, Box::new(cmd.clone()));
-//
-//     cmd.execute(node.geometry_mut());
-//
-//     let action_name = GString::from(cmd.name());
-//     undo_redo.create_action(&action_name);
-//
-//     // Register do/undo callbacks that retrieve command from registry
-//     undo_redo.add_do_method(/* callable referencing id */);
-//     undo_redo.add_undo_method(/* callable referencing id */);
-//
-//     undo_redo.commit_action_ex().execute(false).done();
-// }
-// ```
-//
-// The working pattern is demonstrated in BlockotNode::test_move_vertex() which uses
-// object methods (_apply_vertex_move) as callbacks instead of a command registry.
-// =============================================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::SelectOp;
+    use crate::test_utils::unit_cube;
+    use crate::tools::commands::MoveVertices;
+
+    #[test]
+    fn test_registry_assigns_unique_ids() {
+        let mut registry = CommandRegistry::new();
+        let cmd = MoveVertices::new(vec![0], Vector3::new(1.0, 0.0, 0.0)).unwrap();
+
+        let first = registry.register(Box::new(cmd.clone()));
+        let second = registry.register(Box::new(cmd));
+
+        assert_ne!(first, second);
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn test_registry_undo_redo_roundtrip() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+        let mut registry = CommandRegistry::new();
+
+        let cmd = MoveVertices::new(vec![0, 1], Vector3::new(0.0, 2.0, 0.0)).unwrap();
+        cmd.execute(&mut geo);
+        let moved = geo.clone();
+        let id = registry.register(Box::new(cmd));
+
+        assert!(registry.undo(id, &mut geo));
+        assert_eq!(geo, original);
+
+        assert!(registry.redo(id, &mut geo));
+        assert_eq!(geo, moved);
+    }
+
+    #[test]
+    fn test_registry_unknown_id_is_noop() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+        let mut registry = CommandRegistry::new();
+
+        assert!(!registry.undo(42, &mut geo));
+        assert!(!registry.redo(42, &mut geo));
+        assert_eq!(geo, original);
+        assert_eq!(registry.name(42), None);
+    }
+
+    #[test]
+    fn test_registry_swaps_selection() {
+        let mut registry = CommandRegistry::new();
+        let id = registry.register(Box::new(MoveVertices::new(vec![0], Vector3::ZERO).unwrap()));
+        registry.remember_selection(id, HashSet::from([0, 1]));
+
+        // Undo brings back the selection from before, redo the one after
+        let mut selection = Selection::default();
+        selection.pick_vertex(5, SelectOp::Replace);
+        registry.swap_selection(id, &mut selection);
+        assert_eq!(selection.vertex_indices, HashSet::from([0, 1]));
+        registry.swap_selection(id, &mut selection);
+        assert_eq!(selection.vertex_indices, HashSet::from([5]));
+
+        // Unknown ids leave the selection alone
+        registry.remember_selection(42, HashSet::new());
+        registry.swap_selection(42, &mut selection);
+        assert_eq!(selection.vertex_indices, HashSet::from([5]));
+    }
+
+    #[test]
+    fn test_registry_drops_undone_commands() {
+        let mut geo = unit_cube();
+        let mut registry = CommandRegistry::new();
+        let cmd = MoveVertices::new(vec![0], Vector3::new(1.0, 0.0, 0.0)).unwrap();
+        let kept = registry.register(Box::new(cmd.clone()));
+        let redone = registry.register(Box::new(cmd.clone()));
+        let undone = registry.register(Box::new(cmd.clone()));
+        registry.remember_selection(undone, HashSet::from([0]));
+        assert!(registry.undo(undone, &mut geo));
+        assert!(registry.undo(redone, &mut geo));
+        assert!(registry.redo(redone, &mut geo));
+
+        // A new action replaces the undone one in Godot's history
+        let new = registry.register(Box::new(cmd));
+
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.name(undone), None);
+        assert!(!registry.selections.contains_key(&undone));
+        for id in [kept, redone, new] {
+            assert_eq!(registry.name(id), Some("Move Vertices"));
+        }
+    }
+
+    #[test]
+    fn test_registry_clear() {
+        let mut registry = CommandRegistry::new();
+        let id = registry.register(Box::new(MoveVertices::new(vec![0], Vector3::ZERO).unwrap()));
+        assert_eq!(registry.name(id), Some("Move Vertices"));
+
+        registry.clear();
+        assert!(registry.is_empty());
+        assert_eq!(registry.name(id), None);
+    }
+}
@@ -0,0 +1,683 @@
+// editor/input_handler.rs - Modal transform drag/preview coordination
+//
+// Runs the Blender-style modal tools on the node in edit mode. G (move),
+// R (rotate) and S (scale) start a tool: mouse motion drives a preview on the
+// geometry (committed vertices are untouched), X/Y/Z lock an axis, Shift+X/Y/Z
+// excludes one, typed digits set an exact value, LMB/Enter confirms as one
+// undoable command and Esc/RMB cancels. Holding Ctrl while rotating snaps
+// the angle.
+//
+// Tools work in world space; the result is converted to the node's local
+// space before it is previewed or committed. Rotate and scale are centred on
+// the pivot chosen in TransformSettings (see tools/pivot.rs); individual
+// origins commit one command per pivot group, bundled as a CompositeCommand.
+// Axis constraints follow the chosen orientation (tools/orientation.rs); the
+// constrained world-space result is always converted back through the node's
+// inverse global transform, so local offsets match what was shown.
+// Dragged amounts snap per TransformSettings::snap (tools/snap.rs): Ctrl
+// forces snapping on and Shift bypasses it while held. Moves also snap onto
+// nearby vertices/edges/faces of this and other BlockotNodes (proximity.rs);
+// the candidates are collected once when the move starts.
+// E extrudes the selected faces and runs a move locked to their normal, or
+// extrudes selected open boundary edges into quads and moves them freely;
+// the extrude and the move commit together as one undo step, and cancelling
+// reverts the extrude too.
+//
+// [Source: architecture.md#Preview-State-Pattern]
+
+use std::collections::{HashMap, HashSet};
+
+use godot::classes::{
+    Camera3D, EditorInterface, InputEvent, InputEventKey, InputEventMouseButton,
+    InputEventMouseMotion,
+};
+use godot::global::{Key, MouseButton};
+use godot::prelude::*;
+
+use super::blockot_node::BlockotNode;
+use super::history::execute_boxed_with_undo;
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, Edge};
+use crate::selection::{SelectOp, Selection};
+use crate::tools::commands::{
+    CompositeCommand, ExtrudeEdges, ExtrudeFaces, MoveVertices, RotateVertices, ScaleVertices,
+};
+use crate::tools::modal::{Axis, AxisConstraint, MoveTool, NumericInput, RotateTool, ScaleTool};
+use crate::tools::orientation::{orientation_basis, selection_normal, TransformOrientation};
+use crate::tools::pivot::{median, pivot_groups, PivotGroup, PivotMode};
+use crate::tools::proximity::{SnapIndex, SnapTarget, FACE_SNAP_DISTANCE_PX, SNAP_DISTANCE_PX};
+use crate::tools::snap::SnapSettings;
+use crate::tools::Command;
+
+/// Minimum mouse distance from the pivot on screen for a stable rotate/scale, in pixels.
+const PIVOT_MIN_RADIUS_PX: f32 = 4.0;
+
+/// Plugin-level settings shared by all modal transform tools.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransformSettings {
+    /// Where rotate/scale are centred
+    pub pivot_mode: PivotMode,
+    /// 3D cursor position in world space
+    pub cursor: Vector3,
+    /// Axes that X/Y/Z constraints follow
+    pub orientation: TransformOrientation,
+    /// Grid and increment snapping
+    pub snap: SnapSettings,
+}
+
+/// What a modal transform did with an input event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalOutcome {
+    /// Event consumed, the tool keeps running
+    Running,
+    /// Event not used by the tool (camera navigation etc.)
+    Ignored,
+    /// The tool confirmed or cancelled and is done
+    Finished,
+}
+
+/// Which modal tool is running, with its pure-Rust state.
+#[derive(Debug, Clone)]
+enum Tool {
+    Move(MoveTool),
+    Rotate(RotateTool),
+    Scale(ScaleTool),
+}
+
+impl Tool {
+    fn constraint_mut(&mut self) -> &mut AxisConstraint {
+        match self {
+            Tool::Move(tool) => &mut tool.constraint,
+            Tool::Rotate(tool) => &mut tool.constraint,
+            Tool::Scale(tool) => &mut tool.constraint,
+        }
+    }
+
+    fn numeric_mut(&mut self) -> &mut NumericInput {
+        match self {
+            Tool::Move(tool) => &mut tool.numeric,
+            Tool::Rotate(tool) => &mut tool.numeric,
+            Tool::Scale(tool) => &mut tool.numeric,
+        }
+    }
+}
+
+/// The tool's current result in the node's local space (pivots are per group).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LocalTransform {
+    Translate(Vector3),
+    Rotate(Basis),
+    Scale(Basis),
+}
+
+impl LocalTransform {
+    fn apply(self, v: Vector3, pivot: Vector3) -> Vector3 {
+        match self {
+            LocalTransform::Translate(offset) => v + offset,
+            LocalTransform::Rotate(rotation) => pivot + rotation * (v - pivot),
+            LocalTransform::Scale(scale) => pivot + scale * (v - pivot),
+        }
+    }
+
+    fn is_identity(self) -> bool {
+        match self {
+            LocalTransform::Translate(offset) => offset == Vector3::ZERO,
+            LocalTransform::Rotate(basis) | LocalTransform::Scale(basis) => {
+                basis == Basis::IDENTITY
+            }
+        }
+    }
+
+    /// Build the undoable command for this transform over the pivot groups.
+    fn into_command(
+        self,
+        geo: &BlockotGeometry,
+        groups: &[PivotGroup],
+        indices: &[usize],
+    ) -> Result<Box<dyn Command>, BlockotError> {
+        let (name, mut commands): (&'static str, Vec<Box<dyn Command>>) = match self {
+            LocalTransform::Translate(offset) => {
+                return Ok(Box::new(MoveVertices::new(indices.to_vec(), offset)?));
+            }
+            LocalTransform::Rotate(rotation) => (
+                "Rotate Vertices",
+                groups
+                    .iter()
+                    .map(|g| {
+                        RotateVertices::new(geo, g.indices.clone(), g.pivot, rotation)
+                            .map(|cmd| Box::new(cmd) as Box<dyn Command>)
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            LocalTransform::Scale(scale) => (
+                "Scale Vertices",
+                groups
+                    .iter()
+                    .map(|g| {
+                        ScaleVertices::with_basis(geo, g.indices.clone(), g.pivot, scale)
+                            .map(|cmd| Box::new(cmd) as Box<dyn Command>)
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+        };
+        if commands.len() == 1 {
+            return Ok(commands.remove(0));
+        }
+        Ok(Box::new(CompositeCommand::new(name, commands)?))
+    }
+}
+
+/// Topology change applied before the tool started (e.g. an extrude).
+/// It commits together with the transform, or is reverted on cancel.
+struct PendingCommand {
+    command: Box<dyn Command>,
+    /// Selection before the command ran, restored on cancel
+    selection: Selection,
+}
+
+/// A running modal transform on the node in edit mode.
+pub struct ModalTransform {
+    tool: Tool,
+    /// Vertices being transformed (the selection, sorted)
+    indices: Vec<usize>,
+    /// Selection split by pivot, pivots in the node's local space
+    groups: Vec<PivotGroup>,
+    /// Local-space pivot of each transformed vertex
+    vertex_pivots: HashMap<usize, Vector3>,
+    /// Overall pivot in world space; drag plane and on-screen rotation centre
+    anchor: Vector3,
+    /// Axes that X/Y/Z constraints follow
+    orientation: TransformOrientation,
+    /// Averaged normal of the selection in world space (Normal orientation)
+    normal: Option<Vector3>,
+    /// Snapping configuration captured when the tool started
+    snap: SnapSettings,
+    /// Ctrl held (forces snapping)
+    ctrl: bool,
+    /// Shift held (bypasses snapping)
+    shift: bool,
+    /// Proximity snap candidates (moves only)
+    snap_index: Option<SnapIndex>,
+    /// Camera transform the snap candidates were last projected with
+    snap_camera: Option<Transform3D>,
+    /// Current proximity snap target, drawn by the plugin
+    snap_target: Option<SnapTarget>,
+    /// Mouse position when the tool started
+    start_mouse: Vector2,
+    /// Latest mouse position
+    mouse: Vector2,
+    /// Result of the latest update, committed on confirm
+    current: LocalTransform,
+    /// Already-applied command the transform belongs to
+    pending: Option<PendingCommand>,
+}
+
+impl ModalTransform {
+    /// Start a modal move (G) of the node's selected vertices.
+    /// Returns None (and starts nothing) when the selection is empty.
+    pub fn begin_move(
+        node: &mut BlockotNode,
+        mouse: Vector2,
+        settings: &TransformSettings,
+    ) -> Option<Self> {
+        Self::begin(node, mouse, settings, Tool::Move(MoveTool::new()))
+    }
+
+    /// Start a modal rotate (R) of the node's selected vertices around the pivot.
+    /// Returns None (and starts nothing) when the selection is empty.
+    pub fn begin_rotate(
+        node: &mut BlockotNode,
+        mouse: Vector2,
+        settings: &TransformSettings,
+    ) -> Option<Self> {
+        Self::begin(node, mouse, settings, Tool::Rotate(RotateTool::new()))
+    }
+
+    /// Start a modal scale (S) of the node's selected vertices around the pivot.
+    /// Returns None (and starts nothing) when the selection is empty.
+    pub fn begin_scale(
+        node: &mut BlockotNode,
+        mouse: Vector2,
+        settings: &TransformSettings,
+    ) -> Option<Self> {
+        Self::begin(node, mouse, settings, Tool::Scale(ScaleTool::new()))
+    }
+
+    /// Extrude the selection (E) and start moving the new geometry: whole
+    /// faces move along their normal, open boundary edges move freely.
+    /// Returns None (and changes nothing) when nothing extrudable is selected.
+    pub fn begin_extrude(
+        node: &mut BlockotNode,
+        mouse: Vector2,
+        settings: &TransformSettings,
+    ) -> Option<Self> {
+        node.update_selection_hints();
+        let selection = node.selection().clone();
+        let mut settings = *settings;
+        // The new geometry starts on top of what it came from; snapping onto that would pin it
+        settings.snap.proximity = false;
+        let mut tool = MoveTool::new();
+
+        let (command, new_vertices): (Box<dyn Command>, Vec<usize>) =
+            if !selection.selected_faces.is_empty() {
+                let command =
+                    ExtrudeFaces::new(node.geometry(), selection.selected_faces.clone(), 0.0)
+                        .ok()?;
+                tool.constraint = AxisConstraint::Axis(Axis::Z);
+                settings.orientation = TransformOrientation::Normal;
+                let cap = command.cap_vertices();
+                (Box::new(command), cap)
+            } else {
+                let topology = node.topology().clone();
+                let edges: Vec<Edge> = selection
+                    .selected_edges
+                    .iter()
+                    .copied()
+                    .filter(|&(a, b)| topology.is_boundary_edge(a, b))
+                    .collect();
+                let command =
+                    ExtrudeEdges::new(node.geometry(), &topology, edges, Vector3::ZERO).ok()?;
+                let copies = command.new_vertices().collect();
+                (Box::new(command), copies)
+            };
+
+        command.execute(node.geometry_mut());
+        node.selection_mut()
+            .pick_vertices(&new_vertices, SelectOp::Replace);
+        node.update_selection_hints();
+
+        let pending = PendingCommand { command, selection };
+        match Self::begin(node, mouse, &settings, Tool::Move(tool)) {
+            Some(mut modal) => {
+                modal.pending = Some(pending);
+                Some(modal)
+            }
+            None => {
+                pending.revert(node);
+                None
+            }
+        }
+    }
+
+    fn begin(
+        node: &mut BlockotNode,
+        mouse: Vector2,
+        settings: &TransformSettings,
+        tool: Tool,
+    ) -> Option<Self> {
+        let global = node.base().get_global_transform();
+        let cursor = global.affine_inverse() * settings.cursor;
+        let groups = pivot_groups(
+            settings.pivot_mode,
+            node.selection(),
+            node.geometry(),
+            cursor,
+        );
+        if groups.is_empty() {
+            return None;
+        }
+
+        let mut indices: Vec<usize> = groups.iter().flat_map(|g| g.indices.clone()).collect();
+        indices.sort_unstable();
+        let vertex_pivots = groups
+            .iter()
+            .flat_map(|g| g.indices.iter().map(|&i| (i, g.pivot)))
+            .collect();
+        let overall_pivot = match groups.as_slice() {
+            [single] => single.pivot,
+            _ => median(node.geometry(), &indices),
+        };
+        let anchor = global * overall_pivot;
+        // Normals transform with the inverse transpose to stay perpendicular under scale
+        let normal = selection_normal(node.selection(), node.geometry())
+            .map(|n| global.basis.inverse().transposed() * n);
+
+        let snap_index = (matches!(tool, Tool::Move(_)) && settings.snap.proximity)
+            .then(|| build_snap_index(node, &indices));
+
+        node.geometry_mut().begin_preview(indices.clone());
+        node.refresh_after_geometry_change();
+
+        Some(Self {
+            tool,
+            indices,
+            groups,
+            vertex_pivots,
+            anchor,
+            orientation: settings.orientation,
+            normal,
+            snap: settings.snap,
+            ctrl: false,
+            shift: false,
+            snap_index,
+            snap_camera: None,
+            snap_target: None,
+            start_mouse: mouse,
+            mouse,
+            current: LocalTransform::Translate(Vector3::ZERO),
+            pending: None,
+        })
+    }
+
+    /// Feed an input event to the running tool.
+    pub fn handle_input(
+        &mut self,
+        node: &mut BlockotNode,
+        camera: &Gd<Camera3D>,
+        event: &Gd<InputEvent>,
+    ) -> ModalOutcome {
+        if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
+            self.mouse = motion.get_position();
+            self.ctrl = motion.is_ctrl_pressed();
+            self.shift = motion.is_shift_pressed();
+            self.update_preview(node, camera);
+            // Motion is shared with the editor so viewport navigation keeps working
+            return ModalOutcome::Ignored;
+        }
+
+        if let Ok(mb) = event.clone().try_cast::<InputEventMouseButton>() {
+            let button = mb.get_button_index();
+            if button != MouseButton::LEFT && button != MouseButton::RIGHT {
+                return ModalOutcome::Ignored;
+            }
+            if !mb.is_pressed() {
+                return ModalOutcome::Running;
+            }
+            if button == MouseButton::LEFT {
+                self.confirm(node);
+            } else {
+                self.cancel(node);
+            }
+            return ModalOutcome::Finished;
+        }
+
+        if let Ok(key) = event.clone().try_cast::<InputEventKey>() {
+            let modifier = match key.get_keycode() {
+                Key::CTRL => Some(&mut self.ctrl),
+                Key::SHIFT => Some(&mut self.shift),
+                _ => None,
+            };
+            if let Some(held) = modifier {
+                // Snap state changes without waiting for the next mouse motion
+                *held = key.is_pressed();
+                self.update_preview(node, camera);
+                return ModalOutcome::Running;
+            }
+            if !key.is_pressed() || key.is_echo() {
+                return ModalOutcome::Running;
+            }
+            match key.get_keycode() {
+                Key::ESCAPE => {
+                    self.cancel(node);
+                    return ModalOutcome::Finished;
+                }
+                Key::ENTER | Key::KP_ENTER => {
+                    self.confirm(node);
+                    return ModalOutcome::Finished;
+                }
+                Key::X => self.toggle_axis(Axis::X, key.is_shift_pressed()),
+                Key::Y => self.toggle_axis(Axis::Y, key.is_shift_pressed()),
+                Key::Z => self.toggle_axis(Axis::Z, key.is_shift_pressed()),
+                Key::BACKSPACE => self.tool.numeric_mut().backspace(),
+                _ => {
+                    let typed = char::from_u32(key.get_unicode());
+                    if !typed.is_some_and(|c| self.tool.numeric_mut().push(c)) {
+                        // Other keys are swallowed while the tool runs
+                        return ModalOutcome::Running;
+                    }
+                }
+            }
+            self.update_preview(node, camera);
+            return ModalOutcome::Running;
+        }
+
+        ModalOutcome::Ignored
+    }
+
+    /// The proximity snap target the move currently sticks to, if any.
+    pub fn snap_target(&self) -> Option<SnapTarget> {
+        self.snap_target
+    }
+
+    /// Abort the tool: drop the preview, leaving the geometry as it was.
+    pub fn cancel(&self, node: &mut BlockotNode) {
+        node.geometry_mut().cancel_preview();
+        if let Some(pending) = &self.pending {
+            pending.revert(node);
+        }
+        node.refresh_after_geometry_change();
+    }
+
+    /// X/Y/Z locks an axis; with Shift it excludes the axis instead.
+    fn toggle_axis(&mut self, axis: Axis, exclude: bool) {
+        let constraint = self.tool.constraint_mut();
+        *constraint = if exclude {
+            constraint.toggled_exclude(axis)
+        } else {
+            constraint.toggled(axis)
+        };
+    }
+
+    /// Commit the previewed transform as one undoable command, together with
+    /// the pending command it belongs to.
+    fn confirm(&self, node: &mut BlockotNode) {
+        node.geometry_mut().cancel_preview();
+
+        let transform = if self.current.is_identity() {
+            None
+        } else {
+            match self
+                .current
+                .into_command(node.geometry(), &self.groups, &self.indices)
+            {
+                Ok(cmd) => Some(cmd),
+                Err(e) => {
+                    godot_warn!("BlockotPlugin: transform not applied: {}", e);
+                    self.cancel(node);
+                    return;
+                }
+            }
+        };
+
+        let mut commands: Vec<Box<dyn Command>> =
+            self.pending.iter().map(|p| p.command.clone()).collect();
+        commands.extend(transform);
+        let command: Box<dyn Command> = match commands.len() {
+            0 => {
+                node.refresh_after_geometry_change();
+                return;
+            }
+            1 => commands.remove(0),
+            _ => {
+                let name = commands[0].name();
+                match CompositeCommand::new(name, commands) {
+                    Ok(composite) => Box::new(composite),
+                    Err(e) => {
+                        godot_warn!("BlockotPlugin: transform not applied: {}", e);
+                        self.cancel(node);
+                        return;
+                    }
+                }
+            }
+        };
+        // The pending command is already applied; revert it so the undo
+        // system re-runs it together with the transform as one step. The
+        // selection from before it is put back meanwhile, so undo restores it.
+        let selected = self.pending.as_ref().map(|pending| {
+            pending.command.undo(node.geometry_mut());
+            node.selection_mut()
+                .replace_vertices(pending.selection.vertex_indices.clone())
+        });
+        execute_boxed_with_undo(node, command);
+        if let Some(selected) = selected {
+            node.selection_mut().replace_vertices(selected);
+            node.update_selection_hints();
+            node.refresh_vertex_handles();
+        }
+    }
+
+    /// Recompute the tool's result from the mouse and show it as a preview
+    /// when it changed.
+    fn update_preview(&mut self, node: &mut BlockotNode, camera: &Gd<Camera3D>) {
+        let global = node.base().get_global_transform();
+        let world_to_local = global.affine_inverse();
+        let view_basis = camera.get_global_transform().basis;
+        let orientation =
+            orientation_basis(self.orientation, global.basis, view_basis, self.normal);
+        let snapping = self.snap.is_active(self.ctrl, self.shift);
+
+        let current = match &mut self.tool {
+            Tool::Move(tool) => {
+                tool.orientation = orientation;
+                tool.snap = snapping.then_some(self.snap.grid_size);
+                tool.anchor = self.anchor;
+                let start = view_plane_point(camera, self.start_mouse, self.anchor);
+                let current = view_plane_point(camera, self.mouse, self.anchor);
+                self.snap_target = match (self.snap_index.as_mut(), current) {
+                    (Some(index), Some(dragged)) if snapping => {
+                        find_snap_target(index, &mut self.snap_camera, camera, self.mouse, dragged)
+                    }
+                    _ => None,
+                };
+                tool.target = self.snap_target.map(|target| target.position);
+                if let (Some(start), Some(current)) = (start, current) {
+                    tool.set_drag_offset(current - start);
+                }
+                LocalTransform::Translate(world_to_local.basis * tool.offset())
+            }
+            Tool::Rotate(tool) => {
+                tool.orientation = orientation;
+                tool.snap = snapping.then_some(self.snap.rotation_step_degrees);
+                let pivot_on_screen = camera.unproject_position(self.anchor);
+                let d = self.mouse - pivot_on_screen;
+                if d.length() > PIVOT_MIN_RADIUS_PX {
+                    // Screen y points down; flip it so the angle is counter-clockwise
+                    tool.drag_to((-d.y).atan2(d.x));
+                }
+                let view_axis = view_basis.col_c();
+                let world_rotation = tool.rotation(view_axis);
+                LocalTransform::Rotate(world_to_local.basis * world_rotation * global.basis)
+            }
+            Tool::Scale(tool) => {
+                tool.orientation = orientation;
+                tool.snap = snapping.then_some(self.snap.scale_step);
+                let pivot_on_screen = camera.unproject_position(self.anchor);
+                let start_radius = self.start_mouse.distance_to(pivot_on_screen);
+                if start_radius > PIVOT_MIN_RADIUS_PX {
+                    tool.set_drag_factor(self.mouse.distance_to(pivot_on_screen) / start_radius);
+                }
+                LocalTransform::Scale(world_to_local.basis * tool.scale_basis() * global.basis)
+            }
+        };
+        // Snapped motion often lands on the same result; skip the rebuild then
+        if current == self.current {
+            return;
+        }
+        self.current = current;
+
+        let vertex_pivots = &self.vertex_pivots;
+        node.geometry_mut()
+            .update_preview(|i, v| current.apply(v, vertex_pivots[&i]));
+        node.refresh_after_geometry_change();
+    }
+}
+
+impl PendingCommand {
+    /// Undo the command and restore the selection it replaced.
+    fn revert(&self, node: &mut BlockotNode) {
+        self.command.undo(node.geometry_mut());
+        *node.selection_mut() = self.selection.clone();
+        node.update_selection_hints();
+        node.refresh_after_geometry_change();
+    }
+}
+
+/// Collect proximity snap candidates in world space: the node's own geometry
+/// without the `moving` vertices, and every other visible BlockotNode in the
+/// edited scene.
+fn build_snap_index(node: &BlockotNode, moving: &[usize]) -> SnapIndex {
+    let mut index = SnapIndex::new();
+    add_snap_mesh(&mut index, node, &moving.iter().copied().collect());
+
+    let Some(root) = EditorInterface::singleton().get_edited_scene_root() else {
+        return index;
+    };
+    let own_id = node.base().instance_id();
+    let mut candidates = root.find_children_ex("*").type_("BlockotNode").done();
+    candidates.push(&root);
+
+    for other in candidates.iter_shared() {
+        if other.instance_id() == own_id {
+            continue;
+        }
+        let Ok(other) = other.try_cast::<BlockotNode>() else {
+            continue;
+        };
+        let other = other.bind();
+        if other.base().is_visible_in_tree() {
+            add_snap_mesh(&mut index, &other, &HashSet::new());
+        }
+    }
+    index
+}
+
+/// Add a node's geometry to the snap index, converted to world space.
+fn add_snap_mesh(index: &mut SnapIndex, node: &BlockotNode, excluded: &HashSet<usize>) {
+    let global = node.base().get_global_transform();
+    let geo = node.geometry();
+    let world: Vec<Vector3> = geo.vertices.iter().map(|v| global * *v).collect();
+    index.add_mesh(&world, &geo.faces, excluded);
+}
+
+/// Find the proximity snap target under the mouse, reprojecting the
+/// candidates when the camera has moved since `projected_with`. `dragged` is
+/// the unsnapped point under the mouse at the anchor's depth; faces are only
+/// snapped to near it.
+fn find_snap_target(
+    index: &mut SnapIndex,
+    projected_with: &mut Option<Transform3D>,
+    camera: &Gd<Camera3D>,
+    mouse: Vector2,
+    dragged: Vector3,
+) -> Option<SnapTarget> {
+    let camera_transform = camera.get_global_transform();
+    if *projected_with != Some(camera_transform) {
+        index.project(|p| (!camera.is_position_behind(p)).then(|| camera.unproject_position(p)));
+        *projected_with = Some(camera_transform);
+    }
+
+    let origin = camera.project_ray_origin(mouse);
+    let direction = camera.project_ray_normal(mouse);
+    // Faces count within FACE_SNAP_DISTANCE_PX, measured at the dragged point
+    let beside = mouse + Vector2::new(FACE_SNAP_DISTANCE_PX, 0.0);
+    let face_range =
+        view_plane_point(camera, beside, dragged).map_or(0.0, |p| p.distance_to(dragged));
+    index.find_target(
+        mouse,
+        origin,
+        direction,
+        SNAP_DISTANCE_PX,
+        dragged,
+        face_range,
+    )
+}
+
+/// Intersect the camera ray under the mouse with the view-aligned plane through `anchor`.
+///
+/// Returns None if the ray runs parallel to the plane.
+pub(super) fn view_plane_point(
+    camera: &Gd<Camera3D>,
+    mouse: Vector2,
+    anchor: Vector3,
+) -> Option<Vector3> {
+    let origin = camera.project_ray_origin(mouse);
+    let direction = camera.project_ray_normal(mouse);
+    let normal = camera.get_global_transform().basis.col_c();
+
+    let denom = direction.dot(normal);
+    if denom.abs() < 1e-6 {
+        return None;
+    }
+    let t = (anchor - origin).dot(normal) / denom;
+    Some(origin + direction * t)
+}
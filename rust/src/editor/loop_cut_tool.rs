@@ -0,0 +1,166 @@
+// editor/loop_cut_tool.rs - Modal loop cut (Ctrl+R)
+//
+// Ctrl+R starts a loop cut on the node in edit mode. Hovering an edge
+// previews the new loop through its edge ring (tools/commands/loop_cut.rs)
+// as an overlay, placed where the mouse sits along the edge; the scroll
+// wheel adds or removes evenly spaced cuts. LMB applies the cut as one
+// undoable command and selects the new loops; Esc/RMB cancels. The mesh is
+// only changed on confirm.
+
+use godot::classes::{
+    Camera3D, InputEvent, InputEventKey, InputEventMouseButton, InputEventMouseMotion,
+};
+use godot::global::{Key, MouseButton};
+use godot::prelude::*;
+
+use super::blockot_node::BlockotNode;
+use super::history::execute_with_undo;
+use super::input_handler::ModalOutcome;
+use super::plugin::project_vertices;
+use crate::selection::{find_closest_edge, SelectOp};
+use crate::tools::commands::LoopCut;
+
+/// Hover distance from a projected edge, in pixels.
+const EDGE_HOVER_THRESHOLD_PX: f32 = 10.0;
+/// Closest a single cut gets to either end of its edge, as a fraction.
+const MIN_FRACTION: f32 = 0.05;
+/// Most loops one cut inserts.
+const MAX_CUTS: usize = 64;
+
+/// A running loop cut with the cut under the mouse, if any.
+pub struct LoopCutTool {
+    /// Number of loops to insert
+    cuts: usize,
+    /// Latest mouse position
+    mouse: Vector2,
+    /// Cut through the hovered edge, applied on confirm
+    hovered: Option<LoopCut>,
+    /// World-space segments of the hovered cut's loops, drawn by the plugin
+    segments: Vec<(Vector3, Vector3)>,
+}
+
+impl LoopCutTool {
+    /// Start a loop cut with the mouse at `mouse`.
+    pub fn new(node: &mut BlockotNode, camera: &Gd<Camera3D>, mouse: Vector2) -> Self {
+        let mut tool = Self {
+            cuts: 1,
+            mouse,
+            hovered: None,
+            segments: Vec::new(),
+        };
+        tool.update(node, camera);
+        tool
+    }
+
+    /// World-space segments of the previewed loops.
+    pub fn segments(&self) -> &[(Vector3, Vector3)] {
+        &self.segments
+    }
+
+    /// Feed an input event to the loop cut.
+    pub fn handle_input(
+        &mut self,
+        node: &mut BlockotNode,
+        camera: &Gd<Camera3D>,
+        event: &Gd<InputEvent>,
+    ) -> ModalOutcome {
+        if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
+            self.mouse = motion.get_position();
+            self.update(node, camera);
+            // Motion is shared with the editor so viewport navigation keeps working
+            return ModalOutcome::Ignored;
+        }
+
+        if let Ok(mb) = event.clone().try_cast::<InputEventMouseButton>() {
+            let button = mb.get_button_index();
+            if button == MouseButton::WHEEL_UP || button == MouseButton::WHEEL_DOWN {
+                if mb.is_pressed() {
+                    self.cuts = if button == MouseButton::WHEEL_UP {
+                        (self.cuts + 1).min(MAX_CUTS)
+                    } else {
+                        (self.cuts - 1).max(1)
+                    };
+                    self.update(node, camera);
+                }
+                // Scrolling changes the cut count instead of zooming
+                return ModalOutcome::Running;
+            }
+            if button != MouseButton::LEFT && button != MouseButton::RIGHT {
+                return ModalOutcome::Ignored;
+            }
+            if !mb.is_pressed() {
+                return ModalOutcome::Running;
+            }
+            if button == MouseButton::LEFT {
+                self.confirm(node);
+            }
+            return ModalOutcome::Finished;
+        }
+
+        if let Ok(key) = event.clone().try_cast::<InputEventKey>() {
+            if key.is_pressed() && key.get_keycode() == Key::ESCAPE {
+                return ModalOutcome::Finished;
+            }
+            // Other keys are swallowed while the tool runs
+            return ModalOutcome::Running;
+        }
+
+        ModalOutcome::Ignored
+    }
+
+    /// Apply the hovered cut and select the new loops.
+    fn confirm(&self, node: &mut BlockotNode) {
+        let Some(cut) = self.hovered.clone() else {
+            return;
+        };
+        let new_vertices: Vec<usize> = cut.new_vertices().collect();
+        execute_with_undo(node, cut);
+        node.selection_mut()
+            .pick_vertices(&new_vertices, SelectOp::Replace);
+        node.update_selection_hints();
+        node.refresh_vertex_handles();
+    }
+
+    /// Rebuild the cut for the edge under the mouse.
+    fn update(&mut self, node: &mut BlockotNode, camera: &Gd<Camera3D>) {
+        self.hovered = None;
+        self.segments.clear();
+
+        let screen_positions = project_vertices(camera, node);
+        let global = node.base().get_global_transform();
+        let (geo, topology) = node.geometry_and_topology();
+        let Some((a, b)) = find_closest_edge(
+            &screen_positions,
+            topology.edges(),
+            self.mouse,
+            EDGE_HOVER_THRESHOLD_PX,
+        ) else {
+            return;
+        };
+        let (Some(pa), Some(pb)) = (screen_positions[a], screen_positions[b]) else {
+            return;
+        };
+        let along = pb - pa;
+        let fraction = if along.length_squared() > 0.0 {
+            (self.mouse - pa).dot(along) / along.length_squared()
+        } else {
+            0.5
+        };
+
+        let fraction = fraction.clamp(MIN_FRACTION, 1.0 - MIN_FRACTION);
+        let Ok(cut) = LoopCut::new(geo, topology, (a, b), self.cuts, fraction) else {
+            return;
+        };
+        for positions in cut.loop_positions() {
+            let world: Vec<Vector3> = positions.iter().map(|&p| global * p).collect();
+            self.segments
+                .extend(world.windows(2).map(|pair| (pair[0], pair[1])));
+            if cut.is_closed() {
+                if let (Some(&first), Some(&last)) = (world.first(), world.last()) {
+                    self.segments.push((last, first));
+                }
+            }
+        }
+        self.hovered = Some(cut);
+    }
+}
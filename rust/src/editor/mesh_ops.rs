@@ -0,0 +1,263 @@
+// editor/mesh_ops.rs - Mesh edits applied to the selection from hotkeys
+//
+// X/Delete deletes, Ctrl+X dissolves and Alt+X collapses the selection of
+// the node in edit mode. What they act on follows the selection mode:
+// vertices, the faces along the selected edges, or the selected faces.
+// M merges the selected vertices into one (at their centre, or at the first
+// or last picked), Ctrl+M merges those closer than the merge distance.
+// F fills the selected boundary loops with n-gons and Alt+F bridges two
+// selected boundary loops with quads. Alt+N flips the selected faces and
+// Shift+N recalculates their normals (every face's when none is selected).
+// I insets the selected faces as one region (Alt+I: each face on its own)
+// and Ctrl+B bevels the selected edges, by the amounts set in Editor
+// Settings.
+// The commands compact the geometry's arrays, so the selection is carried
+// through their index remap afterwards; undo restores the exact arrays and
+// the selection from before (see history.rs).
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::blockot_node::BlockotNode;
+use super::history::execute_with_undo;
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, Edge};
+use crate::selection::{SelectOp, SelectionMode};
+use crate::tools::commands::{
+    BevelEdges, BridgeEdgeLoops, CollapseEdges, DeleteFaces, DeleteVertices, DissolveEdges,
+    DissolveVertices, FillHole, FlipFaces, InsetFaces, InsetMode, MergeAt, MergeByDistance,
+    MergeVertices, RecalculateNormals,
+};
+
+/// Delete the selected vertices, the faces along the selected edges, or the
+/// selected faces, depending on the selection mode. Clears the selection.
+pub fn delete_selection(node: &mut BlockotNode) -> Result<(), BlockotError> {
+    match node.selection().mode {
+        SelectionMode::Vertex => {
+            let cmd = DeleteVertices::new(node.geometry(), selected_vertices(node))?;
+            execute_with_undo(node, cmd);
+        }
+        SelectionMode::Edge => {
+            let edges = node.selection().selected_edges.clone();
+            let topology = node.topology();
+            let faces: BTreeSet<usize> = edges
+                .iter()
+                .flat_map(|&(a, b)| topology.edge_faces(a, b).iter().copied())
+                .collect();
+            let cmd = DeleteFaces::new(node.geometry(), faces.into_iter().collect())?;
+            execute_with_undo(node, cmd);
+        }
+        SelectionMode::Face => {
+            let faces = node.selection().selected_faces.clone();
+            let cmd = DeleteFaces::new(node.geometry(), faces)?;
+            execute_with_undo(node, cmd);
+        }
+    }
+    select(node, &[]);
+    Ok(())
+}
+
+/// Dissolve the selected vertices, the selected edges, or the edges shared
+/// by two selected faces, depending on the selection mode.
+pub fn dissolve_selection(node: &mut BlockotNode) -> Result<(), BlockotError> {
+    let after = match node.selection().mode {
+        SelectionMode::Vertex => {
+            let cmd = DissolveVertices::new(node.geometry(), selected_vertices(node))?;
+            let after = remapped_selection(node, |v| cmd.remapped(v));
+            execute_with_undo(node, cmd);
+            after
+        }
+        SelectionMode::Edge | SelectionMode::Face => {
+            let edges = if node.selection().mode == SelectionMode::Edge {
+                node.selection().selected_edges.clone()
+            } else {
+                inner_edges(node)
+            };
+            let (geo, topology) = node.geometry_and_topology();
+            let cmd = DissolveEdges::new(geo, topology, edges)?;
+            let after = remapped_selection(node, |v| cmd.remapped(v));
+            execute_with_undo(node, cmd);
+            after
+        }
+    };
+    select(node, &after);
+    Ok(())
+}
+
+/// Collapse the selected edges (in Face mode, the edges of the selected
+/// faces), keeping the merged vertices selected.
+pub fn collapse_selection(node: &mut BlockotNode) -> Result<(), BlockotError> {
+    let edges = node.selection().selected_edges.clone();
+    let (geo, topology) = node.geometry_and_topology();
+    let cmd = CollapseEdges::new(geo, topology, edges)?;
+    let after = remapped_selection(node, |v| cmd.remapped(v));
+    execute_with_undo(node, cmd);
+    select(node, &after);
+    Ok(())
+}
+
+/// Merge the selected vertices into one. Returns the number of vertices
+/// removed; nothing is recorded when that is none.
+pub fn merge_selection(node: &mut BlockotNode, at: MergeAt) -> Result<usize, BlockotError> {
+    let cmd = MergeVertices::new(node.geometry(), node.selection().ordered_vertices(), at)?;
+    let removed = cmd.removed_vertex_count();
+    if removed == 0 {
+        return Ok(0);
+    }
+    let after = remapped_selection(node, |v| cmd.remapped(v));
+    execute_with_undo(node, cmd);
+    select(node, &after);
+    Ok(removed)
+}
+
+/// Merge selected vertices (every vertex when nothing is selected) lying
+/// within `threshold` of each other. Returns the number of vertices removed.
+pub fn merge_by_distance(node: &mut BlockotNode, threshold: f32) -> Result<usize, BlockotError> {
+    let mut vertices = selected_vertices(node);
+    if vertices.is_empty() {
+        vertices = (0..node.geometry().vertices.len()).collect();
+    }
+    let cmd = MergeByDistance::new(node.geometry(), vertices, threshold)?;
+    let removed = cmd.removed_vertex_count();
+    if removed == 0 {
+        return Ok(0);
+    }
+    let after = remapped_selection(node, |v| cmd.remapped(v));
+    execute_with_undo(node, cmd);
+    select(node, &after);
+    Ok(removed)
+}
+
+/// Fill every closed loop of selected boundary edges with an n-gon.
+pub fn fill_selection(node: &mut BlockotNode) -> Result<(), BlockotError> {
+    let edges = selected_boundary_edges(node);
+    let (geo, topology) = node.geometry_and_topology();
+    let cmd = FillHole::new(geo, topology, edges)?;
+    execute_with_undo(node, cmd);
+    node.update_selection_hints();
+    node.refresh_vertex_handles();
+    Ok(())
+}
+
+/// Bridge the two loops of selected boundary edges with quads.
+pub fn bridge_selection(node: &mut BlockotNode) -> Result<(), BlockotError> {
+    let edges = selected_boundary_edges(node);
+    let (geo, topology) = node.geometry_and_topology();
+    let cmd = BridgeEdgeLoops::new(geo, topology, edges)?;
+    execute_with_undo(node, cmd);
+    node.update_selection_hints();
+    node.refresh_vertex_handles();
+    Ok(())
+}
+
+/// Reverse the winding of the selected faces.
+pub fn flip_selection(node: &mut BlockotNode) -> Result<(), BlockotError> {
+    let faces = node.selection().selected_faces.clone();
+    let cmd = FlipFaces::new(node.geometry(), faces)?;
+    execute_with_undo(node, cmd);
+    Ok(())
+}
+
+/// Make the winding of the selected faces (every face when none is
+/// selected) consistent, closed parts facing outward. Returns the number of
+/// faces flipped.
+pub fn recalculate_normals(node: &mut BlockotNode) -> Result<usize, BlockotError> {
+    let mut faces = node.selection().selected_faces.clone();
+    if faces.is_empty() {
+        faces = (0..node.geometry().faces.len()).collect();
+    }
+    let (geo, topology) = node.geometry_and_topology();
+    let cmd = RecalculateNormals::new(geo, topology, faces)?;
+    let flipped = cmd.flipped_faces().len();
+    if flipped > 0 {
+        execute_with_undo(node, cmd);
+    }
+    Ok(flipped)
+}
+
+/// Inset the selected faces by `thickness`, keeping the inset faces selected.
+pub fn inset_selection(
+    node: &mut BlockotNode,
+    thickness: f32,
+    mode: InsetMode,
+) -> Result<(), BlockotError> {
+    let faces = node.selection().selected_faces.clone();
+    let cmd = InsetFaces::new(node.geometry(), faces.clone(), thickness, mode)?;
+    execute_with_undo(node, cmd);
+    // The faces keep their indices but now use the inner vertex copies
+    let vertices: BTreeSet<usize> = faces
+        .iter()
+        .filter_map(|&f| node.geometry().faces.get(f))
+        .flat_map(|face| face.vertex_indices.iter().copied())
+        .collect();
+    select(node, &vertices.into_iter().collect::<Vec<_>>());
+    Ok(())
+}
+
+/// Bevel the selected edges with strips `width` wide and `segments` quads
+/// across.
+pub fn bevel_selection(
+    node: &mut BlockotNode,
+    width: f32,
+    segments: usize,
+) -> Result<(), BlockotError> {
+    let edges = node.selection().selected_edges.clone();
+    let (geo, topology) = node.geometry_and_topology();
+    let cmd = BevelEdges::new(geo, topology, edges, width, segments)?;
+    execute_with_undo(node, cmd);
+    node.update_selection_hints();
+    node.refresh_vertex_handles();
+    Ok(())
+}
+
+/// Selected edges on an open border (a face selection's inner edges drop out).
+fn selected_boundary_edges(node: &mut BlockotNode) -> Vec<Edge> {
+    let edges = node.selection().selected_edges.clone();
+    let topology = node.topology();
+    edges
+        .into_iter()
+        .filter(|&(a, b)| topology.is_boundary_edge(a, b))
+        .collect()
+}
+
+/// Where the selected vertices end up after an edit, given its remap.
+fn remapped_selection(node: &BlockotNode, remap: impl Fn(usize) -> Option<usize>) -> Vec<usize> {
+    let after: BTreeSet<usize> = node
+        .selection()
+        .vertex_indices
+        .iter()
+        .filter_map(|&v| remap(v))
+        .collect();
+    after.into_iter().collect()
+}
+
+/// The selected vertices, sorted.
+fn selected_vertices(node: &BlockotNode) -> Vec<usize> {
+    let mut vertices: Vec<usize> = node.selection().vertex_indices.iter().copied().collect();
+    vertices.sort_unstable();
+    vertices
+}
+
+/// Edges between two selected faces.
+fn inner_edges(node: &mut BlockotNode) -> Vec<Edge> {
+    let faces: HashSet<usize> = node.selection().selected_faces.iter().copied().collect();
+    let (geo, topology) = node.geometry_and_topology();
+    let edges: BTreeSet<Edge> = faces
+        .iter()
+        .filter_map(|&f| geo.faces.get(f))
+        .flat_map(|face| face.edges())
+        .map(|(a, b)| edge_key(a, b))
+        .filter(|&(a, b)| {
+            let shared = topology.edge_faces(a, b);
+            shared.len() == 2 && shared.iter().all(|f| faces.contains(f))
+        })
+        .collect();
+    edges.into_iter().collect()
+}
+
+/// Replace the selection after an edit and refresh the handles.
+fn select(node: &mut BlockotNode, vertices: &[usize]) {
+    node.selection_mut()
+        .pick_vertices(vertices, SelectOp::Replace);
+    node.update_selection_hints();
+    node.refresh_vertex_handles();
+}
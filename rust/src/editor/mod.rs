@@ -4,11 +4,19 @@
 // Bridges pure Rust geometry/tools to Godot's systems.
 
 mod blockot_node;
+mod cut_tool;
+mod edge_slide_tool;
 pub mod edit_mode;
 mod history;
+mod input_handler;
+mod loop_cut_tool;
+mod mesh_ops;
 mod plugin;
+mod settings;
 
 pub use blockot_node::BlockotNode;
 pub use edit_mode::EditModeState;
-pub use history::{execute_with_undo, execute_without_undo, undo_command};
+pub use history::{
+    execute_boxed_with_undo, execute_with_undo, execute_without_undo, undo_command, CommandRegistry,
+};
 pub use plugin::BlockotPlugin;
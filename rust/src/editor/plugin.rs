@@ -1,7 +1,13 @@
 // editor/plugin.rs - BlockotPlugin EditorPlugin
 //
 // Handles input forwarding and edit mode toggling for BlockotNode.
-// Captures Tab key to enter/exit edit mode.
+// Captures Tab key to enter/exit edit mode, 1/2/3 to switch selection mode,
+// and the selection itself: clicks (Shift adds, Ctrl toggles), box select,
+// A/Alt+A and X-ray (Alt+Z). Period, Comma and Shift+RMB set the pivot,
+// orientation and 3D cursor. Other keys start the tools, each documented in
+// its own module: transforms and extrude in input_handler.rs, cuts in
+// cut_tool.rs and loop_cut_tool.rs, edge slide in edge_slide_tool.rs, and
+// mesh edits in mesh_ops.rs.
 //
 // Tab detection uses process() polling with the Input singleton because
 // the Godot editor's GUI focus system intercepts Tab for focus navigation
@@ -11,17 +17,58 @@
 
 use godot::classes::editor_plugin::AfterGuiInput;
 use godot::classes::{
-    Camera3D, EditorInterface, EditorPlugin, IEditorPlugin, Input, InputEvent,
-    InputEventMouseButton, Object,
+    Camera3D, Control, EditorInterface, EditorPlugin, IEditorPlugin, Input, InputEvent,
+    InputEventKey, InputEventMouseButton, InputEventMouseMotion, Object,
 };
 use godot::global::{Key, MouseButton};
 use godot::obj::EngineEnum;
 use godot::prelude::*;
 
 use super::blockot_node::BlockotNode;
+use super::cut_tool::CutTool;
+use super::edge_slide_tool::EdgeSlideTool;
 use super::edit_mode::EditModeState;
-use crate::selection::find_closest_vertex;
-use crate::selection::SelectionMode;
+use super::input_handler::{view_plane_point, ModalOutcome, ModalTransform, TransformSettings};
+use super::loop_cut_tool::LoopCutTool;
+use super::mesh_ops::{
+    bevel_selection, bridge_selection, collapse_selection, delete_selection, dissolve_selection,
+    fill_selection, flip_selection, inset_selection, merge_by_distance, merge_selection,
+    recalculate_normals,
+};
+use super::settings::{
+    load_bevel_settings, load_inset_thickness, load_merge_distance, load_snap_settings,
+    register_editing_settings, register_snap_settings,
+};
+use crate::error::BlockotError;
+use crate::selection::{
+    find_closest_edge, find_closest_vertex, find_vertices_in_rect, is_vertex_occluded,
+    raycast_faces,
+};
+use crate::selection::{SelectOp, SelectionMode};
+use crate::tools::commands::{InsetMode, MergeAt};
+use crate::tools::proximity::{SnapKind, SnapTarget};
+
+/// Pick radius around a projected vertex, in pixels.
+const VERTEX_SELECTION_THRESHOLD_PX: f32 = 15.0;
+/// Pick distance from a projected edge segment, in pixels.
+const EDGE_SELECTION_THRESHOLD_PX: f32 = 10.0;
+/// Mouse travel after an LMB press before a click becomes a box selection, in pixels.
+const BOX_SELECT_DRAG_THRESHOLD_PX: f32 = 4.0;
+/// Radius of the 3D cursor overlay, in pixels.
+const CURSOR_RADIUS_PX: f32 = 8.0;
+/// Half-size of the proximity snap indicator, in pixels.
+const SNAP_INDICATOR_PX: f32 = 6.0;
+
+/// An LMB press that is either a click or, once dragged far enough, a box selection.
+/// Modifiers are captured at press time.
+#[derive(Debug, Clone, Copy)]
+struct PendingPick {
+    start: Vector2,
+    current: Vector2,
+    shift: bool,
+    ctrl: bool,
+    dragging: bool,
+}
 
 /// Editor plugin that provides edit mode for BlockotNode.
 ///
@@ -34,10 +81,32 @@ pub struct BlockotPlugin {
     edit_state: EditModeState,
     /// Edge detection for Tab key polling
     tab_was_pressed: bool,
+    /// LMB press awaiting release (click or box selection)
+    pending_pick: Option<PendingPick>,
+    /// X-ray: select through the mesh, including occluded vertices
+    xray: bool,
+    /// Running modal transform (G), if any
+    modal: Option<ModalTransform>,
+    /// Running cut (C), if any
+    cut: Option<CutTool>,
+    /// Running loop cut (Ctrl+R), if any
+    loop_cut: Option<LoopCutTool>,
+    /// Running edge slide (Shift+V), if any
+    slide: Option<EdgeSlideTool>,
+    /// Last mouse position seen over the viewport (modal tools start from it)
+    last_mouse_pos: Vector2,
+    /// Pivot mode, 3D cursor, orientation and snapping for modal transforms
+    transform_settings: TransformSettings,
 }
 
 #[godot_api]
 impl IEditorPlugin for BlockotPlugin {
+    fn enter_tree(&mut self) {
+        register_snap_settings();
+        register_editing_settings();
+        self.transform_settings.snap = load_snap_settings();
+    }
+
     fn handles(&self, object: Gd<Object>) -> bool {
         object.is_class("BlockotNode")
     }
@@ -77,13 +146,10 @@ impl IEditorPlugin for BlockotPlugin {
         viewport_camera: Option<Gd<Camera3D>>,
         event: Option<Gd<InputEvent>>,
     ) -> i32 {
-        // Only process when in edit mode with Vertex selection mode
-        if !self.edit_state.is_active() {
+        // Only process when in edit mode
+        let Some(mode) = self.edit_state.selection_mode() else {
             return AfterGuiInput::PASS.ord();
-        }
-        if self.edit_state.selection_mode() != Some(SelectionMode::Vertex) {
-            return AfterGuiInput::PASS.ord();
-        }
+        };
 
         let Some(event) = event else {
             return AfterGuiInput::PASS.ord();
@@ -92,17 +158,249 @@ impl IEditorPlugin for BlockotPlugin {
             return AfterGuiInput::PASS.ord();
         };
 
-        // Detect left mouse button press (not release)
+        if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
+            self.last_mouse_pos = motion.get_position();
+        }
+
+        // A running modal tool takes all input until it finishes
+        if self.modal.is_some() {
+            return self.handle_modal_input(&camera, &event);
+        }
+        if self.cut.is_some() {
+            return self.handle_cut_input(&camera, &event);
+        }
+        if self.loop_cut.is_some() {
+            return self.handle_loop_cut_input(&camera, &event);
+        }
+        if self.slide.is_some() {
+            return self.handle_slide_input(&camera, &event);
+        }
+
+        // Selection mode hotkeys (1/2/3)
+        if let Ok(key) = event.clone().try_cast::<InputEventKey>() {
+            if key.is_pressed() && !key.is_echo() {
+                if let Some(new_mode) = selection_mode_for_key(key.get_keycode()) {
+                    self.set_selection_mode(new_mode);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::Z && key.is_alt_pressed() {
+                    self.xray = !self.xray;
+                    godot_print!(
+                        "BlockotPlugin: X-ray {}",
+                        if self.xray { "on" } else { "off" }
+                    );
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::G && !key.is_ctrl_pressed() {
+                    self.begin_modal(ModalTransform::begin_move);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::R && key.is_ctrl_pressed() {
+                    self.begin_loop_cut(&camera);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::V && key.is_shift_pressed() {
+                    self.begin_slide();
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::R && !key.is_ctrl_pressed() {
+                    self.begin_modal(ModalTransform::begin_rotate);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::S && !key.is_ctrl_pressed() {
+                    self.begin_modal(ModalTransform::begin_scale);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::E && !key.is_ctrl_pressed() {
+                    self.begin_modal(ModalTransform::begin_extrude);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::C && !key.is_ctrl_pressed() {
+                    self.begin_cut();
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::X || key.get_keycode() == Key::DELETE {
+                    if key.is_ctrl_pressed() {
+                        self.apply_mesh_op(dissolve_selection);
+                    } else if key.is_alt_pressed() {
+                        self.apply_mesh_op(collapse_selection);
+                    } else {
+                        self.apply_mesh_op(delete_selection);
+                    }
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::M {
+                    self.handle_merge(
+                        key.is_ctrl_pressed(),
+                        key.is_shift_pressed(),
+                        key.is_alt_pressed(),
+                    );
+                    return AfterGuiInput::STOP.ord();
+                }
+                // Shift+F is left to Godot's freelook, and a plain F that
+                // finds nothing to fill still focuses the selection
+                if key.get_keycode() == Key::F && !key.is_ctrl_pressed() && !key.is_shift_pressed()
+                {
+                    let filled = if key.is_alt_pressed() {
+                        self.apply_mesh_op(bridge_selection)
+                    } else {
+                        self.apply_mesh_op(fill_selection)
+                    };
+                    if filled.is_some() {
+                        return AfterGuiInput::STOP.ord();
+                    }
+                }
+                if key.get_keycode() == Key::I && !key.is_ctrl_pressed() {
+                    let mode = if key.is_alt_pressed() {
+                        InsetMode::Individual
+                    } else {
+                        InsetMode::Region
+                    };
+                    let thickness = load_inset_thickness();
+                    self.apply_mesh_op(|node| inset_selection(node, thickness, mode));
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::B && key.is_ctrl_pressed() {
+                    let (width, segments) = load_bevel_settings();
+                    self.apply_mesh_op(|node| bevel_selection(node, width, segments));
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::N && key.is_alt_pressed() {
+                    self.apply_mesh_op(flip_selection);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::N && key.is_shift_pressed() {
+                    if let Some(flipped) = self.apply_mesh_op(recalculate_normals) {
+                        godot_print!(
+                            "BlockotPlugin: Recalculate normals flipped {} faces",
+                            flipped
+                        );
+                    }
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::A && !key.is_ctrl_pressed() {
+                    self.handle_select_all(!key.is_alt_pressed());
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::PERIOD {
+                    let settings = &mut self.transform_settings;
+                    settings.pivot_mode = settings.pivot_mode.next();
+                    godot_print!("BlockotPlugin: Pivot {:?}", settings.pivot_mode);
+                    return AfterGuiInput::STOP.ord();
+                }
+                if key.get_keycode() == Key::COMMA {
+                    let settings = &mut self.transform_settings;
+                    settings.orientation = settings.orientation.next();
+                    godot_print!("BlockotPlugin: Orientation {:?}", settings.orientation);
+                    return AfterGuiInput::STOP.ord();
+                }
+            }
+        }
+
+        // Track drags of a pending LMB press; past the threshold it becomes a box
+        if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
+            let Some(pick) = self.pending_pick.as_mut() else {
+                return AfterGuiInput::PASS.ord();
+            };
+            pick.current = motion.get_position();
+            if !pick.dragging && pick.start.distance_to(pick.current) > BOX_SELECT_DRAG_THRESHOLD_PX
+            {
+                pick.dragging = true;
+            }
+            if pick.dragging {
+                self.base_mut().update_overlays();
+            }
+            return AfterGuiInput::STOP.ord();
+        }
+
+        // LMB press starts a pick; the release decides between click and box
         if let Ok(mb) = event.try_cast::<InputEventMouseButton>() {
-            if mb.is_pressed() && mb.get_button_index() == MouseButton::LEFT {
+            // Shift+RMB places the 3D cursor
+            if mb.get_button_index() == MouseButton::RIGHT && mb.is_shift_pressed() {
+                if mb.is_pressed() {
+                    self.place_cursor(&camera, mb.get_position());
+                }
+                return AfterGuiInput::STOP.ord();
+            }
+            if mb.get_button_index() != MouseButton::LEFT {
+                return AfterGuiInput::PASS.ord();
+            }
+
+            if mb.is_pressed() {
                 let mouse_pos = mb.get_position();
-                self.handle_vertex_click(&camera, mouse_pos);
+                self.pending_pick = Some(PendingPick {
+                    start: mouse_pos,
+                    current: mouse_pos,
+                    shift: mb.is_shift_pressed(),
+                    ctrl: mb.is_ctrl_pressed(),
+                    dragging: false,
+                });
                 return AfterGuiInput::STOP.ord();
             }
+
+            let Some(pick) = self.pending_pick.take() else {
+                return AfterGuiInput::PASS.ord();
+            };
+            if pick.dragging {
+                self.handle_box_select(&camera, &pick);
+                self.base_mut().update_overlays();
+            } else {
+                let op = select_op_for_modifiers(pick.shift, pick.ctrl);
+                match mode {
+                    SelectionMode::Vertex => self.handle_vertex_click(&camera, pick.start, op),
+                    SelectionMode::Edge => self.handle_edge_click(&camera, pick.start, op),
+                    SelectionMode::Face => self.handle_face_click(&camera, pick.start, op),
+                }
+            }
+            return AfterGuiInput::STOP.ord();
         }
 
         AfterGuiInput::PASS.ord()
     }
+
+    fn forward_3d_draw_over_viewport(&mut self, viewport_control: Option<Gd<Control>>) {
+        if !self.edit_state.is_active() {
+            return;
+        }
+        let Some(mut control) = viewport_control else {
+            return;
+        };
+
+        self.draw_cursor(&mut control);
+        if let Some(target) = self.modal.as_ref().and_then(ModalTransform::snap_target) {
+            draw_snap_indicator(&mut control, target);
+        }
+        if let Some((from, to)) = self.cut.as_ref().and_then(CutTool::line) {
+            control
+                .draw_line_ex(from, to, Color::from_rgba(1.0, 0.9, 0.2, 0.9))
+                .width(1.5)
+                .done();
+        }
+        if let (Some(loop_cut), Some(camera)) = (&self.loop_cut, editor_camera()) {
+            draw_loop_preview(&mut control, &camera, loop_cut.segments());
+        }
+
+        let Some(pick) = self.pending_pick.filter(|pick| pick.dragging) else {
+            return;
+        };
+
+        let min = Vector2::new(
+            pick.start.x.min(pick.current.x),
+            pick.start.y.min(pick.current.y),
+        );
+        let max = Vector2::new(
+            pick.start.x.max(pick.current.x),
+            pick.start.y.max(pick.current.y),
+        );
+        let rect = Rect2::new(min, max - min);
+
+        control.draw_rect(rect, Color::from_rgba(1.0, 1.0, 1.0, 0.1));
+        control
+            .draw_rect_ex(rect, Color::from_rgba(1.0, 1.0, 1.0, 0.8))
+            .filled(false)
+            .width(1.0)
+            .done();
+    }
 }
 
 #[godot_api]
@@ -111,6 +409,12 @@ impl BlockotPlugin {}
 impl BlockotPlugin {
     /// Handle Tab key press: toggle edit mode for the currently selected node.
     fn handle_tab_press(&mut self) {
+        self.cancel_pending_pick();
+        self.cancel_modal();
+        self.cancel_cut();
+        self.cancel_loop_cut();
+        self.cancel_slide();
+
         // Get the currently selected BlockotNode
         let Some(node_id) = self.get_selected_blockot_node_id() else {
             // No BlockotNode selected — if we're in edit mode, exit it
@@ -150,6 +454,11 @@ impl BlockotPlugin {
 
     /// Exit edit mode and notify the active node.
     fn do_exit_edit_mode(&mut self) {
+        self.cancel_pending_pick();
+        self.cancel_modal();
+        self.cancel_cut();
+        self.cancel_loop_cut();
+        self.cancel_slide();
         if let Some(node_id) = self.edit_state.active_node_id() {
             self.edit_state.exit_edit_mode();
             self.notify_node_exit_edit_mode(node_id);
@@ -185,59 +494,442 @@ impl BlockotPlugin {
         }
     }
 
-    /// Handle a vertex click: project vertices to screen space, find closest, update selection.
-    fn handle_vertex_click(&self, camera: &Gd<Camera3D>, mouse_pos: Vector2) {
-        let Some(node_id) = self.edit_state.active_node_id() else {
+    /// Switch the selection mode of the active edit session.
+    /// The node keeps its selected vertices and recomputes edge/face hints.
+    fn set_selection_mode(&mut self, mode: SelectionMode) {
+        if self.edit_state.selection_mode() == Some(mode) {
+            return;
+        }
+        self.edit_state.set_selection_mode(mode);
+
+        if let Some(mut node) = self.active_node() {
+            node.bind_mut().set_selection_mode(mode);
+        }
+        godot_print!("BlockotPlugin: Selection mode {:?}", mode);
+    }
+
+    /// Get the BlockotNode currently in edit mode, if it still exists.
+    fn active_node(&self) -> Option<Gd<BlockotNode>> {
+        let node_id = self.edit_state.active_node_id()?;
+        let instance_id = InstanceId::try_from_i64(node_id)?;
+        let obj = Gd::<Object>::try_from_instance_id(instance_id).ok()?;
+        obj.try_cast::<BlockotNode>().ok()
+    }
+
+    /// Start a modal tool (G/R/S/E) on the active node's selection.
+    fn begin_modal(
+        &mut self,
+        begin: fn(&mut BlockotNode, Vector2, &TransformSettings) -> Option<ModalTransform>,
+    ) {
+        if self.pending_pick.is_some() {
+            return;
+        }
+        let Some(mut node) = self.active_node() else {
+            return;
+        };
+        // Pick up Editor Settings changes made since the last transform
+        self.transform_settings.snap = load_snap_settings();
+        self.modal = begin(
+            &mut node.bind_mut(),
+            self.last_mouse_pos,
+            &self.transform_settings,
+        );
+        if self.modal.is_none() {
+            godot_print!("BlockotPlugin: Nothing selected to transform");
+        }
+    }
+
+    /// Run a mesh edit (see mesh_ops.rs) on the active node's selection.
+    /// Returns the operation's result, or None if it did not run or failed.
+    fn apply_mesh_op<T>(
+        &mut self,
+        op: impl FnOnce(&mut BlockotNode) -> Result<T, BlockotError>,
+    ) -> Option<T> {
+        if self.pending_pick.is_some() {
+            return None;
+        }
+        let mut node = self.active_node()?;
+        let result = op(&mut node.bind_mut());
+        result
+            .map_err(|e| godot_print!("BlockotPlugin: {}", e))
+            .ok()
+    }
+
+    /// Merge the active node's selected vertices: at their centre, the first
+    /// (Shift) or last (Alt) picked, or by distance (Ctrl).
+    fn handle_merge(&mut self, by_distance: bool, first: bool, last: bool) {
+        let removed = if by_distance {
+            let threshold = load_merge_distance();
+            self.apply_mesh_op(|node| merge_by_distance(node, threshold))
+        } else {
+            let at = if first {
+                MergeAt::First
+            } else if last {
+                MergeAt::Last
+            } else {
+                MergeAt::Center
+            };
+            self.apply_mesh_op(|node| merge_selection(node, at))
+        };
+        if let Some(removed) = removed {
+            godot_print!("BlockotPlugin: Merge removed {} vertices", removed);
+        }
+    }
+
+    /// Forward an event to the running modal tool, dropping it once finished.
+    fn handle_modal_input(&mut self, camera: &Gd<Camera3D>, event: &Gd<InputEvent>) -> i32 {
+        let Some(mut modal) = self.modal.take() else {
+            return AfterGuiInput::PASS.ord();
+        };
+        let Some(mut node) = self.active_node() else {
+            return AfterGuiInput::PASS.ord();
+        };
+
+        let old_target = modal.snap_target();
+        let outcome = modal.handle_input(&mut node.bind_mut(), camera, event);
+        let new_target = match outcome {
+            ModalOutcome::Finished => None,
+            _ => modal.snap_target(),
+        };
+        if new_target != old_target {
+            self.base_mut().update_overlays();
+        }
+
+        match outcome {
+            ModalOutcome::Running => {
+                self.modal = Some(modal);
+                AfterGuiInput::STOP.ord()
+            }
+            ModalOutcome::Ignored => {
+                self.modal = Some(modal);
+                AfterGuiInput::PASS.ord()
+            }
+            ModalOutcome::Finished => AfterGuiInput::STOP.ord(),
+        }
+    }
+
+    /// Cancel a running modal tool, restoring the previewed vertices.
+    fn cancel_modal(&mut self) {
+        let Some(modal) = self.modal.take() else {
+            return;
+        };
+        if let Some(mut node) = self.active_node() {
+            modal.cancel(&mut node.bind_mut());
+        }
+        if modal.snap_target().is_some() {
+            self.base_mut().update_overlays();
+        }
+    }
+
+    /// Start a cut (C) on the active node.
+    fn begin_cut(&mut self) {
+        if self.pending_pick.is_some() || self.active_node().is_none() {
+            return;
+        }
+        self.cut = Some(CutTool::new(self.last_mouse_pos));
+        godot_print!("BlockotPlugin: Cut - click the start and end of the line (Shift: bisect)");
+    }
+
+    /// Forward an event to the running cut, dropping it once finished.
+    fn handle_cut_input(&mut self, camera: &Gd<Camera3D>, event: &Gd<InputEvent>) -> i32 {
+        let Some(mut cut) = self.cut.take() else {
+            return AfterGuiInput::PASS.ord();
+        };
+        let Some(mut node) = self.active_node() else {
+            return AfterGuiInput::PASS.ord();
+        };
+
+        let outcome = cut.handle_input(&mut node.bind_mut(), camera, event);
+        if cut.line().is_some() {
+            self.base_mut().update_overlays();
+        }
+
+        match outcome {
+            ModalOutcome::Running => {
+                self.cut = Some(cut);
+                AfterGuiInput::STOP.ord()
+            }
+            ModalOutcome::Ignored => {
+                self.cut = Some(cut);
+                AfterGuiInput::PASS.ord()
+            }
+            ModalOutcome::Finished => AfterGuiInput::STOP.ord(),
+        }
+    }
+
+    /// Abandon a running cut and clear its line.
+    fn cancel_cut(&mut self) {
+        if self.cut.take().is_some_and(|cut| cut.line().is_some()) {
+            self.base_mut().update_overlays();
+        }
+    }
+
+    /// Start a loop cut (Ctrl+R) on the active node.
+    fn begin_loop_cut(&mut self, camera: &Gd<Camera3D>) {
+        if self.pending_pick.is_some() {
+            return;
+        }
+        let Some(mut node) = self.active_node() else {
             return;
         };
-        let Some(instance_id) = InstanceId::try_from_i64(node_id) else {
+        self.loop_cut = Some(LoopCutTool::new(
+            &mut node.bind_mut(),
+            camera,
+            self.last_mouse_pos,
+        ));
+        self.base_mut().update_overlays();
+        godot_print!("BlockotPlugin: Loop cut - hover an edge, scroll for more cuts, click to cut");
+    }
+
+    /// Forward an event to the running loop cut, dropping it once finished.
+    fn handle_loop_cut_input(&mut self, camera: &Gd<Camera3D>, event: &Gd<InputEvent>) -> i32 {
+        let Some(mut loop_cut) = self.loop_cut.take() else {
+            return AfterGuiInput::PASS.ord();
+        };
+        let Some(mut node) = self.active_node() else {
+            return AfterGuiInput::PASS.ord();
+        };
+
+        let outcome = loop_cut.handle_input(&mut node.bind_mut(), camera, event);
+        self.base_mut().update_overlays();
+
+        match outcome {
+            ModalOutcome::Running => {
+                self.loop_cut = Some(loop_cut);
+                AfterGuiInput::STOP.ord()
+            }
+            ModalOutcome::Ignored => {
+                self.loop_cut = Some(loop_cut);
+                AfterGuiInput::PASS.ord()
+            }
+            ModalOutcome::Finished => AfterGuiInput::STOP.ord(),
+        }
+    }
+
+    /// Abandon a running loop cut and clear its preview.
+    fn cancel_loop_cut(&mut self) {
+        if self.loop_cut.take().is_some() {
+            self.base_mut().update_overlays();
+        }
+    }
+
+    /// Start an edge slide (Shift+V) of the active node's selected loops.
+    fn begin_slide(&mut self) {
+        if self.pending_pick.is_some() {
+            return;
+        }
+        let Some(mut node) = self.active_node() else {
             return;
         };
-        let Ok(obj) = Gd::<Object>::try_from_instance_id(instance_id) else {
+        self.slide = EdgeSlideTool::begin(&mut node.bind_mut(), self.last_mouse_pos);
+        if self.slide.is_none() {
+            godot_print!("BlockotPlugin: No selected edges to slide");
+        }
+    }
+
+    /// Forward an event to the running edge slide, dropping it once finished.
+    fn handle_slide_input(&mut self, camera: &Gd<Camera3D>, event: &Gd<InputEvent>) -> i32 {
+        let Some(mut slide) = self.slide.take() else {
+            return AfterGuiInput::PASS.ord();
+        };
+        let Some(mut node) = self.active_node() else {
+            return AfterGuiInput::PASS.ord();
+        };
+
+        match slide.handle_input(&mut node.bind_mut(), camera, event) {
+            ModalOutcome::Running => {
+                self.slide = Some(slide);
+                AfterGuiInput::STOP.ord()
+            }
+            ModalOutcome::Ignored => {
+                self.slide = Some(slide);
+                AfterGuiInput::PASS.ord()
+            }
+            ModalOutcome::Finished => AfterGuiInput::STOP.ord(),
+        }
+    }
+
+    /// Cancel a running edge slide, restoring the previewed vertices.
+    fn cancel_slide(&mut self) {
+        let Some(slide) = self.slide.take() else {
             return;
         };
-        let Ok(mut node) = obj.try_cast::<BlockotNode>() else {
+        if let Some(mut node) = self.active_node() {
+            slide.cancel(&mut node.bind_mut());
+        }
+    }
+
+    /// Place the 3D cursor under the mouse: on the active node's surface when
+    /// the ray hits a face, otherwise on the view plane through the old cursor.
+    fn place_cursor(&mut self, camera: &Gd<Camera3D>, mouse_pos: Vector2) {
+        let surface_hit = self.active_node().and_then(|node| {
+            let bound = node.bind();
+            let (origin, direction) = local_ray(camera, &bound, mouse_pos);
+            let hit = raycast_faces(bound.geometry(), origin, direction)?;
+            let global_transform = bound.base().get_global_transform();
+            Some(global_transform * (origin + direction * hit.distance))
+        });
+        let Some(cursor) = surface_hit
+            .or_else(|| view_plane_point(camera, mouse_pos, self.transform_settings.cursor))
+        else {
             return;
         };
 
+        self.transform_settings.cursor = cursor;
+        self.base_mut().update_overlays();
+    }
+
+    /// Draw the 3D cursor as a ring with a cross hair.
+    fn draw_cursor(&self, control: &mut Gd<Control>) {
+        let Some(camera) = editor_camera() else {
+            return;
+        };
+        let cursor = self.transform_settings.cursor;
+        if camera.is_position_behind(cursor) {
+            return;
+        }
+
+        let center = camera.unproject_position(cursor);
+        let color = Color::from_rgba(1.0, 0.3, 0.3, 0.9);
+        let r = CURSOR_RADIUS_PX;
+        control
+            .draw_arc_ex(center, r, 0.0, std::f32::consts::TAU, 24, color)
+            .width(1.5)
+            .done();
+        control.draw_line(
+            center - Vector2::new(r * 1.5, 0.0),
+            center + Vector2::new(r * 1.5, 0.0),
+            color,
+        );
+        control.draw_line(
+            center - Vector2::new(0.0, r * 1.5),
+            center + Vector2::new(0.0, r * 1.5),
+            color,
+        );
+    }
+
+    /// Drop an in-progress click/box selection and clear its overlay.
+    fn cancel_pending_pick(&mut self) {
+        if self.pending_pick.take().is_some_and(|pick| pick.dragging) {
+            self.base_mut().update_overlays();
+        }
+    }
+
+    /// Handle a box selection: select the vertices whose projections fall inside
+    /// the dragged rectangle. Edge/face hints follow from the selected vertices.
+    fn handle_box_select(&self, camera: &Gd<Camera3D>, pick: &PendingPick) {
+        let Some(mut node) = self.active_node() else {
+            return;
+        };
+        let mut bound = node.bind_mut();
+
+        let screen_positions = project_pickable_vertices(camera, &bound, self.xray);
+        let inside = find_vertices_in_rect(&screen_positions, pick.start, pick.current);
+        let op = box_op_for_modifiers(pick.shift, pick.ctrl);
+
+        bound.selection_mut().pick_vertices(&inside, op);
+        bound.update_selection_hints();
+        bound.refresh_vertex_handles();
+    }
+
+    /// Handle A / Alt+A: select or deselect everything on the active node.
+    fn handle_select_all(&self, select: bool) {
+        let Some(mut node) = self.active_node() else {
+            return;
+        };
         let mut bound = node.bind_mut();
 
-        // Get the node's global transform for local-to-world conversion
-        let global_transform = bound.base().get_global_transform();
-
-        // Project each vertex to screen space
-        let screen_positions: Vec<Option<Vector2>> = bound
-            .geometry()
-            .vertices
-            .iter()
-            .map(|v| {
-                let world_pos = global_transform * *v;
-                if camera.is_position_behind(world_pos) {
-                    None
-                } else {
-                    Some(camera.unproject_position(world_pos))
-                }
-            })
-            .collect();
-
-        // Find closest vertex within threshold (15 pixels)
-        const VERTEX_SELECTION_THRESHOLD_PX: f32 = 15.0;
+        if select {
+            bound.select_all();
+        } else {
+            bound.selection_mut().clear();
+            bound.refresh_vertex_handles();
+        }
+    }
+
+    /// Handle a vertex click: project vertices to screen space, find closest, update selection.
+    fn handle_vertex_click(&self, camera: &Gd<Camera3D>, mouse_pos: Vector2, op: SelectOp) {
+        let Some(mut node) = self.active_node() else {
+            return;
+        };
+        let mut bound = node.bind_mut();
+
+        let screen_positions = project_pickable_vertices(camera, &bound, self.xray);
+
+        // Find closest vertex within threshold
         let hit = find_closest_vertex(&screen_positions, mouse_pos, VERTEX_SELECTION_THRESHOLD_PX);
 
         match hit {
             Some(index) => {
-                bound.selection_mut().select_vertex(index);
+                bound.selection_mut().pick_vertex(index, op);
+                bound.update_selection_hints();
             }
-            None => {
+            None if op == SelectOp::Replace => {
                 // Clicked empty space — deselect all
                 bound.selection_mut().clear();
             }
+            None => {}
         }
 
         // Refresh handle rendering to show selection state
         bound.refresh_vertex_handles();
     }
 
+    /// Handle an edge click: find the closest projected edge, select its two vertices.
+    fn handle_edge_click(&self, camera: &Gd<Camera3D>, mouse_pos: Vector2, op: SelectOp) {
+        let Some(mut node) = self.active_node() else {
+            return;
+        };
+        let mut bound = node.bind_mut();
+
+        let screen_positions = project_vertices(camera, &bound);
+        let edges = bound.topology().edges().to_vec();
+
+        let hit = find_closest_edge(
+            &screen_positions,
+            &edges,
+            mouse_pos,
+            EDGE_SELECTION_THRESHOLD_PX,
+        );
+
+        match hit {
+            Some((a, b)) => {
+                bound.selection_mut().pick_edge(a, b, op);
+            }
+            None if op == SelectOp::Replace => {
+                // Clicked empty space — deselect all
+                bound.selection_mut().clear();
+            }
+            None => {}
+        }
+
+        bound.refresh_vertex_handles();
+    }
+
+    /// Handle a face click: cast a ray from the camera, select the nearest hit face.
+    fn handle_face_click(&self, camera: &Gd<Camera3D>, mouse_pos: Vector2, op: SelectOp) {
+        let Some(mut node) = self.active_node() else {
+            return;
+        };
+        let mut bound = node.bind_mut();
+
+        let (origin, direction) = local_ray(camera, &bound, mouse_pos);
+        let hit = raycast_faces(bound.geometry(), origin, direction);
+
+        match hit {
+            Some(hit) => {
+                bound.pick_face(hit.face, op);
+            }
+            None if op == SelectOp::Replace => {
+                // Clicked empty space — deselect all
+                bound.selection_mut().clear();
+            }
+            None => {}
+        }
+
+        bound.refresh_vertex_handles();
+    }
+
     /// Notify a BlockotNode that it should exit edit mode.
     fn notify_node_exit_edit_mode(&self, node_id: i64) {
         if let Some(instance_id) = InstanceId::try_from_i64(node_id) {
@@ -249,3 +941,167 @@ impl BlockotPlugin {
         }
     }
 }
+
+/// Map the 1/2/3 hotkeys to Vertex/Edge/Face selection modes.
+fn selection_mode_for_key(key: Key) -> Option<SelectionMode> {
+    match key {
+        Key::KEY_1 => Some(SelectionMode::Vertex),
+        Key::KEY_2 => Some(SelectionMode::Edge),
+        Key::KEY_3 => Some(SelectionMode::Face),
+        _ => None,
+    }
+}
+
+/// Map click modifiers to a selection operation: Ctrl toggles, Shift adds.
+fn select_op_for_modifiers(shift: bool, ctrl: bool) -> SelectOp {
+    if ctrl {
+        SelectOp::Toggle
+    } else if shift {
+        SelectOp::Add
+    } else {
+        SelectOp::Replace
+    }
+}
+
+/// Map box selection modifiers to a selection operation: Ctrl subtracts, Shift adds.
+fn box_op_for_modifiers(shift: bool, ctrl: bool) -> SelectOp {
+    if ctrl {
+        SelectOp::Subtract
+    } else if shift {
+        SelectOp::Add
+    } else {
+        SelectOp::Replace
+    }
+}
+
+/// Project the node's vertices to screen space.
+///
+/// Accounts for the node's global transform (local-to-world).
+/// Vertices behind the camera are returned as `None`.
+pub(super) fn project_vertices(camera: &Gd<Camera3D>, node: &BlockotNode) -> Vec<Option<Vector2>> {
+    let global_transform = node.base().get_global_transform();
+
+    node.geometry()
+        .vertices
+        .iter()
+        .map(|v| {
+            let world_pos = global_transform * *v;
+            if camera.is_position_behind(world_pos) {
+                None
+            } else {
+                Some(camera.unproject_position(world_pos))
+            }
+        })
+        .collect()
+}
+
+/// Project the node's vertices for picking, hiding occluded ones unless `xray`.
+///
+/// A vertex is occluded when a face blocks the camera ray towards it; occluded
+/// vertices are returned as `None`, like vertices behind the camera.
+fn project_pickable_vertices(
+    camera: &Gd<Camera3D>,
+    node: &BlockotNode,
+    xray: bool,
+) -> Vec<Option<Vector2>> {
+    let mut screen_positions = project_vertices(camera, node);
+    if xray {
+        return screen_positions;
+    }
+
+    let world_to_local = node.base().get_global_transform().affine_inverse();
+    for (index, pos) in screen_positions.iter_mut().enumerate() {
+        let Some(screen_pos) = *pos else {
+            continue;
+        };
+        // Ray origin under the vertex works for both perspective and orthographic cameras
+        let eye = world_to_local * camera.project_ray_origin(screen_pos);
+        if is_vertex_occluded(node.geometry(), index, eye) {
+            *pos = None;
+        }
+    }
+    screen_positions
+}
+
+/// Mark a proximity snap target: a circle on vertices, a diamond on edges
+/// and a square on faces.
+fn draw_snap_indicator(control: &mut Gd<Control>, target: SnapTarget) {
+    let Some(camera) = editor_camera() else {
+        return;
+    };
+    if camera.is_position_behind(target.position) {
+        return;
+    }
+
+    let center = camera.unproject_position(target.position);
+    let color = Color::from_rgba(1.0, 0.8, 0.2, 0.9);
+    let r = SNAP_INDICATOR_PX;
+    match target.kind {
+        SnapKind::Vertex => {
+            control
+                .draw_arc_ex(center, r, 0.0, std::f32::consts::TAU, 16, color)
+                .width(2.0)
+                .done();
+        }
+        SnapKind::Edge => {
+            let corners = [
+                center + Vector2::new(0.0, -r),
+                center + Vector2::new(r, 0.0),
+                center + Vector2::new(0.0, r),
+                center + Vector2::new(-r, 0.0),
+            ];
+            for (&from, &to) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+                control.draw_line_ex(from, to, color).width(2.0).done();
+            }
+        }
+        SnapKind::Face => {
+            let rect = Rect2::new(center - Vector2::new(r, r), Vector2::new(r, r) * 2.0);
+            control
+                .draw_rect_ex(rect, color)
+                .filled(false)
+                .width(2.0)
+                .done();
+        }
+    }
+}
+
+/// Draw the loops a loop cut would insert, given as world-space segments.
+fn draw_loop_preview(
+    control: &mut Gd<Control>,
+    camera: &Gd<Camera3D>,
+    segments: &[(Vector3, Vector3)],
+) {
+    let color = Color::from_rgba(1.0, 0.9, 0.2, 0.9);
+    for &(from, to) in segments {
+        if camera.is_position_behind(from) || camera.is_position_behind(to) {
+            continue;
+        }
+        control
+            .draw_line_ex(
+                camera.unproject_position(from),
+                camera.unproject_position(to),
+                color,
+            )
+            .width(1.5)
+            .done();
+    }
+}
+
+/// The camera of the first 3D editor viewport, used when drawing overlays.
+fn editor_camera() -> Option<Gd<Camera3D>> {
+    EditorInterface::singleton()
+        .get_editor_viewport_3d()?
+        .get_camera_3d()
+}
+
+/// Build the camera ray under the mouse in the node's local space.
+///
+/// Returns `(origin, direction)`. The direction is not renormalized, so ray
+/// parameters stay comparable between world and local space.
+fn local_ray(camera: &Gd<Camera3D>, node: &BlockotNode, mouse_pos: Vector2) -> (Vector3, Vector3) {
+    let world_to_local = node.base().get_global_transform().affine_inverse();
+    let origin = camera.project_ray_origin(mouse_pos);
+    let direction = camera.project_ray_normal(mouse_pos);
+
+    (world_to_local * origin, world_to_local.basis * direction)
+}
@@ -0,0 +1,206 @@
+// editor/settings.rs - Blockot entries in EditorSettings
+//
+// Registers the snapping configuration under `blockot/snapping/*` so it
+// shows up in Editor Settings, and reads it back into the pure-Rust
+// SnapSettings used by the transform tools. The merge distance used by
+// merge by distance and the inset and bevel amounts live under
+// `blockot/editing/*`.
+
+use godot::classes::{EditorInterface, EditorSettings, Engine};
+use godot::global::PropertyHint;
+use godot::obj::EngineEnum;
+use godot::prelude::*;
+
+use crate::tools::snap::SnapSettings;
+
+const SNAP_ENABLED: &str = "blockot/snapping/enabled";
+const SNAP_GRID_SIZE: &str = "blockot/snapping/grid_size";
+const SNAP_ROTATION_STEP: &str = "blockot/snapping/rotation_step_degrees";
+const SNAP_SCALE_STEP: &str = "blockot/snapping/scale_step";
+const SNAP_PROXIMITY: &str = "blockot/snapping/proximity";
+const MERGE_DISTANCE: &str = "blockot/editing/merge_distance";
+const INSET_THICKNESS: &str = "blockot/editing/inset_thickness";
+const BEVEL_WIDTH: &str = "blockot/editing/bevel_width";
+const BEVEL_SEGMENTS: &str = "blockot/editing/bevel_segments";
+
+/// Default merge by distance threshold, in metres.
+const DEFAULT_MERGE_DISTANCE: f32 = 0.001;
+/// Default inset ring thickness, in metres.
+const DEFAULT_INSET_THICKNESS: f32 = 0.1;
+/// Default bevel strip width, in metres.
+const DEFAULT_BEVEL_WIDTH: f32 = 0.1;
+/// Default number of quads across a bevel strip.
+const DEFAULT_BEVEL_SEGMENTS: usize = 1;
+
+/// Add the snapping settings with their defaults, keeping values the user already set.
+pub fn register_snap_settings() {
+    let Some(mut settings) = editor_settings() else {
+        return;
+    };
+    let defaults = SnapSettings::default();
+
+    add_setting(
+        &mut settings,
+        SNAP_ENABLED,
+        defaults.enabled.to_variant(),
+        "",
+    );
+    add_setting(
+        &mut settings,
+        SNAP_GRID_SIZE,
+        (defaults.grid_size as f64).to_variant(),
+        "0.001,100,0.001,or_greater,suffix:m",
+    );
+    add_setting(
+        &mut settings,
+        SNAP_ROTATION_STEP,
+        (defaults.rotation_step_degrees as f64).to_variant(),
+        "0.1,180,0.1,degrees",
+    );
+    add_setting(
+        &mut settings,
+        SNAP_SCALE_STEP,
+        (defaults.scale_step as f64).to_variant(),
+        "0.001,10,0.001,or_greater",
+    );
+    add_setting(
+        &mut settings,
+        SNAP_PROXIMITY,
+        defaults.proximity.to_variant(),
+        "",
+    );
+}
+
+/// Add the editing settings with their defaults, keeping values the user already set.
+pub fn register_editing_settings() {
+    let Some(mut settings) = editor_settings() else {
+        return;
+    };
+    add_setting(
+        &mut settings,
+        MERGE_DISTANCE,
+        (DEFAULT_MERGE_DISTANCE as f64).to_variant(),
+        "0,1,0.0001,or_greater,suffix:m",
+    );
+    add_setting(
+        &mut settings,
+        INSET_THICKNESS,
+        (DEFAULT_INSET_THICKNESS as f64).to_variant(),
+        "0.001,10,0.001,or_greater,suffix:m",
+    );
+    add_setting(
+        &mut settings,
+        BEVEL_WIDTH,
+        (DEFAULT_BEVEL_WIDTH as f64).to_variant(),
+        "0.001,10,0.001,or_greater,suffix:m",
+    );
+    add_setting(
+        &mut settings,
+        BEVEL_SEGMENTS,
+        (DEFAULT_BEVEL_SEGMENTS as i64).to_variant(),
+        "1,32,1,or_greater",
+    );
+}
+
+/// Read the merge by distance threshold, falling back to the default for a
+/// missing or invalid entry.
+pub fn load_merge_distance() -> f32 {
+    let Some(settings) = editor_settings() else {
+        return DEFAULT_MERGE_DISTANCE;
+    };
+    settings
+        .get_setting(MERGE_DISTANCE)
+        .try_to::<f64>()
+        .map(|v| v as f32)
+        .ok()
+        .filter(|v| *v >= 0.0)
+        .unwrap_or(DEFAULT_MERGE_DISTANCE)
+}
+
+/// Read the inset thickness, falling back to the default for a missing or
+/// invalid entry.
+pub fn load_inset_thickness() -> f32 {
+    load_amount(INSET_THICKNESS, DEFAULT_INSET_THICKNESS)
+}
+
+/// Read the bevel width and segment count, falling back to the defaults for
+/// missing or invalid entries.
+pub fn load_bevel_settings() -> (f32, usize) {
+    let width = load_amount(BEVEL_WIDTH, DEFAULT_BEVEL_WIDTH);
+    let segments = editor_settings()
+        .and_then(|settings| settings.get_setting(BEVEL_SEGMENTS).try_to::<i64>().ok())
+        .filter(|v| *v >= 1)
+        .map_or(DEFAULT_BEVEL_SEGMENTS, |v| v as usize);
+    (width, segments)
+}
+
+/// Read a positive length, falling back to `default`.
+fn load_amount(name: &str, default: f32) -> f32 {
+    let Some(settings) = editor_settings() else {
+        return default;
+    };
+    settings
+        .get_setting(name)
+        .try_to::<f64>()
+        .map(|v| v as f32)
+        .ok()
+        .filter(|v| *v > 0.0)
+        .unwrap_or(default)
+}
+
+/// Read the snapping settings, falling back to defaults for missing or invalid entries.
+pub fn load_snap_settings() -> SnapSettings {
+    let defaults = SnapSettings::default();
+    let Some(settings) = editor_settings() else {
+        return defaults;
+    };
+
+    let float = |name: &str, default: f32| -> f32 {
+        settings
+            .get_setting(name)
+            .try_to::<f64>()
+            .map(|v| v as f32)
+            .ok()
+            .filter(|v| *v > 0.0)
+            .unwrap_or(default)
+    };
+
+    SnapSettings {
+        enabled: settings
+            .get_setting(SNAP_ENABLED)
+            .try_to::<bool>()
+            .unwrap_or(defaults.enabled),
+        grid_size: float(SNAP_GRID_SIZE, defaults.grid_size),
+        rotation_step_degrees: float(SNAP_ROTATION_STEP, defaults.rotation_step_degrees),
+        scale_step: float(SNAP_SCALE_STEP, defaults.scale_step),
+        proximity: settings
+            .get_setting(SNAP_PROXIMITY)
+            .try_to::<bool>()
+            .unwrap_or(defaults.proximity),
+    }
+}
+
+/// The editor's settings, or None when not running inside the editor.
+fn editor_settings() -> Option<Gd<EditorSettings>> {
+    if !Engine::singleton().is_editor_hint() {
+        return None;
+    }
+    EditorInterface::singleton().get_editor_settings()
+}
+
+/// Register one setting: default value, revert value and inspector hint.
+fn add_setting(settings: &mut Gd<EditorSettings>, name: &str, default: Variant, hint: &str) {
+    if !settings.has_setting(name) {
+        settings.set_setting(name, &default);
+    }
+    settings.set_initial_value(name, &default, false);
+
+    let mut info = Dictionary::new();
+    info.set("name", name);
+    info.set("type", default.get_type().ord());
+    if !hint.is_empty() {
+        info.set("hint", PropertyHint::RANGE.ord());
+        info.set("hint_string", hint);
+    }
+    settings.add_property_info(&info);
+}
@@ -12,11 +12,44 @@ pub enum BlockotError {
     /// Operation requires a selection but none was provided
     EmptySelection,
 
+    /// A composite command was given no child commands
+    EmptyComposite,
+
     /// Vertex index is out of bounds
     InvalidVertexIndex(usize),
 
     /// Face index is out of bounds
     InvalidFaceIndex(usize),
+
+    /// No face uses the edge between these vertices
+    InvalidEdge(usize, usize),
+
+    /// Edge is shared by several faces but the operation needs an open border
+    NotBoundaryEdge(usize, usize),
+
+    /// No quad uses the edge, so there is no edge ring through it
+    NoEdgeRing(usize, usize),
+
+    /// Edge is not shared by exactly two faces
+    NonManifoldEdge(usize, usize),
+
+    /// Faces around the vertex do not form a single fan
+    NonManifoldVertex(usize),
+
+    /// Selected edges stop at the vertex instead of closing a loop
+    OpenEdgeLoop(usize),
+
+    /// More than two selected edges meet at the vertex
+    EdgeLoopBranches(usize),
+
+    /// Bridging needs exactly two edge loops; holds the number found
+    BridgeLoopCount(usize),
+
+    /// Edge loops with these edge counts cannot be bridged quad by quad
+    BridgeLoopMismatch(usize, usize),
+
+    /// A width or thickness is zero, negative or not a number
+    NonPositiveAmount,
 }
 
 impl fmt::Display for BlockotError {
@@ -25,12 +58,45 @@ impl fmt::Display for BlockotError {
             BlockotError::EmptySelection => {
                 write!(f, "Operation requires a selection but none was provided")
             }
+            BlockotError::EmptyComposite => {
+                write!(f, "Composite command has no child commands")
+            }
             BlockotError::InvalidVertexIndex(idx) => {
                 write!(f, "Invalid vertex index: {}", idx)
             }
             BlockotError::InvalidFaceIndex(idx) => {
                 write!(f, "Invalid face index: {}", idx)
             }
+            BlockotError::InvalidEdge(a, b) => {
+                write!(f, "Invalid edge: {}-{}", a, b)
+            }
+            BlockotError::NotBoundaryEdge(a, b) => {
+                write!(f, "Edge {}-{} is not a boundary edge", a, b)
+            }
+            BlockotError::NoEdgeRing(a, b) => {
+                write!(f, "Edge {}-{} has no ring of quads", a, b)
+            }
+            BlockotError::NonManifoldEdge(a, b) => {
+                write!(f, "Edge {}-{} is not shared by exactly two faces", a, b)
+            }
+            BlockotError::NonManifoldVertex(v) => {
+                write!(f, "Faces around vertex {} do not form a single fan", v)
+            }
+            BlockotError::OpenEdgeLoop(v) => {
+                write!(f, "Edges stop at vertex {} instead of closing a loop", v)
+            }
+            BlockotError::EdgeLoopBranches(v) => {
+                write!(f, "Edges branch at vertex {}", v)
+            }
+            BlockotError::BridgeLoopCount(n) => {
+                write!(f, "Bridging needs exactly two edge loops, found {}", n)
+            }
+            BlockotError::BridgeLoopMismatch(a, b) => {
+                write!(f, "Edge loops of {} and {} edges cannot be bridged", a, b)
+            }
+            BlockotError::NonPositiveAmount => {
+                write!(f, "Amount must be greater than zero")
+            }
         }
     }
 }
@@ -47,6 +113,10 @@ mod tests {
             BlockotError::EmptySelection.to_string(),
             "Operation requires a selection but none was provided"
         );
+        assert_eq!(
+            BlockotError::EmptyComposite.to_string(),
+            "Composite command has no child commands"
+        );
         assert_eq!(
             BlockotError::InvalidVertexIndex(5).to_string(),
             "Invalid vertex index: 5"
@@ -55,6 +125,46 @@ mod tests {
             BlockotError::InvalidFaceIndex(10).to_string(),
             "Invalid face index: 10"
         );
+        assert_eq!(
+            BlockotError::InvalidEdge(1, 2).to_string(),
+            "Invalid edge: 1-2"
+        );
+        assert_eq!(
+            BlockotError::NotBoundaryEdge(3, 4).to_string(),
+            "Edge 3-4 is not a boundary edge"
+        );
+        assert_eq!(
+            BlockotError::NoEdgeRing(5, 6).to_string(),
+            "Edge 5-6 has no ring of quads"
+        );
+        assert_eq!(
+            BlockotError::NonManifoldEdge(7, 8).to_string(),
+            "Edge 7-8 is not shared by exactly two faces"
+        );
+        assert_eq!(
+            BlockotError::NonManifoldVertex(9).to_string(),
+            "Faces around vertex 9 do not form a single fan"
+        );
+        assert_eq!(
+            BlockotError::OpenEdgeLoop(2).to_string(),
+            "Edges stop at vertex 2 instead of closing a loop"
+        );
+        assert_eq!(
+            BlockotError::EdgeLoopBranches(4).to_string(),
+            "Edges branch at vertex 4"
+        );
+        assert_eq!(
+            BlockotError::BridgeLoopCount(3).to_string(),
+            "Bridging needs exactly two edge loops, found 3"
+        );
+        assert_eq!(
+            BlockotError::BridgeLoopMismatch(4, 6).to_string(),
+            "Edge loops of 4 and 6 edges cannot be bridged"
+        );
+        assert_eq!(
+            BlockotError::NonPositiveAmount.to_string(),
+            "Amount must be greater than zero"
+        );
     }
 
     #[test]
@@ -1,5 +1,7 @@
 // geometry/face.rs - Face struct for n-gon support
 
+use godot::prelude::Vector3;
+
 /// Represents a face (polygon) in BlockotGeometry.
 /// Supports n-gons (triangles, quads, or more vertices).
 #[derive(Debug, Clone, PartialEq)]
@@ -44,6 +46,48 @@ impl Face {
     pub fn is_quad(&self) -> bool {
         self.vertex_indices.len() == 4
     }
+
+    /// Directed edges in winding order, the last one closing back to the
+    /// first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let n = self.vertex_indices.len();
+        (0..n).map(move |i| (self.vertex_indices[i], self.vertex_indices[(i + 1) % n]))
+    }
+
+    /// Reverse the winding, turning the normal around. The first vertex
+    /// stays first, so flipping twice restores the exact order.
+    pub fn flip(&mut self) {
+        if let Some((_, rest)) = self.vertex_indices.split_first_mut() {
+            rest.reverse();
+        }
+    }
+
+    /// Unit normal of the whole loop, or None if the face is degenerate
+    /// (fewer than 3 vertices, or no area).
+    ///
+    /// Sums `(v[i+1] - v0) × (v[i] - v0)` over the edges (Newell's method),
+    /// which points outward for the winding used by the primitives. Unlike
+    /// the cross product of the first two edges, it holds when some corners
+    /// are straight, e.g. after a cut inserted a vertex into an edge.
+    pub fn try_normal(&self, vertices: &[Vector3]) -> Option<Vector3> {
+        if self.vertex_indices.len() < 3 {
+            return None;
+        }
+        let v0 = vertices[self.vertex_indices[0]];
+        let cross = self.edges().fold(Vector3::ZERO, |sum, (a, b)| {
+            sum + (vertices[b] - v0).cross(vertices[a] - v0)
+        });
+        let length_sq = cross.length_squared();
+        if length_sq < 1e-10 {
+            return None;
+        }
+        Some(cross / length_sq.sqrt())
+    }
+
+    /// Unit normal of the face, falling back to up for degenerate faces.
+    pub fn normal(&self, vertices: &[Vector3]) -> Vector3 {
+        self.try_normal(vertices).unwrap_or(Vector3::UP)
+    }
 }
 
 #[cfg(test)]
@@ -67,4 +111,74 @@ mod tests {
         assert!(!face.is_quad());
         assert_eq!(face.vertex_count(), 3);
     }
+
+    #[test]
+    fn test_face_edges_wrap_around() {
+        let face = Face::triangle(4, 7, 2);
+        let edges: Vec<_> = face.edges().collect();
+        assert_eq!(edges, vec![(4, 7), (7, 2), (2, 4)]);
+    }
+
+    #[test]
+    fn test_face_flip() {
+        let vertices = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+        let mut face = Face::quad(0, 1, 2, 3);
+
+        face.flip();
+        assert_eq!(face.vertex_indices, vec![0, 3, 2, 1]);
+        assert_eq!(face.normal(&vertices), Vector3::new(0.0, -1.0, 0.0));
+
+        face.flip();
+        assert_eq!(face, Face::quad(0, 1, 2, 3));
+    }
+
+    #[test]
+    fn test_face_normal() {
+        let vertices = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+        let face = Face::quad(0, 1, 2, 3);
+        assert_eq!(
+            face.try_normal(&vertices),
+            Some(Vector3::new(0.0, 1.0, 0.0))
+        );
+
+        let reversed = Face::quad(3, 2, 1, 0);
+        assert_eq!(reversed.normal(&vertices), Vector3::new(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn test_degenerate_face_normal() {
+        let vertices = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+        ];
+        let face = Face::triangle(0, 1, 2);
+        assert_eq!(face.try_normal(&vertices), None);
+        assert_eq!(face.normal(&vertices), Vector3::UP);
+        assert_eq!(Face::new(vec![0, 1]).try_normal(&vertices), None);
+    }
+
+    #[test]
+    fn test_normal_with_straight_first_corner() {
+        // Quad with an extra vertex on its first edge
+        let vertices = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.5, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+        let face = Face::new(vec![0, 1, 2, 3, 4]);
+        assert_eq!(face.normal(&vertices), Vector3::new(0.0, 1.0, 0.0));
+    }
 }
@@ -5,7 +5,7 @@
 
 use godot::prelude::Vector3;
 
-use super::Face;
+use super::{Face, PreviewState};
 
 /// The source of truth for blockot geometry.
 /// Contains vertices and faces in a pure Rust representation.
@@ -19,6 +19,9 @@ pub struct BlockotGeometry {
 
     /// Flag indicating if geometry has been modified since last cache rebuild
     pub dirty: bool,
+
+    /// In-progress transform preview (visual only, never compared or saved)
+    pub(super) preview: Option<PreviewState>,
 }
 
 impl BlockotGeometry {
@@ -28,6 +31,7 @@ impl BlockotGeometry {
             vertices: Vec::new(),
             faces: Vec::new(),
             dirty: true,
+            preview: None,
         }
     }
 
@@ -37,6 +41,7 @@ impl BlockotGeometry {
             vertices: Vec::with_capacity(vertex_count),
             faces: Vec::with_capacity(face_count),
             dirty: true,
+            preview: None,
         }
     }
 
@@ -8,8 +8,12 @@
 
 mod face;
 mod mesh;
+mod preview;
 pub mod primitives;
 pub mod serialization;
+mod topology;
 
 pub use face::Face;
 pub use mesh::BlockotGeometry;
+pub use preview::PreviewState;
+pub use topology::{edge_key, Edge, MeshTopology, TopologyCache};
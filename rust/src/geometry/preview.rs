@@ -0,0 +1,185 @@
+// geometry/preview.rs - PreviewState for drag operations
+//
+// While a modal tool runs, the committed vertices stay untouched and the
+// previewed positions live here. Rendering goes through `render_vertices()`.
+// Committing is the tool's job: it cancels the preview and executes a command.
+// [Source: architecture.md#Preview-State-Pattern]
+
+use std::borrow::Cow;
+
+use godot::prelude::Vector3;
+
+use super::BlockotGeometry;
+
+/// Previewed positions for a set of vertices (visual only, not committed).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewState {
+    /// Vertices being previewed
+    affected_indices: Vec<usize>,
+    /// Previewed position of each affected vertex (parallel to `affected_indices`)
+    positions: Vec<Vector3>,
+}
+
+impl PreviewState {
+    /// Returns the vertices being previewed.
+    pub fn affected_indices(&self) -> &[usize] {
+        &self.affected_indices
+    }
+
+    /// Returns the previewed positions, parallel to `affected_indices()`.
+    pub fn positions(&self) -> &[Vector3] {
+        &self.positions
+    }
+}
+
+impl BlockotGeometry {
+    /// Start previewing a transform of the given vertices (user presses G/R/S).
+    ///
+    /// Out-of-range indices are dropped. Replaces any preview already running.
+    pub fn begin_preview(&mut self, indices: Vec<usize>) {
+        let affected_indices: Vec<usize> = indices
+            .into_iter()
+            .filter(|&i| i < self.vertices.len())
+            .collect();
+        let positions = affected_indices.iter().map(|&i| self.vertices[i]).collect();
+
+        self.preview = Some(PreviewState {
+            affected_indices,
+            positions,
+        });
+        self.dirty = true;
+    }
+
+    /// Update the preview (during drag).
+    ///
+    /// `transform` maps `(vertex index, committed position)` to the previewed
+    /// position, so the preview is always computed from the committed source.
+    /// Only marks the geometry dirty when a previewed position changed, so
+    /// mouse motion that leaves the result alone does not rebuild the mesh.
+    /// Does nothing when no preview is active.
+    pub fn update_preview(&mut self, transform: impl Fn(usize, Vector3) -> Vector3) {
+        let Some(preview) = &mut self.preview else {
+            return;
+        };
+        for (pos, &index) in preview.positions.iter_mut().zip(&preview.affected_indices) {
+            let new_pos = transform(index, self.vertices[index]);
+            if *pos != new_pos {
+                *pos = new_pos;
+                self.dirty = true;
+            }
+        }
+    }
+
+    /// Cancel the preview (Escape/right-click): visuals return to the committed source.
+    pub fn cancel_preview(&mut self) {
+        if self.preview.take().is_some() {
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the active preview, if any.
+    pub fn preview(&self) -> Option<&PreviewState> {
+        self.preview.as_ref()
+    }
+
+    /// Returns true while a preview is active.
+    pub fn is_previewing(&self) -> bool {
+        self.preview.is_some()
+    }
+
+    /// Vertex positions for rendering: committed positions with the preview applied.
+    pub fn render_vertices(&self) -> Cow<'_, [Vector3]> {
+        let Some(preview) = &self.preview else {
+            return Cow::Borrowed(&self.vertices);
+        };
+        let mut vertices = self.vertices.clone();
+        for (&index, &pos) in preview.affected_indices.iter().zip(&preview.positions) {
+            vertices[index] = pos;
+        }
+        Cow::Owned(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::unit_cube;
+
+    #[test]
+    fn test_preview_leaves_source_untouched() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        geo.begin_preview(vec![0, 1]);
+        geo.update_preview(|_, v| v + Vector3::UP);
+
+        assert_eq!(geo, original);
+        assert!(geo.is_previewing());
+        let rendered = geo.render_vertices();
+        assert_eq!(rendered[0], original.vertices[0] + Vector3::UP);
+        assert_eq!(rendered[1], original.vertices[1] + Vector3::UP);
+        assert_eq!(rendered[2], original.vertices[2]);
+    }
+
+    #[test]
+    fn test_update_preview_is_computed_from_source() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        geo.begin_preview(vec![3]);
+        geo.update_preview(|_, v| v + Vector3::UP);
+        geo.update_preview(|_, v| v + Vector3::UP);
+
+        // Not accumulated: each update starts from the committed position
+        assert_eq!(geo.render_vertices()[3], original.vertices[3] + Vector3::UP);
+    }
+
+    #[test]
+    fn test_unchanged_update_stays_clean() {
+        let mut geo = unit_cube();
+        geo.begin_preview(vec![3]);
+        geo.update_preview(|_, v| v + Vector3::UP);
+        geo.mark_clean();
+
+        geo.update_preview(|_, v| v + Vector3::UP);
+        assert!(!geo.dirty);
+
+        geo.update_preview(|_, v| v + Vector3::RIGHT);
+        assert!(geo.dirty);
+    }
+
+    #[test]
+    fn test_cancel_preview_restores_render() {
+        let mut geo = unit_cube();
+        geo.begin_preview(vec![0]);
+        geo.update_preview(|_, v| v * 2.0);
+        geo.mark_clean();
+
+        geo.cancel_preview();
+
+        assert!(!geo.is_previewing());
+        assert!(geo.dirty);
+        assert_eq!(geo.render_vertices()[0], geo.vertices[0]);
+    }
+
+    #[test]
+    fn test_begin_preview_drops_invalid_indices() {
+        let mut geo = unit_cube();
+        geo.begin_preview(vec![1, 99]);
+
+        let preview = geo.preview().unwrap();
+        assert_eq!(preview.affected_indices(), &[1]);
+        assert_eq!(preview.positions(), &[geo.vertices[1]]);
+    }
+
+    #[test]
+    fn test_update_without_preview_is_noop() {
+        let mut geo = unit_cube();
+        geo.mark_clean();
+
+        geo.update_preview(|_, v| v * 2.0);
+
+        assert!(!geo.dirty);
+        assert!(matches!(geo.render_vertices(), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,471 @@
+// geometry/topology.rs - Derived edge/face adjacency for BlockotGeometry
+//
+// BlockotGeometry only stores vertices and faces. MeshTopology derives the
+// edge list and adjacency tables from it so edge selection and topology
+// operations don't have to rediscover them ad hoc.
+//
+// Topology is DERIVED data: never edit it, rebuild it. TopologyCache rebuilds
+// whenever the geometry is dirty; the owner must also call invalidate() when it
+// clears the dirty flag (see BlockotNode::rebuild_array_mesh).
+//
+// PURE RUST - no Godot types.
+
+use std::collections::HashMap;
+
+use super::BlockotGeometry;
+
+/// An undirected edge as a pair of vertex indices, stored smallest index first.
+pub type Edge = (usize, usize);
+
+/// Normalize a vertex pair into an `Edge` key (smallest index first).
+pub fn edge_key(a: usize, b: usize) -> Edge {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Edge and adjacency tables derived from a `BlockotGeometry`.
+///
+/// Edges are numbered in order of first appearance while walking the faces,
+/// so the same geometry always produces the same edge order.
+#[derive(Debug, Clone, Default)]
+pub struct MeshTopology {
+    /// All unique edges
+    edges: Vec<Edge>,
+    /// Edge -> index into `edges`
+    edge_lookup: HashMap<Edge, usize>,
+    /// For each edge, the faces using it
+    edge_faces: Vec<Vec<usize>>,
+    /// For each face, its edges in winding order (edge i joins corner i and i+1)
+    face_edges: Vec<Vec<usize>>,
+    /// For each vertex, the edges touching it
+    vertex_edges: Vec<Vec<usize>>,
+    /// For each vertex, the faces using it
+    vertex_faces: Vec<Vec<usize>>,
+}
+
+impl MeshTopology {
+    /// Build the topology tables for the given geometry.
+    ///
+    /// Faces referencing out-of-range vertices and zero-length edges
+    /// (repeated consecutive indices) are skipped rather than panicking.
+    pub fn build(geo: &BlockotGeometry) -> Self {
+        let vertex_count = geo.vertices.len();
+        let mut topo = Self {
+            edges: Vec::new(),
+            edge_lookup: HashMap::new(),
+            edge_faces: Vec::new(),
+            face_edges: Vec::with_capacity(geo.faces.len()),
+            vertex_edges: vec![Vec::new(); vertex_count],
+            vertex_faces: vec![Vec::new(); vertex_count],
+        };
+
+        for (face_idx, face) in geo.faces.iter().enumerate() {
+            let indices = &face.vertex_indices;
+            let mut face_edge_ids = Vec::with_capacity(indices.len());
+
+            for (i, &a) in indices.iter().enumerate() {
+                let b = indices[(i + 1) % indices.len()];
+                if a >= vertex_count || b >= vertex_count || a == b {
+                    continue;
+                }
+
+                let id = topo.insert_edge(edge_key(a, b));
+                if !topo.edge_faces[id].contains(&face_idx) {
+                    topo.edge_faces[id].push(face_idx);
+                }
+                face_edge_ids.push(id);
+
+                if !topo.vertex_faces[a].contains(&face_idx) {
+                    topo.vertex_faces[a].push(face_idx);
+                }
+            }
+
+            topo.face_edges.push(face_edge_ids);
+        }
+
+        topo
+    }
+
+    /// Insert an edge if it is new, returning its index.
+    fn insert_edge(&mut self, key: Edge) -> usize {
+        if let Some(&id) = self.edge_lookup.get(&key) {
+            return id;
+        }
+        let id = self.edges.len();
+        self.edges.push(key);
+        self.edge_lookup.insert(key, id);
+        self.edge_faces.push(Vec::new());
+        self.vertex_edges[key.0].push(id);
+        self.vertex_edges[key.1].push(id);
+        id
+    }
+
+    /// Returns all unique edges.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the number of unique edges.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns true if the two vertices are joined by an edge.
+    pub fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.edge_lookup.contains_key(&edge_key(a, b))
+    }
+
+    /// Returns the faces sharing the edge between `a` and `b` (empty if no such edge).
+    pub fn edge_faces(&self, a: usize, b: usize) -> &[usize] {
+        match self.edge_lookup.get(&edge_key(a, b)) {
+            Some(&id) => &self.edge_faces[id],
+            None => &[],
+        }
+    }
+
+    /// Returns the faces using the given vertex (empty if out of range).
+    pub fn vertex_faces(&self, vertex: usize) -> &[usize] {
+        self.vertex_faces.get(vertex).map_or(&[], |f| f.as_slice())
+    }
+
+    /// Returns the edges touching the given vertex.
+    pub fn vertex_edges(&self, vertex: usize) -> Vec<Edge> {
+        self.vertex_edges.get(vertex).map_or_else(Vec::new, |ids| {
+            ids.iter().map(|&id| self.edges[id]).collect()
+        })
+    }
+
+    /// Returns the vertices joined to the given vertex by an edge.
+    pub fn vertex_neighbours(&self, vertex: usize) -> Vec<usize> {
+        self.vertex_edges(vertex)
+            .into_iter()
+            .map(|(a, b)| if a == vertex { b } else { a })
+            .collect()
+    }
+
+    /// Returns the edges of a face in winding order (empty if out of range).
+    pub fn face_edges(&self, face: usize) -> Vec<Edge> {
+        self.face_edges.get(face).map_or_else(Vec::new, |ids| {
+            ids.iter().map(|&id| self.edges[id]).collect()
+        })
+    }
+
+    /// Returns the faces sharing at least one edge with the given face.
+    pub fn face_neighbours(&self, face: usize) -> Vec<usize> {
+        let mut neighbours = Vec::new();
+        if let Some(ids) = self.face_edges.get(face) {
+            for &id in ids {
+                for &other in &self.edge_faces[id] {
+                    if other != face && !neighbours.contains(&other) {
+                        neighbours.push(other);
+                    }
+                }
+            }
+        }
+        neighbours
+    }
+
+    /// Returns true if exactly one face uses the edge (an open border).
+    pub fn is_boundary_edge(&self, a: usize, b: usize) -> bool {
+        self.edge_faces(a, b).len() == 1
+    }
+
+    /// Returns all edges used by exactly one face.
+    pub fn boundary_edges(&self) -> Vec<Edge> {
+        self.edges
+            .iter()
+            .zip(&self.edge_faces)
+            .filter(|(_, faces)| faces.len() == 1)
+            .map(|(&edge, _)| edge)
+            .collect()
+    }
+
+    /// Returns the edge loop running through the edge `a`-`b`.
+    ///
+    /// A loop continues through a vertex with four edges by taking the edge that
+    /// shares no face with the current one, and follows open borders through
+    /// three-edge border vertices. It stops at poles and corners.
+    /// Edges are returned in walking order; empty if `a`-`b` is not an edge.
+    pub fn edge_loop(&self, a: usize, b: usize) -> Vec<Edge> {
+        let Some(&start) = self.edge_lookup.get(&edge_key(a, b)) else {
+            return Vec::new();
+        };
+
+        let mut forward = vec![start];
+        if self.walk_loop(start, b, &mut forward) {
+            return self.to_edges(&forward);
+        }
+
+        let mut backward = vec![start];
+        self.walk_loop(start, a, &mut backward);
+        backward.reverse();
+        backward.extend_from_slice(&forward[1..]);
+        self.to_edges(&backward)
+    }
+
+    /// Returns the edge ring through the edge `a`-`b`.
+    ///
+    /// A ring steps across quads to the opposite edge, then into the next face
+    /// sharing that edge. It stops at non-quads and open borders.
+    /// Edges are returned in walking order; empty if `a`-`b` is not an edge.
+    pub fn edge_ring(&self, a: usize, b: usize) -> Vec<Edge> {
+        let Some(&start) = self.edge_lookup.get(&edge_key(a, b)) else {
+            return Vec::new();
+        };
+
+        let faces = &self.edge_faces[start];
+        let mut forward = vec![start];
+        if let Some(&face) = faces.first() {
+            if self.walk_ring(start, face, &mut forward) {
+                return self.to_edges(&forward);
+            }
+        }
+
+        let mut backward = vec![start];
+        if let Some(&face) = faces.get(1) {
+            self.walk_ring(start, face, &mut backward);
+        }
+        backward.reverse();
+        backward.extend_from_slice(&forward[1..]);
+        self.to_edges(&backward)
+    }
+
+    /// Walk an edge loop from `start` through `vertex`, appending edges to `out`.
+    /// Returns true if the walk closed back on an edge already visited.
+    fn walk_loop(&self, start: usize, vertex: usize, out: &mut Vec<usize>) -> bool {
+        let mut edge = start;
+        let mut vertex = vertex;
+
+        while let Some(next) = self.loop_continuation(edge, vertex) {
+            if out.contains(&next) {
+                return true;
+            }
+            out.push(next);
+            let (a, b) = self.edges[next];
+            vertex = if a == vertex { b } else { a };
+            edge = next;
+        }
+        false
+    }
+
+    /// The edge continuing the loop from `edge` through `vertex`, if any.
+    fn loop_continuation(&self, edge: usize, vertex: usize) -> Option<usize> {
+        let around = &self.vertex_edges[vertex];
+        let edge_faces = &self.edge_faces[edge];
+        let is_border = edge_faces.len() == 1;
+        if !(around.len() == 4 || (around.len() == 3 && is_border)) {
+            return None;
+        }
+
+        let mut candidates = around.iter().copied().filter(|&other| {
+            other != edge
+                && !self.edge_faces[other]
+                    .iter()
+                    .any(|f| edge_faces.contains(f))
+        });
+        let next = candidates.next()?;
+        if candidates.next().is_some() {
+            return None;
+        }
+        Some(next)
+    }
+
+    /// Walk an edge ring from `start` into `face`, appending edges to `out`.
+    /// Returns true if the walk closed back on an edge already visited.
+    fn walk_ring(&self, start: usize, face: usize, out: &mut Vec<usize>) -> bool {
+        let mut edge = start;
+        let mut face = face;
+
+        loop {
+            let ids = &self.face_edges[face];
+            if ids.len() != 4 {
+                return false;
+            }
+            let Some(pos) = ids.iter().position(|&id| id == edge) else {
+                return false;
+            };
+            let opposite = ids[(pos + 2) % 4];
+            if out.contains(&opposite) {
+                return true;
+            }
+            out.push(opposite);
+
+            let Some(&next_face) = self.edge_faces[opposite].iter().find(|&&f| f != face) else {
+                return false;
+            };
+            edge = opposite;
+            face = next_face;
+        }
+    }
+
+    fn to_edges(&self, ids: &[usize]) -> Vec<Edge> {
+        ids.iter().map(|&id| self.edges[id]).collect()
+    }
+}
+
+/// Lazily rebuilt `MeshTopology`, invalidated by the geometry's dirty flag.
+///
+/// `get()` rebuilds whenever the geometry is dirty or nothing is cached yet.
+/// Whoever clears `BlockotGeometry::dirty` must call `invalidate()` at the same
+/// time, otherwise a change consumed by the mesh rebuild would go unnoticed.
+#[derive(Debug, Clone, Default)]
+pub struct TopologyCache {
+    topology: Option<MeshTopology>,
+}
+
+impl TopologyCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns up-to-date topology for the geometry, rebuilding if needed.
+    pub fn get(&mut self, geo: &BlockotGeometry) -> &MeshTopology {
+        if geo.dirty || self.topology.is_none() {
+            self.topology = Some(MeshTopology::build(geo));
+        }
+        self.topology.get_or_insert_with(MeshTopology::default)
+    }
+
+    /// Drop the cached topology so the next `get()` rebuilds it.
+    pub fn invalidate(&mut self) {
+        self.topology = None;
+    }
+
+    /// Returns true if topology is currently cached.
+    pub fn is_cached(&self) -> bool {
+        self.topology.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, single_quad, unit_cube};
+
+    #[test]
+    fn test_edge_key_normalizes() {
+        assert_eq!(edge_key(3, 1), (1, 3));
+        assert_eq!(edge_key(1, 3), (1, 3));
+    }
+
+    #[test]
+    fn test_cube_edges() {
+        let topo = MeshTopology::build(&unit_cube());
+        assert_eq!(topo.edge_count(), 12);
+        // Closed mesh: every edge has two faces
+        for &(a, b) in topo.edges() {
+            assert_eq!(topo.edge_faces(a, b).len(), 2, "edge ({}, {})", a, b);
+        }
+        assert!(topo.boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_cube_edge_faces() {
+        let topo = MeshTopology::build(&unit_cube());
+        // Edge 0-1 is shared by Front (0) and Bottom (3)
+        let mut faces = topo.edge_faces(1, 0).to_vec();
+        faces.sort();
+        assert_eq!(faces, vec![0, 3]);
+        assert!(topo.edge_faces(0, 6).is_empty());
+        assert!(!topo.has_edge(0, 6));
+    }
+
+    #[test]
+    fn test_cube_vertex_adjacency() {
+        let topo = MeshTopology::build(&unit_cube());
+        // Vertex 0 touches Front, Bottom and Left
+        let mut faces = topo.vertex_faces(0).to_vec();
+        faces.sort();
+        assert_eq!(faces, vec![0, 3, 5]);
+
+        let mut neighbours = topo.vertex_neighbours(0);
+        neighbours.sort();
+        assert_eq!(neighbours, vec![1, 3, 4]);
+        assert!(topo.vertex_faces(100).is_empty());
+    }
+
+    #[test]
+    fn test_face_edges_and_neighbours() {
+        let topo = MeshTopology::build(&unit_cube());
+        assert_eq!(topo.face_edges(0), vec![(0, 1), (1, 5), (4, 5), (0, 4)]);
+
+        let mut neighbours = topo.face_neighbours(2); // Top
+        neighbours.sort();
+        assert_eq!(neighbours, vec![0, 1, 4, 5]);
+    }
+
+    #[test]
+    fn test_single_quad_is_all_boundary() {
+        let topo = MeshTopology::build(&single_quad());
+        assert_eq!(topo.boundary_edges().len(), 4);
+        assert!(topo.is_boundary_edge(0, 1));
+    }
+
+    #[test]
+    fn test_cube_edge_ring() {
+        let topo = MeshTopology::build(&unit_cube());
+        let mut ring = topo.edge_ring(0, 1);
+        ring.sort();
+        assert_eq!(ring, vec![(0, 1), (2, 3), (4, 5), (6, 7)]);
+    }
+
+    #[test]
+    fn test_cube_edge_loop_stops_at_poles() {
+        // Cube corners have three edges, so a loop is just the starting edge
+        let topo = MeshTopology::build(&unit_cube());
+        assert_eq!(topo.edge_loop(0, 1), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_grid_edge_loop_crosses_interior() {
+        // 3x3 grid: vertex (x, z) has index z * 4 + x
+        let topo = MeshTopology::build(&quad_grid(3, 3));
+        let mut edge_loop = topo.edge_loop(5, 9);
+        edge_loop.sort();
+        assert_eq!(edge_loop, vec![(1, 5), (5, 9), (9, 13)]);
+    }
+
+    #[test]
+    fn test_grid_edge_loop_follows_border() {
+        let topo = MeshTopology::build(&quad_grid(3, 3));
+        let mut edge_loop = topo.edge_loop(1, 2);
+        edge_loop.sort();
+        assert_eq!(edge_loop, vec![(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_grid_edge_ring_stops_at_border() {
+        let topo = MeshTopology::build(&quad_grid(3, 3));
+        let mut ring = topo.edge_ring(5, 9);
+        ring.sort();
+        assert_eq!(ring, vec![(4, 8), (5, 9), (6, 10), (7, 11)]);
+    }
+
+    #[test]
+    fn test_missing_edge_queries_are_empty() {
+        let topo = MeshTopology::build(&unit_cube());
+        assert!(topo.edge_loop(0, 6).is_empty());
+        assert!(topo.edge_ring(0, 6).is_empty());
+    }
+
+    #[test]
+    fn test_cache_rebuilds_when_dirty() {
+        let mut geo = single_quad();
+        geo.mark_clean();
+        let mut cache = TopologyCache::new();
+        assert!(!cache.is_cached());
+        assert_eq!(cache.get(&geo).edge_count(), 4);
+
+        // Change topology and mark dirty: cache must pick it up
+        geo.faces.clear();
+        geo.mark_dirty();
+        assert_eq!(cache.get(&geo).edge_count(), 0);
+
+        cache.invalidate();
+        assert!(!cache.is_cached());
+    }
+}
@@ -1,10 +1,13 @@
-// selection/hit_test.rs - Screen-space vertex hit testing
+// selection/hit_test.rs - Screen-space vertex and edge hit testing
 //
-// Pure Rust function for finding the closest vertex to a mouse click.
+// Pure Rust functions for finding the closest vertex or edge to a mouse click,
+// and the vertices inside a box (marquee) selection rectangle.
 // Uses projected 2D screen positions (None = behind camera).
 
 use godot::prelude::Vector2;
 
+use crate::geometry::Edge;
+
 /// Find the closest projected vertex to the mouse position within a pixel threshold.
 ///
 /// Returns the index of the closest vertex, or None if no vertex is within range.
@@ -30,6 +33,72 @@ pub fn find_closest_vertex(
     best_index
 }
 
+/// Find the closest projected edge to the mouse position within a pixel threshold.
+///
+/// Distance is measured from the mouse to the projected edge segment, so clicks
+/// anywhere along the edge count, not just near its midpoint.
+/// Edges with an endpoint behind the camera (`None`) or out of range are skipped.
+pub fn find_closest_edge(
+    screen_positions: &[Option<Vector2>],
+    edges: &[Edge],
+    mouse_pos: Vector2,
+    threshold: f32,
+) -> Option<Edge> {
+    let mut best_edge = None;
+    let mut best_dist_sq = threshold * threshold;
+
+    for &(a, b) in edges {
+        let (Some(Some(pa)), Some(Some(pb))) = (screen_positions.get(a), screen_positions.get(b))
+        else {
+            continue;
+        };
+        let dist_sq = distance_to_segment_squared(mouse_pos, *pa, *pb);
+        if dist_sq < best_dist_sq {
+            best_dist_sq = dist_sq;
+            best_edge = Some((a, b));
+        }
+    }
+
+    best_edge
+}
+
+/// Find all projected vertices inside the screen rectangle spanned by two corners.
+///
+/// The corners may be given in any order (drag direction doesn't matter).
+/// Points on the rectangle border count as inside. Vertices behind the camera
+/// (`None`) are skipped. Returns indices in ascending order.
+pub fn find_vertices_in_rect(
+    screen_positions: &[Option<Vector2>],
+    corner_a: Vector2,
+    corner_b: Vector2,
+) -> Vec<usize> {
+    let (min_x, max_x) = (corner_a.x.min(corner_b.x), corner_a.x.max(corner_b.x));
+    let (min_y, max_y) = (corner_a.y.min(corner_b.y), corner_a.y.max(corner_b.y));
+
+    screen_positions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, pos)| {
+            let p = (*pos)?;
+            let inside = (min_x..=max_x).contains(&p.x) && (min_y..=max_y).contains(&p.y);
+            inside.then_some(i)
+        })
+        .collect()
+}
+
+/// Squared distance from point `p` to the segment `a`-`b`.
+fn distance_to_segment_squared(p: Vector2, a: Vector2, b: Vector2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_squared();
+    let t = if length_sq > 0.0 {
+        ((p - a).dot(ab) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let closest = a + ab * t;
+    (p.x - closest.x).powi(2) + (p.y - closest.y).powi(2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +181,124 @@ mod tests {
         let result = find_closest_vertex(&positions, mouse, 15.0);
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_find_closest_edge_along_segment() {
+        let positions = vec![
+            Some(Vector2::new(100.0, 100.0)),
+            Some(Vector2::new(300.0, 100.0)),
+            Some(Vector2::new(300.0, 300.0)),
+        ];
+        let edges = vec![(0, 1), (1, 2)];
+        // Far from both endpoints, but 5px from the middle of edge 0-1
+        let mouse = Vector2::new(200.0, 105.0);
+
+        let result = find_closest_edge(&positions, &edges, mouse, 15.0);
+        assert_eq!(result, Some((0, 1)));
+    }
+
+    #[test]
+    fn test_find_closest_edge_closest_wins() {
+        let positions = vec![
+            Some(Vector2::new(0.0, 0.0)),
+            Some(Vector2::new(100.0, 0.0)),
+            Some(Vector2::new(0.0, 10.0)),
+            Some(Vector2::new(100.0, 10.0)),
+        ];
+        let edges = vec![(0, 1), (2, 3)];
+        let mouse = Vector2::new(50.0, 7.0);
+
+        let result = find_closest_edge(&positions, &edges, mouse, 15.0);
+        assert_eq!(result, Some((2, 3)));
+    }
+
+    #[test]
+    fn test_find_closest_edge_beyond_endpoint() {
+        let positions = vec![Some(Vector2::new(0.0, 0.0)), Some(Vector2::new(100.0, 0.0))];
+        // On the edge's line, but 20px past its end
+        let mouse = Vector2::new(120.0, 0.0);
+
+        let result = find_closest_edge(&positions, &[(0, 1)], mouse, 15.0);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_find_closest_edge_behind_camera_skipped() {
+        let positions = vec![
+            None,
+            Some(Vector2::new(100.0, 0.0)),
+            Some(Vector2::new(100.0, 100.0)),
+        ];
+        let edges = vec![(0, 1), (1, 2)];
+        let mouse = Vector2::new(50.0, 0.0);
+
+        let result = find_closest_edge(&positions, &edges, mouse, 15.0);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_find_closest_edge_degenerate_segment() {
+        let positions = vec![
+            Some(Vector2::new(10.0, 10.0)),
+            Some(Vector2::new(10.0, 10.0)),
+        ];
+        let mouse = Vector2::new(12.0, 10.0);
+
+        let result = find_closest_edge(&positions, &[(0, 1)], mouse, 15.0);
+        assert_eq!(result, Some((0, 1)));
+    }
+
+    #[test]
+    fn test_find_vertices_in_rect() {
+        let positions = vec![
+            Some(Vector2::new(10.0, 10.0)),
+            Some(Vector2::new(50.0, 50.0)),
+            Some(Vector2::new(200.0, 20.0)),
+            None,
+        ];
+
+        let inside = find_vertices_in_rect(
+            &positions,
+            Vector2::new(0.0, 0.0),
+            Vector2::new(100.0, 100.0),
+        );
+        assert_eq!(inside, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_find_vertices_in_rect_reversed_corners() {
+        let positions = vec![
+            Some(Vector2::new(10.0, 10.0)),
+            Some(Vector2::new(50.0, 50.0)),
+        ];
+
+        // Dragging up-left gives the same result as down-right
+        let inside = find_vertices_in_rect(
+            &positions,
+            Vector2::new(60.0, 60.0),
+            Vector2::new(40.0, 40.0),
+        );
+        assert_eq!(inside, vec![1]);
+    }
+
+    #[test]
+    fn test_find_vertices_in_rect_border_inclusive() {
+        let positions = vec![Some(Vector2::new(100.0, 50.0))];
+
+        let inside = find_vertices_in_rect(
+            &positions,
+            Vector2::new(0.0, 0.0),
+            Vector2::new(100.0, 50.0),
+        );
+        assert_eq!(inside, vec![0]);
+    }
+
+    #[test]
+    fn test_find_vertices_in_rect_behind_camera_skipped() {
+        let positions = vec![None, None];
+
+        let inside =
+            find_vertices_in_rect(&positions, Vector2::new(-1e6, -1e6), Vector2::new(1e6, 1e6));
+        assert!(inside.is_empty());
+    }
 }
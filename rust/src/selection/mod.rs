@@ -9,12 +9,16 @@
 
 pub mod hit_test;
 pub mod modes;
+pub mod raycast;
 
-pub use hit_test::find_closest_vertex;
-pub use modes::SelectionMode;
+pub use hit_test::{find_closest_edge, find_closest_vertex, find_vertices_in_rect};
+pub use modes::{SelectOp, SelectionMode};
+pub use raycast::{is_vertex_occluded, raycast_faces, RayHit};
 
 use std::collections::HashSet;
 
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face, MeshTopology};
+
 /// Vertex-canonical selection model.
 ///
 /// Transforms always operate on `vertex_indices` — no conversion needed.
@@ -30,6 +34,8 @@ pub struct Selection {
     pub selected_edges: Vec<(usize, usize)>,
     /// Rendering hints: which face indices are selected (for Face mode display)
     pub selected_faces: Vec<usize>,
+    /// Vertices picked one by one, oldest first (see `ordered_vertices`)
+    picked: Vec<usize>,
 }
 
 impl Selection {
@@ -40,6 +46,7 @@ impl Selection {
             vertex_indices: HashSet::new(),
             selected_edges: Vec::new(),
             selected_faces: Vec::new(),
+            picked: Vec::new(),
         }
     }
 
@@ -48,6 +55,7 @@ impl Selection {
         self.vertex_indices.clear();
         self.selected_edges.clear();
         self.selected_faces.clear();
+        self.picked.clear();
     }
 
     /// Returns true if nothing is selected.
@@ -61,11 +69,233 @@ impl Selection {
     pub fn select_vertex(&mut self, index: usize) {
         self.clear();
         self.vertex_indices.insert(index);
+        self.picked.push(index);
+    }
+
+    /// Select a single edge, clearing any previous selection.
+    ///
+    /// Both endpoints become the canonical selection; the edge is recorded
+    /// as a rendering hint.
+    pub fn select_edge(&mut self, a: usize, b: usize) {
+        self.clear();
+        self.vertex_indices.insert(a);
+        self.vertex_indices.insert(b);
+        self.selected_edges.push(edge_key(a, b));
+    }
+
+    /// Select a single face, clearing any previous selection.
+    ///
+    /// All of the face's vertices become the canonical selection; the face
+    /// index is recorded as a rendering hint.
+    pub fn select_face(&mut self, face_index: usize, face: &Face) {
+        self.clear();
+        self.vertex_indices
+            .extend(face.vertex_indices.iter().copied());
+        self.selected_faces.push(face_index);
+    }
+
+    /// Select every vertex of the geometry, with matching edge/face hints.
+    pub fn select_all(&mut self, geo: &BlockotGeometry, topology: &MeshTopology) {
+        self.replace_vertices((0..geo.vertices.len()).collect());
+        self.update_hints(geo, topology);
+    }
+
+    /// Replace the selected vertices wholesale (e.g. when undo restores an
+    /// earlier selection), returning the previous ones. The pick order is
+    /// forgotten, since it may name vertices that were renumbered meanwhile.
+    /// Hints are not updated; call `update_hints` afterwards.
+    pub fn replace_vertices(&mut self, vertices: HashSet<usize>) -> HashSet<usize> {
+        self.picked.clear();
+        std::mem::replace(&mut self.vertex_indices, vertices)
+    }
+
+    /// Apply a vertex pick (click) using the given operation.
+    pub fn pick_vertex(&mut self, index: usize, op: SelectOp) {
+        match op {
+            SelectOp::Replace => self.select_vertex(index),
+            SelectOp::Add => {
+                self.vertex_indices.insert(index);
+            }
+            SelectOp::Toggle => self.toggle_vertex(index),
+            SelectOp::Subtract => {
+                self.vertex_indices.remove(&index);
+            }
+        }
+        // Replace already restarted the pick order
+        if op != SelectOp::Replace {
+            self.picked.retain(|&v| v != index);
+            if self.vertex_indices.contains(&index) {
+                self.picked.push(index);
+            }
+        }
+    }
+
+    /// The selected vertices in the order they were picked one by one, oldest
+    /// first, followed by the others (e.g. from a box selection) by index.
+    /// "First" and "last" for operations like merge follow this order.
+    pub fn ordered_vertices(&self) -> Vec<usize> {
+        let mut ordered: Vec<usize> = self
+            .picked
+            .iter()
+            .copied()
+            .filter(|v| self.vertex_indices.contains(v))
+            .collect();
+        let mut rest: Vec<usize> = self
+            .vertex_indices
+            .iter()
+            .copied()
+            .filter(|v| !ordered.contains(v))
+            .collect();
+        rest.sort_unstable();
+        ordered.extend(rest);
+        ordered
+    }
+
+    /// Apply a multi-vertex pick (box selection) using the given operation.
+    ///
+    /// Replace with no vertices clears the selection. Hints are not updated;
+    /// call `update_hints` afterwards.
+    pub fn pick_vertices(&mut self, indices: &[usize], op: SelectOp) {
+        if op == SelectOp::Replace {
+            self.clear();
+        }
+        for &index in indices {
+            match op {
+                SelectOp::Replace | SelectOp::Add => {
+                    self.vertex_indices.insert(index);
+                }
+                SelectOp::Toggle => self.toggle_vertex(index),
+                SelectOp::Subtract => {
+                    self.vertex_indices.remove(&index);
+                }
+            }
+        }
+    }
+
+    /// Apply an edge pick (click) using the given operation.
+    ///
+    /// Removing an edge deselects its endpoints unless another selected edge
+    /// still uses them.
+    pub fn pick_edge(&mut self, a: usize, b: usize, op: SelectOp) {
+        let edge = edge_key(a, b);
+        match op {
+            SelectOp::Replace => self.select_edge(a, b),
+            SelectOp::Add => self.add_edge(edge),
+            SelectOp::Toggle if self.selected_edges.contains(&edge) => self.remove_edge(edge),
+            SelectOp::Toggle => self.add_edge(edge),
+            SelectOp::Subtract => self.remove_edge(edge),
+        }
+    }
+
+    /// Apply a face pick (click) using the given operation.
+    ///
+    /// Removing a face deselects its vertices unless another selected face
+    /// still uses them. Out-of-range face indices are ignored.
+    pub fn pick_face(&mut self, face_index: usize, geo: &BlockotGeometry, op: SelectOp) {
+        let Some(face) = geo.faces.get(face_index) else {
+            return;
+        };
+        match op {
+            SelectOp::Replace => self.select_face(face_index, face),
+            SelectOp::Add => self.add_face(face_index, face),
+            SelectOp::Toggle if self.selected_faces.contains(&face_index) => {
+                self.remove_face(face_index, geo)
+            }
+            SelectOp::Toggle => self.add_face(face_index, face),
+            SelectOp::Subtract => self.remove_face(face_index, geo),
+        }
+    }
+
+    fn add_edge(&mut self, edge: Edge) {
+        self.vertex_indices.insert(edge.0);
+        self.vertex_indices.insert(edge.1);
+        if !self.selected_edges.contains(&edge) {
+            self.selected_edges.push(edge);
+        }
+    }
+
+    fn remove_edge(&mut self, edge: Edge) {
+        self.selected_edges.retain(|&e| e != edge);
+        for v in [edge.0, edge.1] {
+            let still_used = self.selected_edges.iter().any(|&(a, b)| a == v || b == v);
+            if !still_used {
+                self.vertex_indices.remove(&v);
+            }
+        }
+    }
+
+    fn add_face(&mut self, face_index: usize, face: &Face) {
+        self.vertex_indices
+            .extend(face.vertex_indices.iter().copied());
+        if !self.selected_faces.contains(&face_index) {
+            self.selected_faces.push(face_index);
+        }
+    }
+
+    fn remove_face(&mut self, face_index: usize, geo: &BlockotGeometry) {
+        self.selected_faces.retain(|&f| f != face_index);
+        for &v in &geo.faces[face_index].vertex_indices {
+            let still_used = self.selected_faces.iter().any(|&f| {
+                geo.faces
+                    .get(f)
+                    .is_some_and(|face| face.vertex_indices.contains(&v))
+            });
+            if !still_used {
+                self.vertex_indices.remove(&v);
+            }
+        }
+    }
+
+    /// Switch selection mode, keeping the same selected vertices.
+    ///
+    /// Rendering hints are recomputed for the new mode (see `update_hints`).
+    pub fn set_mode(
+        &mut self,
+        mode: SelectionMode,
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+    ) {
+        self.mode = mode;
+        self.update_hints(geo, topology);
+    }
+
+    /// Recompute rendering hints from the canonical `vertex_indices`.
+    ///
+    /// Edges and faces whose vertices are all selected become selected.
+    pub fn update_hints(&mut self, geo: &BlockotGeometry, topology: &MeshTopology) {
+        self.selected_edges = topology
+            .edges()
+            .iter()
+            .copied()
+            .filter(|(a, b)| self.vertex_indices.contains(a) && self.vertex_indices.contains(b))
+            .collect();
+
+        self.selected_faces = geo
+            .faces
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| {
+                !face.vertex_indices.is_empty()
+                    && face
+                        .vertex_indices
+                        .iter()
+                        .all(|i| self.vertex_indices.contains(i))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+    }
+
+    /// Drop selected vertices that no longer exist in the geometry (e.g. after
+    /// undoing a command that added them), then recompute the hints.
+    pub fn retain_valid(&mut self, geo: &BlockotGeometry, topology: &MeshTopology) {
+        self.vertex_indices.retain(|&v| v < geo.vertices.len());
+        self.update_hints(geo, topology);
     }
 
     /// Toggle a vertex in the selection (add if absent, remove if present).
     ///
     /// Used for multi-select (Ctrl+click). Does not clear existing selection.
+    /// Rendering hints are left untouched; call `update_hints` if needed.
     pub fn toggle_vertex(&mut self, index: usize) {
         if !self.vertex_indices.remove(&index) {
             self.vertex_indices.insert(index);
@@ -82,6 +312,7 @@ impl Default for Selection {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::unit_cube;
 
     #[test]
     fn test_new_selection_is_empty() {
@@ -198,6 +429,217 @@ mod tests {
         assert!(sel.vertex_indices.contains(&2));
     }
 
+    #[test]
+    fn test_select_edge_selects_both_vertices() {
+        let mut sel = Selection::new(SelectionMode::Edge);
+        sel.select_vertex(7);
+
+        sel.select_edge(5, 1);
+
+        assert_eq!(sel.vertex_indices.len(), 2);
+        assert!(sel.vertex_indices.contains(&1));
+        assert!(sel.vertex_indices.contains(&5));
+        assert_eq!(sel.selected_edges, vec![(1, 5)]);
+    }
+
+    #[test]
+    fn test_select_face_selects_all_vertices() {
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.select_edge(0, 1);
+
+        sel.select_face(2, &Face::quad(4, 5, 6, 7));
+
+        assert_eq!(sel.vertex_indices.len(), 4);
+        assert!((4..8).all(|i| sel.vertex_indices.contains(&i)));
+        assert!(sel.selected_edges.is_empty());
+        assert_eq!(sel.selected_faces, vec![2]);
+    }
+
+    #[test]
+    fn test_set_mode_face_from_vertices() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let mut sel = Selection::new(SelectionMode::Vertex);
+        // Top face vertices
+        sel.vertex_indices.extend([4, 5, 6, 7]);
+
+        sel.set_mode(SelectionMode::Face, &cube, &topology);
+
+        assert_eq!(sel.mode, SelectionMode::Face);
+        assert_eq!(sel.selected_faces, vec![2]);
+        assert_eq!(sel.selected_edges.len(), 4);
+        assert_eq!(sel.vertex_indices.len(), 4);
+    }
+
+    #[test]
+    fn test_set_mode_edge_from_vertices() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let mut sel = Selection::new(SelectionMode::Vertex);
+        sel.vertex_indices.extend([0, 1, 6]);
+
+        sel.set_mode(SelectionMode::Edge, &cube, &topology);
+
+        assert_eq!(sel.selected_edges, vec![(0, 1)]);
+        assert!(sel.selected_faces.is_empty());
+        // Lone vertex 6 stays selected
+        assert!(sel.vertex_indices.contains(&6));
+    }
+
+    #[test]
+    fn test_set_mode_back_to_vertex_keeps_selection() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.select_face(0, &cube.faces[0]);
+
+        sel.set_mode(SelectionMode::Vertex, &cube, &topology);
+
+        assert_eq!(sel.mode, SelectionMode::Vertex);
+        assert_eq!(sel.vertex_indices.len(), 4);
+        assert_eq!(sel.selected_faces, vec![0]);
+    }
+
+    #[test]
+    fn test_ordered_vertices_follow_picks() {
+        let mut sel = Selection::default();
+        sel.pick_vertex(7, SelectOp::Replace);
+        sel.pick_vertices(&[1, 4], SelectOp::Add);
+        sel.pick_vertex(2, SelectOp::Add);
+        sel.pick_vertex(5, SelectOp::Toggle);
+        assert_eq!(sel.ordered_vertices(), vec![7, 2, 5, 1, 4]);
+
+        // Re-picking moves a vertex to the end, deselecting drops it
+        sel.pick_vertex(7, SelectOp::Add);
+        sel.pick_vertex(2, SelectOp::Subtract);
+        assert_eq!(sel.ordered_vertices(), vec![5, 7, 1, 4]);
+
+        // Vertices changed outside of picks still show up, by index
+        sel.vertex_indices.remove(&5);
+        sel.vertex_indices.insert(0);
+        assert_eq!(sel.ordered_vertices(), vec![7, 0, 1, 4]);
+    }
+
+    #[test]
+    fn test_replace_vertices_forgets_pick_order() {
+        let mut sel = Selection::default();
+        sel.pick_vertex(7, SelectOp::Replace);
+        sel.pick_vertex(2, SelectOp::Add);
+
+        // The restored selection reuses 7 and 2, now meaning other vertices
+        let previous = sel.replace_vertices(HashSet::from([2, 7, 3]));
+        assert_eq!(previous, HashSet::from([7, 2]));
+        assert_eq!(sel.ordered_vertices(), vec![2, 3, 7]);
+    }
+
+    #[test]
+    fn test_pick_vertex_ops() {
+        let mut sel = Selection::new(SelectionMode::Vertex);
+        sel.pick_vertex(1, SelectOp::Replace);
+        sel.pick_vertex(2, SelectOp::Add);
+        sel.pick_vertex(3, SelectOp::Toggle);
+        assert_eq!(sel.vertex_indices.len(), 3);
+
+        sel.pick_vertex(3, SelectOp::Toggle);
+        sel.pick_vertex(1, SelectOp::Subtract);
+        assert_eq!(sel.vertex_indices.len(), 1);
+        assert!(sel.vertex_indices.contains(&2));
+
+        sel.pick_vertex(5, SelectOp::Replace);
+        assert_eq!(sel.vertex_indices.len(), 1);
+        assert!(sel.vertex_indices.contains(&5));
+    }
+
+    #[test]
+    fn test_pick_vertices_box_ops() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_face(0, &cube, SelectOp::Replace);
+
+        // Replace drops the previous selection and its hints
+        sel.pick_vertices(&[4, 5, 6, 7], SelectOp::Replace);
+        sel.update_hints(&cube, &topology);
+        assert_eq!(sel.vertex_indices.len(), 4);
+        assert_eq!(sel.selected_faces, vec![2]);
+
+        sel.pick_vertices(&[0, 1], SelectOp::Add);
+        sel.update_hints(&cube, &topology);
+        assert_eq!(sel.vertex_indices.len(), 6);
+        assert_eq!(sel.selected_faces, vec![0, 2]);
+
+        sel.pick_vertices(&[6, 7], SelectOp::Subtract);
+        sel.update_hints(&cube, &topology);
+        assert_eq!(sel.vertex_indices.len(), 4);
+        assert_eq!(sel.selected_faces, vec![0]);
+
+        sel.pick_vertices(&[], SelectOp::Replace);
+        assert!(sel.is_empty());
+    }
+
+    #[test]
+    fn test_pick_edge_add_and_toggle_keeps_shared_vertex() {
+        let mut sel = Selection::new(SelectionMode::Edge);
+        sel.pick_edge(0, 1, SelectOp::Replace);
+        sel.pick_edge(1, 2, SelectOp::Add);
+        assert_eq!(sel.selected_edges, vec![(0, 1), (1, 2)]);
+        assert_eq!(sel.vertex_indices.len(), 3);
+
+        // Toggling 0-1 off keeps vertex 1, which edge 1-2 still uses
+        sel.pick_edge(1, 0, SelectOp::Toggle);
+        assert_eq!(sel.selected_edges, vec![(1, 2)]);
+        assert!(!sel.vertex_indices.contains(&0));
+        assert!(sel.vertex_indices.contains(&1));
+        assert!(sel.vertex_indices.contains(&2));
+    }
+
+    #[test]
+    fn test_pick_edge_subtract() {
+        let mut sel = Selection::new(SelectionMode::Edge);
+        sel.pick_edge(0, 1, SelectOp::Replace);
+        sel.pick_edge(0, 1, SelectOp::Subtract);
+        assert!(sel.is_empty());
+        assert!(sel.selected_edges.is_empty());
+    }
+
+    #[test]
+    fn test_pick_face_toggle_keeps_shared_vertices() {
+        let cube = unit_cube();
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_face(0, &cube, SelectOp::Replace); // Front: 0, 1, 5, 4
+        sel.pick_face(2, &cube, SelectOp::Toggle); // Top: 4, 5, 6, 7
+        assert_eq!(sel.selected_faces, vec![0, 2]);
+        assert_eq!(sel.vertex_indices.len(), 6);
+
+        sel.pick_face(0, &cube, SelectOp::Toggle);
+        assert_eq!(sel.selected_faces, vec![2]);
+        let mut remaining: Vec<usize> = sel.vertex_indices.iter().copied().collect();
+        remaining.sort();
+        assert_eq!(remaining, vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_pick_face_out_of_range_ignored() {
+        let cube = unit_cube();
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_face(0, &cube, SelectOp::Replace);
+        sel.pick_face(99, &cube, SelectOp::Replace);
+        assert_eq!(sel.selected_faces, vec![0]);
+    }
+
+    #[test]
+    fn test_select_all() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let mut sel = Selection::new(SelectionMode::Vertex);
+
+        sel.select_all(&cube, &topology);
+
+        assert_eq!(sel.vertex_indices.len(), 8);
+        assert_eq!(sel.selected_edges.len(), 12);
+        assert_eq!(sel.selected_faces.len(), 6);
+    }
+
     #[test]
     fn test_clear_after_select_vertex() {
         let mut sel = Selection::new(SelectionMode::Vertex);
@@ -220,4 +662,18 @@ mod tests {
         assert_eq!(cloned.vertex_indices.len(), 2);
         assert_eq!(cloned.selected_edges.len(), 1);
     }
+
+    #[test]
+    fn test_retain_valid_drops_removed_vertices() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_vertices(&[4, 5, 6, 7, 8, 9], SelectOp::Replace);
+
+        sel.retain_valid(&cube, &topology);
+
+        assert_eq!(sel.vertex_indices.len(), 4);
+        assert!(!sel.vertex_indices.contains(&8));
+        assert_eq!(sel.selected_faces, vec![2]);
+    }
 }
@@ -1,6 +1,7 @@
-// selection/modes.rs - Selection mode enum
+// selection/modes.rs - Selection mode and pick operation enums
 //
-// Defines the three selection modes available in edit mode.
+// Defines the three selection modes available in edit mode, and how a
+// pick combines with the existing selection (modifier keys).
 // Pure Rust - no Godot types.
 
 /// The selection mode determines which geometric elements are selectable.
@@ -15,6 +16,20 @@ pub enum SelectionMode {
     Face,
 }
 
+/// How a pick combines with the existing selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectOp {
+    /// Replace the selection with the picked element (plain click)
+    #[default]
+    Replace,
+    /// Add the picked element, keeping the rest (Shift)
+    Add,
+    /// Add if unselected, remove if selected (Ctrl)
+    Toggle,
+    /// Remove the picked element, keeping the rest
+    Subtract,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,6 +46,11 @@ mod tests {
         assert_ne!(SelectionMode::Edge, SelectionMode::Face);
     }
 
+    #[test]
+    fn test_default_select_op_is_replace() {
+        assert_eq!(SelectOp::default(), SelectOp::Replace);
+    }
+
     #[test]
     fn test_selection_mode_clone() {
         let mode = SelectionMode::Face;
@@ -0,0 +1,269 @@
+// selection/raycast.rs - Ray casting against BlockotGeometry faces
+//
+// Pure Rust ray/polygon intersection used for face picking and for
+// occlusion tests that hide vertices behind the mesh from selection.
+// N-gons are fan-triangulated the same way BlockotNode builds its mesh.
+// Rays are given in the geometry's local space (caller converts from world).
+
+use godot::prelude::Vector3;
+
+use crate::geometry::BlockotGeometry;
+
+/// Result of a successful ray cast against the geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// Index of the face that was hit
+    pub face: usize,
+    /// Ray parameter of the hit: `origin + direction * distance`
+    pub distance: f32,
+}
+
+/// Find the nearest face hit by the ray `origin + direction * t` (t > 0).
+///
+/// Faces are double-sided. Faces with fewer than 3 vertices or out-of-range
+/// indices are skipped. Returns None if nothing is hit.
+pub fn raycast_faces(geo: &BlockotGeometry, origin: Vector3, direction: Vector3) -> Option<RayHit> {
+    let mut best: Option<RayHit> = None;
+
+    for face_idx in 0..geo.faces.len() {
+        if let Some(distance) = intersect_face(geo, face_idx, origin, direction) {
+            if best.is_none_or(|hit| distance < hit.distance) {
+                best = Some(RayHit {
+                    face: face_idx,
+                    distance,
+                });
+            }
+        }
+    }
+
+    best
+}
+
+/// Returns true if a face blocks the line of sight from `eye` to a vertex.
+///
+/// Faces that use the vertex are ignored, since the vertex lies on them.
+/// Hits right at the vertex (within a small tolerance) don't count, so
+/// silhouette vertices stay visible. Out-of-range vertices are not occluded.
+pub fn is_vertex_occluded(geo: &BlockotGeometry, vertex_index: usize, eye: Vector3) -> bool {
+    /// Fraction of the eye-to-vertex segment treated as "at the vertex"
+    const END_TOLERANCE: f32 = 1e-4;
+
+    let Some(&target) = geo.vertices.get(vertex_index) else {
+        return false;
+    };
+    // With the unnormalized direction, t in (0, 1) lies between eye and vertex
+    let direction = target - eye;
+
+    geo.faces.iter().enumerate().any(|(face_idx, face)| {
+        !face.vertex_indices.contains(&vertex_index)
+            && intersect_face(geo, face_idx, eye, direction)
+                .is_some_and(|t| t < 1.0 - END_TOLERANCE)
+    })
+}
+
+/// Intersect the ray with a single face, returning the nearest ray parameter.
+///
+/// The face is fan-triangulated: (0, 1, 2), (0, 2, 3), ...
+pub fn intersect_face(
+    geo: &BlockotGeometry,
+    face_idx: usize,
+    origin: Vector3,
+    direction: Vector3,
+) -> Option<f32> {
+    let indices = &geo.faces.get(face_idx)?.vertex_indices;
+    if indices.len() < 3 {
+        return None;
+    }
+
+    let first = *geo.vertices.get(indices[0])?;
+    let mut nearest: Option<f32> = None;
+
+    for i in 1..(indices.len() - 1) {
+        let v1 = *geo.vertices.get(indices[i])?;
+        let v2 = *geo.vertices.get(indices[i + 1])?;
+        if let Some(t) = intersect_triangle(origin, direction, first, v1, v2) {
+            if nearest.is_none_or(|best| t < best) {
+                nearest = Some(t);
+            }
+        }
+    }
+
+    nearest
+}
+
+/// Möller–Trumbore ray/triangle intersection (double-sided).
+///
+/// Returns the ray parameter `t > 0` of the hit, or None if the ray misses
+/// or runs parallel to the triangle.
+pub fn intersect_triangle(
+    origin: Vector3,
+    direction: Vector3,
+    a: Vector3,
+    b: Vector3,
+    c: Vector3,
+) -> Option<f32> {
+    const EPSILON: f32 = 1e-7;
+
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let p = direction.cross(edge2);
+    let det = edge1.dot(p);
+    if det.abs() < EPSILON {
+        return None;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = origin - a;
+    let u = s.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(edge1);
+    let v = direction.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = edge2.dot(q) * inv_det;
+    if t > EPSILON {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Face;
+    use crate::test_utils::unit_cube;
+
+    #[test]
+    fn test_raycast_cube_front() {
+        let cube = unit_cube();
+        let hit = raycast_faces(
+            &cube,
+            Vector3::new(0.0, 0.0, -5.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        );
+
+        let hit = hit.expect("ray should hit the cube");
+        assert_eq!(hit.face, 0); // Front
+        assert!((hit.distance - 4.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_raycast_cube_top_is_nearest() {
+        let cube = unit_cube();
+        // Straight down through the cube: Top is hit before Bottom
+        let hit = raycast_faces(
+            &cube,
+            Vector3::new(0.1, 3.0, 0.2),
+            Vector3::new(0.0, -1.0, 0.0),
+        );
+
+        assert_eq!(hit.map(|h| h.face), Some(2));
+    }
+
+    #[test]
+    fn test_raycast_miss() {
+        let cube = unit_cube();
+        let hit = raycast_faces(
+            &cube,
+            Vector3::new(2.0, 0.0, -5.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        );
+        assert_eq!(hit, None);
+    }
+
+    #[test]
+    fn test_raycast_pointing_away() {
+        let cube = unit_cube();
+        let hit = raycast_faces(
+            &cube,
+            Vector3::new(0.0, 0.0, -5.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        assert_eq!(hit, None);
+    }
+
+    #[test]
+    fn test_raycast_ngon_second_triangle() {
+        // Pentagon in the XY plane; the hit point lies in the last fan triangle
+        let mut geo = BlockotGeometry::new();
+        geo.vertices = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(3.0, 1.5, 0.0),
+            Vector3::new(1.0, 3.0, 0.0),
+            Vector3::new(-1.0, 1.5, 0.0),
+        ];
+        geo.faces = vec![Face::new(vec![0, 1, 2, 3, 4])];
+
+        let hit = raycast_faces(
+            &geo,
+            Vector3::new(-0.2, 1.5, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        assert_eq!(hit.map(|h| h.face), Some(0));
+    }
+
+    #[test]
+    fn test_intersect_triangle_parallel() {
+        let result = intersect_triangle(
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::ZERO,
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_degenerate_face_skipped() {
+        let mut geo = BlockotGeometry::new();
+        geo.vertices = vec![Vector3::ZERO, Vector3::new(1.0, 0.0, 0.0)];
+        geo.faces = vec![Face::new(vec![0, 1])];
+
+        assert_eq!(intersect_face(&geo, 0, Vector3::UP, Vector3::DOWN), None);
+        assert_eq!(intersect_face(&geo, 5, Vector3::UP, Vector3::DOWN), None);
+    }
+
+    #[test]
+    fn test_cube_back_vertices_occluded() {
+        let cube = unit_cube();
+        let eye = Vector3::new(0.1, 0.2, -5.0);
+
+        // Front vertices (z = -0.5) are visible, back vertices are hidden
+        for v in [0, 1, 4, 5] {
+            assert!(
+                !is_vertex_occluded(&cube, v, eye),
+                "vertex {v} should be visible"
+            );
+        }
+        for v in [2, 3, 6, 7] {
+            assert!(
+                is_vertex_occluded(&cube, v, eye),
+                "vertex {v} should be occluded"
+            );
+        }
+    }
+
+    #[test]
+    fn test_silhouette_vertex_visible() {
+        let cube = unit_cube();
+        // Looking at the front-top edge from above and in front: vertex 6 (back-top-right)
+        // is visible over the top face, which it belongs to
+        let eye = Vector3::new(0.0, 5.0, -5.0);
+        assert!(!is_vertex_occluded(&cube, 6, eye));
+        assert!(is_vertex_occluded(&cube, 2, eye));
+    }
+
+    #[test]
+    fn test_occlusion_out_of_range_vertex() {
+        let cube = unit_cube();
+        assert!(!is_vertex_occluded(&cube, 99, Vector3::new(0.0, 0.0, -5.0)));
+    }
+}
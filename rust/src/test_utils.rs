@@ -45,6 +45,49 @@ pub fn single_quad() -> BlockotGeometry {
     geo
 }
 
+/// Creates a flat grid of `cols` x `rows` unit quads on the XZ plane, facing +Y.
+///
+/// Vertex (x, z) has index `z * (cols + 1) + x`; face (x, z) has index `z * cols + x`.
+pub fn quad_grid(cols: usize, rows: usize) -> BlockotGeometry {
+    let mut geo = BlockotGeometry::with_capacity((cols + 1) * (rows + 1), cols * rows);
+
+    for z in 0..=rows {
+        for x in 0..=cols {
+            geo.vertices.push(Vector3::new(x as f32, 0.0, z as f32));
+        }
+    }
+
+    let stride = cols + 1;
+    for z in 0..rows {
+        for x in 0..cols {
+            let a = z * stride + x;
+            geo.faces
+                .push(Face::quad(a, a + 1, a + 1 + stride, a + stride));
+        }
+    }
+
+    geo
+}
+
+/// Creates the same quads as `quad_grid`, but with every quad using its own
+/// four vertices, so neighbouring quads only touch through coincident copies.
+///
+/// Face (x, z) has index `z * cols + x` and uses vertices `4 * face..4 * face + 4`.
+pub fn unwelded_grid(cols: usize, rows: usize) -> BlockotGeometry {
+    let grid = quad_grid(cols, rows);
+    let mut geo = BlockotGeometry::with_capacity(4 * grid.faces.len(), grid.faces.len());
+
+    for face in &grid.faces {
+        let first = geo.vertices.len();
+        geo.vertices
+            .extend(face.vertex_indices.iter().map(|&v| grid.vertices[v]));
+        geo.faces
+            .push(Face::quad(first, first + 1, first + 2, first + 3));
+    }
+
+    geo
+}
+
 /// Compare two Vector3 values with floating point tolerance.
 pub fn vectors_approx_equal(a: Vector3, b: Vector3, epsilon: f32) -> bool {
     (a.x - b.x).abs() < epsilon && (a.y - b.y).abs() < epsilon && (a.z - b.z).abs() < epsilon
@@ -95,6 +138,14 @@ mod tests {
         assert!(geo.faces[0].is_quad());
     }
 
+    #[test]
+    fn test_quad_grid_fixture() {
+        let geo = quad_grid(3, 2);
+        assert_eq!(geo.vertex_count(), 12);
+        assert_eq!(geo.face_count(), 6);
+        assert!(geo.faces.iter().all(|f| f.is_quad()));
+    }
+
     #[test]
     fn test_vectors_approx_equal() {
         let a = Vector3::new(1.0, 2.0, 3.0);
@@ -0,0 +1,675 @@
+// tools/commands/bevel_edges.rs - BevelEdges command implementation
+//
+// Replaces edges with chamfer strips. Every endpoint of a bevelled edge is
+// split: walking the fan of faces around it, each face's corner moves off
+// the vertex - along its unbevelled edge when one of its two edges there is
+// bevelled, inward along both when both are, and onto both edges (cutting
+// the corner) when neither is. A strip of `segments` quads then runs along
+// each bevelled edge, its cross-section curving from the corner in one face
+// to the corner in the other with the old vertex as control point.
+// Whatever hole is left around a vertex is closed: where two bevelled edges
+// pass through it the strips share one profile, a lone bevel's profile is
+// folded into the single face capping it, and other holes get a patch face.
+// Offsets are measured along the neighbouring edges and stop at their
+// midpoint. The old vertex is reused as one of its new points, so no vertex
+// is left unused; new vertices and faces are appended. Undo restores the
+// moved vertices and rewritten faces and truncates the rest.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::Range;
+
+use godot::prelude::Vector3;
+
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to bevel edges into strips of quads.
+#[derive(Debug, Clone)]
+pub struct BevelEdges {
+    /// Bevelled edges (normalized, sorted, unique)
+    edges: Vec<Edge>,
+    /// Strip width, measured along the neighbouring edges
+    width: f32,
+    /// Quads across each strip
+    segments: usize,
+    /// Endpoints of the bevelled edges, each reused as one of its new points
+    moved: Vec<usize>,
+    /// Positions of `moved` before the bevel, restored on undo
+    original_positions: Vec<Vector3>,
+    /// Positions of `moved` after the bevel
+    moved_positions: Vec<Vector3>,
+    /// Positions of the other new points, appended after the existing vertices
+    new_positions: Vec<Vector3>,
+    /// Faces that lost a bevelled corner (sorted)
+    rewritten: Vec<usize>,
+    /// Faces before the bevel (parallel to `rewritten`), restored on undo
+    original_faces: Vec<Face>,
+    /// Faces after the bevel (parallel to `rewritten`)
+    bevelled_faces: Vec<Face>,
+    /// Strip quads and patches, appended after the existing faces
+    new_faces: Vec<Face>,
+    /// Vertex count before the bevel
+    vertex_count: usize,
+    /// Face count before the bevel
+    face_count: usize,
+}
+
+impl BevelEdges {
+    /// Create a new BevelEdges command with strips `width` wide and
+    /// `segments` quads across (at least one). Duplicate edges (in either
+    /// direction) are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if edges is empty,
+    /// `BlockotError::NonPositiveAmount` if width is not above zero,
+    /// `BlockotError::InvalidEdge` if an edge is not in the mesh,
+    /// `BlockotError::NonManifoldEdge` if an edge does not join exactly two
+    /// faces, or `BlockotError::NonManifoldVertex` if the faces around an
+    /// endpoint do not form a single consistently wound fan.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        edges: Vec<Edge>,
+        width: f32,
+        segments: usize,
+    ) -> Result<Self, BlockotError> {
+        if edges.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        if width.is_nan() || width <= 0.0 {
+            return Err(BlockotError::NonPositiveAmount);
+        }
+        let edges: Vec<Edge> = edges
+            .into_iter()
+            .map(|(a, b)| edge_key(a, b))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        for &(a, b) in &edges {
+            if !topology.has_edge(a, b) {
+                return Err(BlockotError::InvalidEdge(a, b));
+            }
+            if topology.edge_faces(a, b).len() != 2 {
+                return Err(BlockotError::NonManifoldEdge(a, b));
+            }
+        }
+
+        let mut builder = Builder {
+            geo,
+            selected: edges.iter().copied().collect(),
+            width,
+            segments: segments.max(1),
+            moved: Vec::new(),
+            moved_positions: Vec::new(),
+            new_positions: Vec::new(),
+            positions: HashMap::new(),
+            corners: HashMap::new(),
+            profiles: HashMap::new(),
+            patches: Vec::new(),
+        };
+        let endpoints: BTreeSet<usize> = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+        for v in endpoints {
+            let (fan, closed) = vertex_fan(geo, topology, v)?;
+            builder.split_vertex(v, &fan, closed);
+        }
+
+        let rewritten: Vec<usize> = builder
+            .corners
+            .keys()
+            .map(|&(f, _)| f)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let original_faces: Vec<Face> = rewritten.iter().map(|&f| geo.faces[f].clone()).collect();
+        let bevelled_faces = rewritten
+            .iter()
+            .map(|&f| {
+                Face::new(
+                    geo.faces[f]
+                        .vertex_indices
+                        .iter()
+                        .flat_map(|&v| match builder.corners.get(&(f, v)) {
+                            Some(corner) => corner.clone(),
+                            None => vec![v],
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let mut new_faces = Vec::new();
+        for &(a, b) in &edges {
+            let faces = topology.edge_faces(a, b);
+            let first = faces[0];
+            let profile_a = builder.profile(a, (a, b), first);
+            let profile_b = builder.profile(b, (a, b), first);
+            // The first quad runs against the first face's edge, so the
+            // strip continues that face's orientation
+            let forward = geo.faces[first].edges().any(|edge| edge == (a, b));
+            let (pa, pb) = if forward {
+                (&profile_b, &profile_a)
+            } else {
+                (&profile_a, &profile_b)
+            };
+            for k in 0..builder.segments {
+                new_faces.push(Face::quad(pa[k], pb[k], pb[k + 1], pa[k + 1]));
+            }
+        }
+        new_faces.append(&mut builder.patches);
+
+        let original_positions = builder.moved.iter().map(|&v| geo.vertices[v]).collect();
+        Ok(Self {
+            edges,
+            width,
+            segments: builder.segments,
+            moved: builder.moved,
+            original_positions,
+            moved_positions: builder.moved_positions,
+            new_positions: builder.new_positions,
+            rewritten,
+            original_faces,
+            bevelled_faces,
+            new_faces,
+            vertex_count: geo.vertices.len(),
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the bevelled edges.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the strip width.
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the number of quads across each strip.
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Returns every vertex of the bevel after execute: the reused endpoints
+    /// followed by the appended vertices.
+    pub fn new_vertices(&self) -> Vec<usize> {
+        self.moved
+            .iter()
+            .copied()
+            .chain(self.vertex_count..self.vertex_count + self.new_positions.len())
+            .collect()
+    }
+
+    /// Returns the indices of the strip and patch faces after execute.
+    pub fn new_face_indices(&self) -> Range<usize> {
+        self.face_count..self.face_count + self.new_faces.len()
+    }
+}
+
+impl Command for BevelEdges {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        for (&v, &position) in self.moved.iter().zip(&self.moved_positions) {
+            if let Some(vertex) = geo.vertices.get_mut(v) {
+                *vertex = position;
+            }
+        }
+        geo.vertices.extend(self.new_positions.iter().copied());
+        for (&f, bevelled) in self.rewritten.iter().zip(&self.bevelled_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = bevelled.clone();
+            }
+        }
+        geo.faces.extend(self.new_faces.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        for (&f, original) in self.rewritten.iter().zip(&self.original_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = original.clone();
+            }
+        }
+        geo.vertices.truncate(self.vertex_count);
+        for (&v, &original) in self.moved.iter().zip(&self.original_positions) {
+            if let Some(vertex) = geo.vertices.get_mut(v) {
+                *vertex = original;
+            }
+        }
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Bevel Edges"
+    }
+}
+
+/// A face around a vertex, with the vertex's neighbours before and after it
+/// in the face's winding.
+#[derive(Debug, Clone, Copy)]
+struct FanFace {
+    face: usize,
+    prev: usize,
+    next: usize,
+}
+
+/// Working state while splitting the endpoints of the bevelled edges.
+struct Builder<'a> {
+    geo: &'a BlockotGeometry,
+    selected: HashSet<Edge>,
+    width: f32,
+    segments: usize,
+    moved: Vec<usize>,
+    moved_positions: Vec<Vector3>,
+    new_positions: Vec<Vector3>,
+    /// Position of every new point
+    positions: HashMap<usize, Vector3>,
+    /// Points replacing a vertex in a face, keyed by (face, vertex)
+    corners: HashMap<(usize, usize), Vec<usize>>,
+    /// Cross-section of a bevelled edge at one endpoint, keyed by
+    /// (vertex, edge), with the face it starts in
+    profiles: HashMap<(usize, Edge), (usize, Vec<usize>)>,
+    /// Faces closing the holes around split vertices
+    patches: Vec<Face>,
+}
+
+impl Builder<'_> {
+    /// Add a point created by splitting vertex `v`; the first one reuses `v`.
+    fn add_point(&mut self, v: usize, position: Vector3) -> usize {
+        let index = if self.moved.last() != Some(&v) {
+            self.moved.push(v);
+            self.moved_positions.push(position);
+            v
+        } else {
+            self.new_positions.push(position);
+            self.geo.vertices.len() + self.new_positions.len() - 1
+        };
+        self.positions.insert(index, position);
+        index
+    }
+
+    /// Offset from `v` toward its neighbour `n`, stopping at the midpoint.
+    fn offset(&self, v: usize, n: usize) -> Vector3 {
+        let along = self.geo.vertices[n] - self.geo.vertices[v];
+        let length = along.length();
+        if length < 1e-6 {
+            return Vector3::ZERO;
+        }
+        along / length * self.width.min(length * 0.5)
+    }
+
+    fn is_selected(&self, v: usize, n: usize) -> bool {
+        self.selected.contains(&edge_key(v, n))
+    }
+
+    /// Replace `v` in every face of its fan, add the profiles of its
+    /// bevelled edges and close the hole left around it.
+    fn split_vertex(&mut self, v: usize, fan: &[FanFace], closed: bool) {
+        let position = self.geo.vertices[v];
+        let mut edge_points: HashMap<usize, usize> = HashMap::new();
+        let mut edge_point = |builder: &mut Self, n: usize| {
+            *edge_points
+                .entry(n)
+                .or_insert_with(|| builder.add_point(v, position + builder.offset(v, n)))
+        };
+
+        let mut corners: Vec<Vec<usize>> = Vec::with_capacity(fan.len());
+        for ff in fan {
+            let corner = match (self.is_selected(v, ff.prev), self.is_selected(v, ff.next)) {
+                (true, true) => {
+                    let inset = self.offset(v, ff.prev) + self.offset(v, ff.next);
+                    vec![self.add_point(v, position + inset)]
+                }
+                (true, false) => vec![edge_point(self, ff.next)],
+                (false, true) => vec![edge_point(self, ff.prev)],
+                (false, false) => vec![edge_point(self, ff.prev), edge_point(self, ff.next)],
+            };
+            corners.push(corner);
+        }
+
+        let bevelled: Vec<usize> = (0..fan.len())
+            .filter(|&i| self.is_selected(v, fan[i].next))
+            .collect();
+        let middle: Vec<usize> = (0..fan.len()).filter(|&i| corners[i].len() == 2).collect();
+        // Two strips through the vertex meet corner to corner on both sides
+        let shared = closed && bevelled.len() == 2 && middle.is_empty();
+
+        let mut interiors: Vec<Vec<usize>> = Vec::with_capacity(bevelled.len());
+        for (k, &i) in bevelled.iter().enumerate() {
+            let j = (i + 1) % fan.len();
+            let (from, to) = (corners[i][corners[i].len() - 1], corners[j][0]);
+            let interior: Vec<usize> = if shared && k == 1 {
+                interiors[0].iter().rev().copied().collect()
+            } else {
+                let (start, end) = (self.positions[&from], self.positions[&to]);
+                (1..self.segments)
+                    .map(|s| {
+                        let t = s as f32 / self.segments as f32;
+                        let curve = start * (1.0 - t) * (1.0 - t)
+                            + position * (2.0 * t * (1.0 - t))
+                            + end * (t * t);
+                        self.add_point(v, curve)
+                    })
+                    .collect()
+            };
+            let profile = [vec![from], interior.clone(), vec![to]].concat();
+            self.profiles
+                .insert((v, edge_key(v, fan[i].next)), (fan[i].face, profile));
+            interiors.push(interior);
+        }
+
+        if closed && !shared {
+            if let ([_], [m]) = (bevelled.as_slice(), middle.as_slice()) {
+                // A lone bevel ends in the one face capping it
+                let (first, last) = (corners[*m][0], corners[*m][1]);
+                corners[*m] = [
+                    vec![first],
+                    interiors[0].iter().rev().copied().collect(),
+                    vec![last],
+                ]
+                .concat();
+            } else {
+                let mut ring: Vec<usize> = Vec::new();
+                let mut interior = interiors.iter();
+                for (i, corner) in corners.iter().enumerate() {
+                    for &point in corner {
+                        if ring.last() != Some(&point) {
+                            ring.push(point);
+                        }
+                    }
+                    if self.is_selected(v, fan[i].next) {
+                        ring.extend(interior.next().into_iter().flatten());
+                    }
+                }
+                if ring.len() > 1 && ring.first() == ring.last() {
+                    ring.pop();
+                }
+                if ring.len() >= 3 {
+                    // The faces around run along the ring, so the patch runs against it
+                    ring.reverse();
+                    self.patches.push(Face::new(ring));
+                }
+            }
+        }
+
+        for (ff, corner) in fan.iter().zip(corners) {
+            self.corners.insert((ff.face, v), corner);
+        }
+    }
+
+    /// The profile of `edge` at vertex `v`, running from face `from`'s corner.
+    fn profile(&self, v: usize, edge: Edge, from: usize) -> Vec<usize> {
+        let (start_face, profile) = &self.profiles[&(v, edge)];
+        if *start_face == from {
+            profile.clone()
+        } else {
+            profile.iter().rev().copied().collect()
+        }
+    }
+}
+
+/// The faces around `v` in winding order, and whether they close around it.
+/// An open fan starts at the face after the border.
+fn vertex_fan(
+    geo: &BlockotGeometry,
+    topology: &MeshTopology,
+    v: usize,
+) -> Result<(Vec<FanFace>, bool), BlockotError> {
+    let fan_face = |f: usize| {
+        let indices = &geo.faces[f].vertex_indices;
+        let n = indices.len();
+        let pos = indices.iter().position(|&x| x == v)?;
+        Some(FanFace {
+            face: f,
+            prev: indices[(pos + n - 1) % n],
+            next: indices[(pos + 1) % n],
+        })
+    };
+    let faces = topology.vertex_faces(v);
+    let all: Vec<FanFace> = faces
+        .iter()
+        .map(|&f| fan_face(f).ok_or(BlockotError::NonManifoldVertex(v)))
+        .collect::<Result<_, _>>()?;
+    let start = all
+        .iter()
+        .copied()
+        .find(|ff| topology.is_boundary_edge(v, ff.prev))
+        .or_else(|| all.first().copied())
+        .ok_or(BlockotError::NonManifoldVertex(v))?;
+
+    let mut fan = vec![start];
+    let mut closed = false;
+    loop {
+        let current = fan[fan.len() - 1];
+        let across = topology.edge_faces(v, current.next);
+        if across.len() == 1 {
+            break;
+        }
+        let [a, b] = across else {
+            return Err(BlockotError::NonManifoldVertex(v));
+        };
+        let other = if *a == current.face { *b } else { *a };
+        if other == start.face {
+            closed = true;
+            break;
+        }
+        let next = all
+            .iter()
+            .copied()
+            .find(|ff| ff.face == other)
+            .filter(|ff| ff.prev == current.next)
+            .ok_or(BlockotError::NonManifoldVertex(v))?;
+        if fan.iter().any(|ff| ff.face == other) {
+            return Err(BlockotError::NonManifoldVertex(v));
+        }
+        fan.push(next);
+    }
+    if fan.len() != all.len() {
+        return Err(BlockotError::NonManifoldVertex(v));
+    }
+    Ok((fan, closed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube, vectors_approx_equal};
+
+    /// Every directed edge is used once, and every edge by at most two faces
+    /// running opposite ways.
+    fn assert_consistent(geo: &BlockotGeometry) {
+        let mut directed = HashSet::new();
+        for face in &geo.faces {
+            for edge in face.edges() {
+                assert!(directed.insert(edge), "edge {:?} used twice", edge);
+            }
+        }
+        for face in &geo.faces {
+            assert!(face.vertex_indices.iter().all(|&v| v < geo.vertices.len()));
+        }
+    }
+
+    fn assert_closed(geo: &BlockotGeometry) {
+        assert_consistent(geo);
+        assert!(MeshTopology::build(geo).boundary_edges().is_empty());
+    }
+
+    fn bevel(
+        geo: &mut BlockotGeometry,
+        edges: Vec<Edge>,
+        width: f32,
+        segments: usize,
+    ) -> BevelEdges {
+        let topology = MeshTopology::build(geo);
+        let cmd = BevelEdges::new(geo, &topology, edges, width, segments).unwrap();
+        cmd.execute(geo);
+        cmd
+    }
+
+    #[test]
+    fn test_bevel_single_cube_edge() {
+        let mut cube = unit_cube();
+        let cmd = bevel(&mut cube, vec![(4, 5)], 0.25, 1);
+
+        // Each endpoint splits in two, one of them reusing the old vertex
+        assert_eq!(cube.vertices.len(), 10);
+        assert_eq!(cube.faces.len(), 7);
+        assert_eq!(cmd.new_face_indices(), 6..7);
+        assert_closed(&cube);
+        // The strip joins the front and top faces a quarter in from the edge
+        for &v in &cube.faces[6].vertex_indices {
+            let p = cube.vertices[v];
+            let on_front = (p.z + 0.5).abs() < 1e-6 && (p.y - 0.25).abs() < 1e-6;
+            let on_top = (p.y - 0.5).abs() < 1e-6 && (p.z + 0.25).abs() < 1e-6;
+            assert!(on_front || on_top, "unexpected strip vertex {:?}", p);
+        }
+    }
+
+    #[test]
+    fn test_strip_faces_outward() {
+        let mut cube = unit_cube();
+        let cmd = bevel(&mut cube, vec![(5, 4)], 0.25, 1);
+
+        let normal = cube.faces[cmd.new_face_indices().start].normal(&cube.vertices);
+        let expected = Vector3::new(0.0, 1.0, -1.0).normalized();
+        assert!(vectors_approx_equal(normal, expected, 1e-5));
+    }
+
+    #[test]
+    fn test_segments_round_the_strip() {
+        let mut cube = unit_cube();
+        let cmd = bevel(&mut cube, vec![(4, 5)], 0.25, 3);
+
+        assert_eq!(cmd.segments(), 3);
+        assert_eq!(cmd.new_face_indices().len(), 3);
+        // Two more profile points per endpoint
+        assert_eq!(cube.vertices.len(), 14);
+        assert_closed(&cube);
+        // The profile bulges toward the old edge
+        let center = Vector3::new(0.0, 0.25, -0.25);
+        let bulge = Vector3::new(0.0, 0.5, -0.5) - center;
+        for v in cmd.new_vertices() {
+            let p = cube.vertices[v];
+            assert!((p - center).dot(bulge) >= -1e-6);
+        }
+    }
+
+    #[test]
+    fn test_bevel_edge_loop_shares_profiles() {
+        let mut cube = unit_cube();
+        let top = vec![(4, 5), (5, 6), (6, 7), (7, 4)];
+        bevel(&mut cube, top, 0.1, 2);
+
+        // Per top vertex: the inset corner, the point down the side and one
+        // shared profile point
+        assert_eq!(cube.vertices.len(), 8 + 4 * 2);
+        assert_eq!(cube.faces.len(), 6 + 4 * 2);
+        assert_closed(&cube);
+        for &v in &cube.faces[2].vertex_indices {
+            let p = cube.vertices[v];
+            assert!((p.y - 0.5).abs() < 1e-6);
+            assert!((p.x.abs() - 0.4).abs() < 1e-6 && (p.z.abs() - 0.4).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_bevel_all_cube_edges_patches_corners() {
+        let mut cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let edges = topology.edges().to_vec();
+        bevel(&mut cube, edges, 0.1, 1);
+
+        assert_eq!(cube.vertices.len(), 24);
+        // 6 faces, 12 strips and a triangle on every corner
+        assert_eq!(cube.faces.len(), 26);
+        assert_eq!(cube.faces.iter().filter(|f| f.is_triangle()).count(), 8);
+        assert_closed(&cube);
+    }
+
+    #[test]
+    fn test_bevel_line_across_grid() {
+        let mut grid = quad_grid(2, 2);
+        bevel(&mut grid, vec![(3, 4), (4, 5)], 0.25, 1);
+
+        assert_eq!(grid.vertices.len(), 12);
+        assert_eq!(grid.faces.len(), 6);
+        assert_consistent(&grid);
+        // The line opens into a strip from z = 0.75 to z = 1.25
+        let zs: BTreeSet<i32> = grid
+            .vertices
+            .iter()
+            .map(|p| (p.z * 100.0).round() as i32)
+            .collect();
+        assert_eq!(zs, BTreeSet::from([0, 75, 125, 200]));
+    }
+
+    #[test]
+    fn test_bevel_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = bevel(&mut cube, vec![(4, 5), (5, 6), (1, 5)], 0.2, 2);
+
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_bevel_redo_matches_execute() {
+        let mut cube = unit_cube();
+        let cmd = bevel(&mut cube, vec![(4, 5)], 0.2, 2);
+        let bevelled = cube.clone();
+
+        cmd.undo(&mut cube);
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube, bevelled);
+    }
+
+    #[test]
+    fn test_boundary_edge_is_rejected() {
+        let grid = quad_grid(1, 1);
+        let topology = MeshTopology::build(&grid);
+        let result = BevelEdges::new(&grid, &topology, vec![(0, 1)], 0.1, 1);
+        assert_eq!(result.unwrap_err(), BlockotError::NonManifoldEdge(0, 1));
+    }
+
+    #[test]
+    fn test_invalid_edge() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = BevelEdges::new(&cube, &topology, vec![(0, 6)], 0.1, 1);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidEdge(0, 6));
+    }
+
+    #[test]
+    fn test_empty_selection() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = BevelEdges::new(&cube, &topology, vec![], 0.1, 1);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_non_positive_width() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        for width in [0.0, -0.1, f32::NAN] {
+            let result = BevelEdges::new(&cube, &topology, vec![(4, 5)], width, 1);
+            assert_eq!(result.unwrap_err(), BlockotError::NonPositiveAmount);
+        }
+    }
+
+    #[test]
+    fn test_bevel_name() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let cmd = BevelEdges::new(&cube, &topology, vec![(5, 4), (4, 5)], 0.1, 0).unwrap();
+        assert_eq!(cmd.name(), "Bevel Edges");
+        assert_eq!(cmd.edges(), &[(4, 5)]);
+        assert_eq!(cmd.segments(), 1);
+        assert_eq!(cmd.width(), 0.1);
+    }
+}
@@ -0,0 +1,185 @@
+// tools/commands/boundary_chains.rs - Shared support for commands that close up openings
+//
+// Fill and bridge build new faces along selected boundary edges. A new face
+// has to walk each boundary edge opposite to the one face already using it,
+// so its normal agrees with its neighbour. `boundary_chains` orders the
+// selected edges into chains that already run that way: closed loops and
+// open runs of edges, each edge walked against its face's winding.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, MeshTopology};
+
+/// A run of selected boundary edges, walked against the bordering faces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct BoundaryChain {
+    /// Vertices along the chain; a closed loop does not repeat its start
+    pub(super) vertices: Vec<usize>,
+    /// True if the last vertex joins back to the first
+    pub(super) closed: bool,
+}
+
+impl BoundaryChain {
+    /// Returns the number of edges in the chain.
+    pub(super) fn edge_count(&self) -> usize {
+        if self.closed {
+            self.vertices.len()
+        } else {
+            self.vertices.len().saturating_sub(1)
+        }
+    }
+}
+
+/// Order `edges` into chains walked against their faces' winding. Duplicate
+/// edges (in either direction) are ignored. Open chains come first, ordered
+/// by their start vertex, then closed loops starting at their lowest vertex.
+///
+/// # Errors
+/// Returns `BlockotError::EmptySelection` if edges is empty,
+/// `BlockotError::InvalidEdge` if an edge is not in the mesh,
+/// `BlockotError::NotBoundaryEdge` if an edge borders more than one face, or
+/// `BlockotError::EdgeLoopBranches` if more than two edges meet at a vertex.
+pub(super) fn boundary_chains(
+    geo: &BlockotGeometry,
+    topology: &MeshTopology,
+    edges: Vec<Edge>,
+) -> Result<Vec<BoundaryChain>, BlockotError> {
+    if edges.is_empty() {
+        return Err(BlockotError::EmptySelection);
+    }
+    let edges: BTreeSet<Edge> = edges.into_iter().map(|(a, b)| edge_key(a, b)).collect();
+
+    // Each edge as the new face walks it: against the face that uses it
+    let mut next: HashMap<usize, usize> = HashMap::new();
+    let mut prev: HashMap<usize, usize> = HashMap::new();
+    for &(a, b) in &edges {
+        let face = match topology.edge_faces(a, b) {
+            [] => return Err(BlockotError::InvalidEdge(a, b)),
+            [face] => geo
+                .faces
+                .get(*face)
+                .ok_or(BlockotError::InvalidFaceIndex(*face))?,
+            _ => return Err(BlockotError::NotBoundaryEdge(a, b)),
+        };
+        let forward = face.edges().any(|edge| edge == (a, b));
+        let (from, to) = if forward { (b, a) } else { (a, b) };
+        if next.insert(from, to).is_some() {
+            return Err(BlockotError::EdgeLoopBranches(from));
+        }
+        if prev.insert(to, from).is_some() {
+            return Err(BlockotError::EdgeLoopBranches(to));
+        }
+    }
+
+    let mut starts: Vec<usize> = next
+        .keys()
+        .copied()
+        .filter(|v| !prev.contains_key(v))
+        .collect();
+    starts.sort_unstable();
+    let mut chains = Vec::new();
+    for start in starts {
+        let mut vertices = vec![start];
+        let mut v = start;
+        while let Some(&to) = next.get(&v) {
+            vertices.push(to);
+            v = to;
+        }
+        chains.push(BoundaryChain {
+            vertices,
+            closed: false,
+        });
+    }
+
+    // Whatever is left walks in circles
+    let mut visited: BTreeSet<usize> = chains
+        .iter()
+        .flat_map(|chain| chain.vertices.iter().copied())
+        .collect();
+    let mut remaining: Vec<usize> = next.keys().copied().collect();
+    remaining.sort_unstable();
+    for start in remaining {
+        if visited.contains(&start) {
+            continue;
+        }
+        let mut vertices = vec![start];
+        visited.insert(start);
+        let mut v = next[&start];
+        while v != start {
+            vertices.push(v);
+            visited.insert(v);
+            v = next[&v];
+        }
+        chains.push(BoundaryChain {
+            vertices,
+            closed: true,
+        });
+    }
+    Ok(chains)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, single_quad, unit_cube};
+
+    #[test]
+    fn test_quad_border_is_one_loop_against_winding() {
+        let quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let chains =
+            boundary_chains(&quad, &topology, vec![(0, 1), (1, 2), (2, 3), (3, 0)]).unwrap();
+
+        // The quad runs 0, 1, 2, 3, so its border is walked the other way
+        assert_eq!(
+            chains,
+            vec![BoundaryChain {
+                vertices: vec![0, 3, 2, 1],
+                closed: true,
+            }]
+        );
+        assert_eq!(chains[0].edge_count(), 4);
+    }
+
+    #[test]
+    fn test_open_chain() {
+        let quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let chains = boundary_chains(&quad, &topology, vec![(1, 0), (1, 2)]).unwrap();
+
+        assert_eq!(
+            chains,
+            vec![BoundaryChain {
+                vertices: vec![2, 1, 0],
+                closed: false,
+            }]
+        );
+        assert_eq!(chains[0].edge_count(), 2);
+    }
+
+    #[test]
+    fn test_branching_edges() {
+        let mut grid = quad_grid(2, 2);
+        // Faces 0 and 3 only touch at the centre vertex
+        grid.faces = vec![grid.faces[0].clone(), grid.faces[3].clone()];
+        let topology = MeshTopology::build(&grid);
+        let edges = topology.boundary_edges();
+
+        let result = boundary_chains(&grid, &topology, edges);
+
+        assert_eq!(result.unwrap_err(), BlockotError::EdgeLoopBranches(4));
+    }
+
+    #[test]
+    fn test_inner_edge_is_rejected() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let (a, b) = edge_key(
+            cube.faces[0].vertex_indices[0],
+            cube.faces[0].vertex_indices[1],
+        );
+        let result = boundary_chains(&cube, &topology, vec![(a, b)]);
+        assert_eq!(result.unwrap_err(), BlockotError::NotBoundaryEdge(a, b));
+    }
+}
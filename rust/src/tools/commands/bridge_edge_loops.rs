@@ -0,0 +1,248 @@
+// tools/commands/bridge_edge_loops.rs - BridgeEdgeLoops command implementation
+//
+// Connects two loops of selected boundary edges with a strip of quads, e.g.
+// the openings of two blockout rooms joined by a corridor. Both loops must
+// be closed, or both open, with the same number of edges. Walking each loop
+// against its faces (see boundary_chains.rs), the loops are paired in
+// opposite directions so every quad is wound consistently with the faces on
+// both sides; closed loops are rotated to the pairing with the shortest
+// total distance.
+// New faces are appended; undo truncates them, so the arrays come back
+// exactly. Validates at construction, execute/undo are infallible.
+
+use std::ops::Range;
+
+use super::boundary_chains::{boundary_chains, BoundaryChain};
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to bridge two edge loops with quads.
+#[derive(Debug, Clone)]
+pub struct BridgeEdgeLoops {
+    /// New quads, appended after the existing faces
+    new_faces: Vec<Face>,
+    /// Face count before the bridge
+    face_count: usize,
+}
+
+impl BridgeEdgeLoops {
+    /// Create a new BridgeEdgeLoops command joining the two loops formed by
+    /// `edges`. Duplicate edges (in either direction) are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if edges is empty,
+    /// `BlockotError::InvalidEdge` if an edge is not in the mesh,
+    /// `BlockotError::NotBoundaryEdge` if an edge borders more than one face,
+    /// `BlockotError::EdgeLoopBranches` if more than two edges meet at a vertex,
+    /// `BlockotError::BridgeLoopCount` if the edges do not form exactly two
+    /// loops, `BlockotError::OpenEdgeLoop` if only one of them is closed, or
+    /// `BlockotError::BridgeLoopMismatch` if their edge counts differ.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        edges: Vec<Edge>,
+    ) -> Result<Self, BlockotError> {
+        let chains = boundary_chains(geo, topology, edges)?;
+        let [first, second] = chains.as_slice() else {
+            return Err(BlockotError::BridgeLoopCount(chains.len()));
+        };
+        if first.closed != second.closed {
+            let open = if first.closed { second } else { first };
+            return Err(BlockotError::OpenEdgeLoop(open.vertices[0]));
+        }
+        if first.edge_count() != second.edge_count() {
+            return Err(BlockotError::BridgeLoopMismatch(
+                first.edge_count(),
+                second.edge_count(),
+            ));
+        }
+
+        let partners = pair_loops(geo, first, second);
+        let n = first.vertices.len();
+        let new_faces = (0..first.edge_count())
+            .map(|i| {
+                let j = (i + 1) % n;
+                Face::quad(
+                    first.vertices[i],
+                    first.vertices[j],
+                    partners[j],
+                    partners[i],
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            new_faces,
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the indices of the new quads after execute.
+    pub fn new_face_indices(&self) -> Range<usize> {
+        self.face_count..self.face_count + self.new_faces.len()
+    }
+}
+
+impl Command for BridgeEdgeLoops {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        geo.faces.extend(self.new_faces.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Bridge Edge Loops"
+    }
+}
+
+/// The vertex of `second` bridged to each vertex of `first`: `second` walked
+/// backwards, rotated (closed loops only) to the closest pairing.
+fn pair_loops(geo: &BlockotGeometry, first: &BoundaryChain, second: &BoundaryChain) -> Vec<usize> {
+    let reversed: Vec<usize> = second.vertices.iter().rev().copied().collect();
+    let n = reversed.len();
+    let paired =
+        |offset: usize| -> Vec<usize> { (0..n).map(|i| reversed[(i + offset) % n]).collect() };
+    if !first.closed {
+        return paired(0);
+    }
+
+    let cost = |offset: usize| -> f32 {
+        first
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                geo.vertices[v].distance_squared_to(geo.vertices[reversed[(i + offset) % n]])
+            })
+            .sum()
+    };
+    let best = (0..n)
+        .min_by(|&a, &b| cost(a).total_cmp(&cost(b)))
+        .unwrap_or(0);
+    paired(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube, unwelded_grid};
+    use godot::prelude::Vector3;
+    use std::collections::HashSet;
+
+    /// Two unit cubes side by side along X, open toward each other.
+    fn facing_open_cubes() -> BlockotGeometry {
+        let mut geo = unit_cube();
+        let mut other = unit_cube();
+        // Drop the first cube's right face and the second cube's left face
+        geo.faces.remove(4);
+        other.faces.remove(5);
+        let offset = geo.vertices.len();
+        geo.vertices.extend(
+            other
+                .vertices
+                .iter()
+                .map(|&v| v + Vector3::new(3.0, 0.0, 0.0)),
+        );
+        geo.faces.extend(
+            other
+                .faces
+                .iter()
+                .map(|face| Face::new(face.vertex_indices.iter().map(|&v| v + offset).collect())),
+        );
+        geo
+    }
+
+    /// Returns true if no two faces walk the same edge in the same direction.
+    fn is_consistently_wound(geo: &BlockotGeometry) -> bool {
+        let mut seen = HashSet::new();
+        geo.faces
+            .iter()
+            .flat_map(|face| face.edges().collect::<Vec<_>>())
+            .all(|edge| seen.insert(edge))
+    }
+
+    #[test]
+    fn test_bridge_closed_loops() {
+        let mut geo = facing_open_cubes();
+        let topology = MeshTopology::build(&geo);
+        let cmd = BridgeEdgeLoops::new(&geo, &topology, topology.boundary_edges()).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(cmd.new_face_indices(), 10..14);
+        assert!(MeshTopology::build(&geo).boundary_edges().is_empty());
+        assert!(is_consistently_wound(&geo));
+        // The closest pairing keeps the corridor straight along X
+        for f in cmd.new_face_indices() {
+            let normal = geo.faces[f].normal(&geo.vertices);
+            assert!(normal.x.abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_bridge_open_chains() {
+        let mut geo = unwelded_grid(2, 1);
+        // Pull the second quad away, leaving a gap between x=1 and x=2
+        for v in &mut geo.vertices[4..] {
+            v.x += 1.0;
+        }
+        let topology = MeshTopology::build(&geo);
+        let cmd = BridgeEdgeLoops::new(&geo, &topology, vec![(1, 2), (4, 7)]).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(geo.faces.len(), 3);
+        assert!(is_consistently_wound(&geo));
+        let bridge = geo.faces[2].normal(&geo.vertices);
+        let neighbour = geo.faces[0].normal(&geo.vertices);
+        assert!(bridge.dot(neighbour) > 0.99);
+    }
+
+    #[test]
+    fn test_loop_count() {
+        let grid = quad_grid(1, 1);
+        let topology = MeshTopology::build(&grid);
+        let result = BridgeEdgeLoops::new(&grid, &topology, topology.boundary_edges());
+        assert_eq!(result.unwrap_err(), BlockotError::BridgeLoopCount(1));
+    }
+
+    #[test]
+    fn test_mismatched_loops() {
+        let geo = unwelded_grid(2, 1);
+        let topology = MeshTopology::build(&geo);
+
+        let result = BridgeEdgeLoops::new(&geo, &topology, vec![(1, 2), (4, 5), (5, 6)]);
+        assert_eq!(result.unwrap_err(), BlockotError::BridgeLoopMismatch(1, 2));
+
+        let mut edges = vec![(0, 1), (1, 2), (2, 3), (3, 0)];
+        edges.push((4, 5));
+        let result = BridgeEdgeLoops::new(&geo, &topology, edges);
+        assert!(matches!(result, Err(BlockotError::OpenEdgeLoop(_))));
+    }
+
+    #[test]
+    fn test_bridge_undo_is_exact() {
+        let original = facing_open_cubes();
+        let mut geo = original.clone();
+        let topology = MeshTopology::build(&geo);
+        let cmd = BridgeEdgeLoops::new(&geo, &topology, topology.boundary_edges()).unwrap();
+
+        cmd.execute(&mut geo);
+        cmd.undo(&mut geo);
+
+        assert_eq!(geo, original);
+    }
+
+    #[test]
+    fn test_bridge_edge_loops_name() {
+        let geo = facing_open_cubes();
+        let topology = MeshTopology::build(&geo);
+        let cmd = BridgeEdgeLoops::new(&geo, &topology, topology.boundary_edges()).unwrap();
+        assert_eq!(cmd.name(), "Bridge Edge Loops");
+    }
+}
@@ -0,0 +1,221 @@
+// tools/commands/collapse_edges.rs - CollapseEdges command implementation
+//
+// Collapses edges to points: the endpoints of each group of connected
+// edges merge into the group's lowest vertex, placed at the group's
+// centre. Faces lose the merged corners, faces left with fewer than three
+// drop out, and the arrays are compacted (see mesh_edit.rs).
+// Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use godot::prelude::Vector3;
+
+use super::mesh_edit::{merge_vertices, MeshEdit};
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, MeshTopology};
+use crate::tools::Command;
+
+/// Command to collapse edges, merging their endpoints.
+#[derive(Debug, Clone)]
+pub struct CollapseEdges {
+    /// Collapsed edges (normalized, sorted, unique)
+    edges: Vec<Edge>,
+    /// Arrays before and after the collapse
+    edit: MeshEdit,
+}
+
+impl CollapseEdges {
+    /// Create a new CollapseEdges command. Duplicate edges (in either
+    /// direction) are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if edges is empty, or
+    /// `BlockotError::InvalidEdge` if an edge is not in the mesh.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        edges: Vec<Edge>,
+    ) -> Result<Self, BlockotError> {
+        if edges.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let edges: Vec<Edge> = edges
+            .into_iter()
+            .map(|(a, b)| edge_key(a, b))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&(a, b)) = edges.iter().find(|&&(a, b)| !topology.has_edge(a, b)) {
+            return Err(BlockotError::InvalidEdge(a, b));
+        }
+
+        let groups = connected_groups(&edges)
+            .into_iter()
+            .map(|group| {
+                let sum = group
+                    .iter()
+                    .fold(Vector3::ZERO, |acc, &v| acc + geo.vertices[v]);
+                let centre = sum / group.len() as f32;
+                (group, centre)
+            })
+            .collect::<Vec<_>>();
+        let edit = merge_vertices(geo, &groups);
+        Ok(Self { edges, edit })
+    }
+
+    /// Returns the collapsed edges.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the number of faces that collapsed away.
+    pub fn removed_face_count(&self) -> usize {
+        self.edit.removed_face_count()
+    }
+
+    /// Returns the index a vertex has after execute; merged vertices map to
+    /// the vertex they merged into.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for CollapseEdges {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Collapse Edges"
+    }
+}
+
+/// The vertices of each connected group of edges, sorted, groups ordered
+/// by their lowest vertex.
+fn connected_groups(edges: &[Edge]) -> Vec<Vec<usize>> {
+    let mut parent: BTreeMap<usize, usize> = BTreeMap::new();
+    fn root(parent: &mut BTreeMap<usize, usize>, v: usize) -> usize {
+        let p = *parent.entry(v).or_insert(v);
+        if p == v {
+            return v;
+        }
+        let r = root(parent, p);
+        parent.insert(v, r);
+        r
+    }
+    for &(a, b) in edges {
+        let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+        parent.insert(ra.max(rb), ra.min(rb));
+    }
+
+    let vertices: Vec<usize> = parent.keys().copied().collect();
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for v in vertices {
+        let r = root(&mut parent, v);
+        groups.entry(r).or_default().push(v);
+    }
+    groups.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube, vectors_approx_equal};
+
+    #[test]
+    fn test_collapse_grid_edge() {
+        let mut grid = quad_grid(2, 1);
+        let topology = MeshTopology::build(&grid);
+        let cmd = CollapseEdges::new(&grid, &topology, vec![(1, 4)]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        // Both quads turn into triangles meeting at the edge's middle
+        assert_eq!(grid.vertices.len(), 5);
+        assert_eq!(grid.faces.len(), 2);
+        assert!(grid.faces.iter().all(|face| face.is_triangle()));
+        let merged = cmd.remapped(4).unwrap();
+        assert_eq!(cmd.remapped(1), Some(merged));
+        assert!(vectors_approx_equal(
+            grid.vertices[merged],
+            Vector3::new(1.0, 0.0, 0.5),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_collapse_drops_degenerate_faces() {
+        let mut cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        // Collapsing two opposite edges of the top face squashes it away
+        let cmd = CollapseEdges::new(&cube, &topology, vec![(4, 5), (6, 7)]).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.vertices.len(), 6);
+        assert_eq!(cube.faces.len(), 5);
+        assert_eq!(cmd.removed_face_count(), 1);
+        assert!(MeshTopology::build(&cube).boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_connected_edges_collapse_together() {
+        let mut grid = quad_grid(3, 1);
+        let topology = MeshTopology::build(&grid);
+        let cmd = CollapseEdges::new(&grid, &topology, vec![(0, 1), (1, 2)]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(grid.vertices.len(), 6);
+        assert_eq!(cmd.remapped(2), cmd.remapped(0));
+        let merged = cmd.remapped(0).unwrap();
+        assert!(vectors_approx_equal(
+            grid.vertices[merged],
+            Vector3::new(1.0, 0.0, 0.0),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_collapse_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let topology = MeshTopology::build(&cube);
+        let cmd = CollapseEdges::new(&cube, &topology, vec![(4, 5), (1, 2)]).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_invalid_edge() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = CollapseEdges::new(&cube, &topology, vec![(0, 6)]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidEdge(0, 6));
+    }
+
+    #[test]
+    fn test_empty_selection() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = CollapseEdges::new(&cube, &topology, vec![]);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_collapse_edges_name() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let cmd = CollapseEdges::new(&cube, &topology, vec![(1, 0)]).unwrap();
+        assert_eq!(cmd.name(), "Collapse Edges");
+        assert_eq!(cmd.edges(), &[(0, 1)]);
+    }
+}
@@ -0,0 +1,136 @@
+// tools/commands/composite.rs - CompositeCommand implementation
+//
+// Groups several commands into a single undoable action.
+// Children execute in order and undo in reverse order.
+
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command that runs a sequence of child commands as one action.
+#[derive(Clone)]
+pub struct CompositeCommand {
+    /// Name shown in the undo menu
+    name: &'static str,
+    /// Child commands, in execution order
+    commands: Vec<Box<dyn Command>>,
+}
+
+impl CompositeCommand {
+    /// Create a new CompositeCommand from already-validated child commands.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptyComposite` if `commands` is empty.
+    pub fn new(name: &'static str, commands: Vec<Box<dyn Command>>) -> Result<Self, BlockotError> {
+        if commands.is_empty() {
+            return Err(BlockotError::EmptyComposite);
+        }
+        Ok(Self { name, commands })
+    }
+
+    /// Returns the child commands in execution order.
+    pub fn commands(&self) -> &[Box<dyn Command>] {
+        &self.commands
+    }
+
+    /// Returns the number of child commands.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if there are no child commands (never true for a constructed command).
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
+
+impl Command for CompositeCommand {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        for cmd in &self.commands {
+            cmd.execute(geo);
+        }
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        for cmd in self.commands.iter().rev() {
+            cmd.undo(geo);
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::unit_cube;
+    use crate::tools::commands::MoveVertices;
+    use godot::prelude::Vector3;
+
+    fn move_cmd(indices: Vec<usize>, offset: Vector3) -> Box<dyn Command> {
+        Box::new(MoveVertices::new(indices, offset).unwrap())
+    }
+
+    #[test]
+    fn test_composite_roundtrip() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        let cmd = CompositeCommand::new(
+            "Move Twice",
+            vec![
+                move_cmd(vec![0, 1], Vector3::new(1.0, 0.0, 0.0)),
+                move_cmd(vec![1, 2], Vector3::new(0.0, 1.0, 0.0)),
+            ],
+        )
+        .unwrap();
+
+        cmd.execute(&mut geo);
+        assert_eq!(
+            geo.vertices[0],
+            original.vertices[0] + Vector3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            geo.vertices[1],
+            original.vertices[1] + Vector3::new(1.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            geo.vertices[2],
+            original.vertices[2] + Vector3::new(0.0, 1.0, 0.0)
+        );
+
+        cmd.undo(&mut geo);
+        assert_eq!(geo, original);
+    }
+
+    #[test]
+    fn test_composite_empty_rejected() {
+        let result = CompositeCommand::new("Nothing", vec![]);
+        assert!(matches!(result, Err(BlockotError::EmptyComposite)));
+    }
+
+    #[test]
+    fn test_composite_name() {
+        let cmd = CompositeCommand::new("Batch", vec![move_cmd(vec![0], Vector3::ZERO)]).unwrap();
+        assert_eq!(cmd.name(), "Batch");
+        assert_eq!(cmd.len(), 1);
+    }
+
+    #[test]
+    fn test_boxed_command_clone() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        let boxed: Box<dyn Command> = Box::new(
+            CompositeCommand::new("Batch", vec![move_cmd(vec![3], Vector3::ONE)]).unwrap(),
+        );
+        let cloned = boxed.clone();
+
+        boxed.execute(&mut geo);
+        cloned.undo(&mut geo);
+        assert_eq!(geo, original);
+        assert_eq!(cloned.name(), "Batch");
+    }
+}
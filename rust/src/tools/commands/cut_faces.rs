@@ -0,0 +1,516 @@
+// tools/commands/cut_faces.rs - CutFaces command implementation
+//
+// Bisects faces with a plane: every edge of the target faces that crosses
+// the plane gets one new vertex, and every face using that edge - target or
+// not - gets it inserted into its loop, so neighbours stay watertight. A
+// target face whose loop then touches the plane at exactly two points, with
+// its remaining vertices on opposite sides, is split there into two n-gons
+// (the first keeps the face's index, the second is appended). Concave faces
+// crossed more than twice keep the new vertices but are not split.
+// The knife tool only targets the faces seen under its line on screen
+// (faces_under_line), so the plane does not slice through hidden walls.
+// Undo truncates the appended vertices/faces and restores rewritten faces,
+// so the arrays come back exactly. Validates at construction, execute/undo
+// are infallible.
+
+use std::collections::{BTreeSet, HashMap};
+
+use godot::prelude::Vector3;
+
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face};
+use crate::selection::raycast_faces;
+use crate::tools::Command;
+
+/// Vertices closer to the cutting plane than this count as on it.
+const CUT_EPSILON: f32 = 1e-5;
+
+/// A cutting plane in the node's local space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CutPlane {
+    /// Any point on the plane
+    pub point: Vector3,
+    /// Unit normal
+    pub normal: Vector3,
+}
+
+impl CutPlane {
+    /// Create a plane through `point`. Returns None for a zero normal.
+    pub fn new(point: Vector3, normal: Vector3) -> Option<Self> {
+        (normal.length() > 1e-6).then(|| Self {
+            point,
+            normal: normal.normalized(),
+        })
+    }
+
+    /// The plane containing two view rays, i.e. a line drawn on screen swept
+    /// along the view direction. Works for perspective (shared origin) and
+    /// orthogonal (shared direction) cameras. Returns None for parallel rays
+    /// from the same origin.
+    pub fn through_rays(
+        origin_a: Vector3,
+        direction_a: Vector3,
+        origin_b: Vector3,
+        direction_b: Vector3,
+    ) -> Option<Self> {
+        let a = origin_a + direction_a;
+        let b = origin_b + direction_b;
+        Self::new(a, (b - a).cross(direction_a + direction_b))
+    }
+
+    /// Signed distance of `v` from the plane, positive on the normal's side.
+    pub fn signed_distance(&self, v: Vector3) -> f32 {
+        (v - self.point).dot(self.normal)
+    }
+}
+
+/// The faces seen along a line drawn on screen, given by the view rays
+/// through its ends: the nearest face hit by each of `samples + 1` rays
+/// spread evenly from the first ray to the second. Sorted and unique.
+pub fn faces_under_line(
+    geo: &BlockotGeometry,
+    origin_a: Vector3,
+    direction_a: Vector3,
+    origin_b: Vector3,
+    direction_b: Vector3,
+    samples: usize,
+) -> Vec<usize> {
+    let samples = samples.max(1);
+    let faces: BTreeSet<usize> = (0..=samples)
+        .filter_map(|k| {
+            let t = k as f32 / samples as f32;
+            let origin = origin_a.lerp(origin_b, t);
+            let direction = direction_a.lerp(direction_b, t);
+            raycast_faces(geo, origin, direction).map(|hit| hit.face)
+        })
+        .collect();
+    faces.into_iter().collect()
+}
+
+/// Command to cut faces along a plane.
+#[derive(Debug, Clone)]
+pub struct CutFaces {
+    /// Positions of the vertices inserted on crossed edges, appended in order
+    new_vertices: Vec<Vector3>,
+    /// Faces rewritten in place (inserted vertices, or the first half of a split)
+    rewritten: Vec<usize>,
+    /// `rewritten` faces before the cut, restored on undo
+    original_faces: Vec<Face>,
+    /// `rewritten` faces after the cut
+    cut_faces: Vec<Face>,
+    /// Second halves of split faces, appended after the existing faces
+    appended: Vec<Face>,
+    /// Vertices the cut runs through (sorted)
+    cut_vertices: Vec<usize>,
+    /// Vertex count before the cut
+    vertex_count: usize,
+    /// Face count before the cut
+    face_count: usize,
+}
+
+impl CutFaces {
+    /// Create a new CutFaces command splitting `faces` along `plane`.
+    /// Duplicate face indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if faces is empty,
+    /// `BlockotError::InvalidFaceIndex` if any face index is out of bounds, or
+    /// `BlockotError::InvalidVertexIndex` if a face references a missing vertex.
+    pub fn new(
+        geo: &BlockotGeometry,
+        faces: Vec<usize>,
+        plane: CutPlane,
+    ) -> Result<Self, BlockotError> {
+        if faces.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let faces: BTreeSet<usize> = faces.into_iter().collect();
+        for &f in &faces {
+            let face = geo.faces.get(f).ok_or(BlockotError::InvalidFaceIndex(f))?;
+            if let Some(&v) = face
+                .vertex_indices
+                .iter()
+                .find(|&&v| v >= geo.vertices.len())
+            {
+                return Err(BlockotError::InvalidVertexIndex(v));
+            }
+        }
+
+        let vertex_count = geo.vertices.len();
+        // Inserted vertices lie on the plane by construction
+        let side = |v: usize| -> f32 {
+            if v >= vertex_count {
+                return 0.0;
+            }
+            let d = plane.signed_distance(geo.vertices[v]);
+            if d.abs() < CUT_EPSILON {
+                0.0
+            } else {
+                d
+            }
+        };
+
+        let mut split_at: HashMap<Edge, usize> = HashMap::new();
+        let mut new_vertices = Vec::new();
+        for &f in &faces {
+            for (a, b) in geo.faces[f].edges() {
+                if side(a) * side(b) >= 0.0 {
+                    continue;
+                }
+                split_at.entry(edge_key(a, b)).or_insert_with(|| {
+                    // Interpolate in key order so both faces of the edge agree
+                    let (a, b) = edge_key(a, b);
+                    let t = side(a) / (side(a) - side(b));
+                    new_vertices.push(geo.vertices[a].lerp(geo.vertices[b], t));
+                    vertex_count + new_vertices.len() - 1
+                });
+            }
+        }
+
+        let mut rewritten = Vec::new();
+        let mut original_faces = Vec::new();
+        let mut cut_faces = Vec::new();
+        let mut appended = Vec::new();
+        let mut cut_vertices = BTreeSet::new();
+        for (f, face) in geo.faces.iter().enumerate() {
+            let ring = insert_cut_points(face, &split_at);
+            let split = if faces.contains(&f) {
+                split_ring(&ring, side)
+            } else {
+                None
+            };
+
+            let cut = match split {
+                Some((first, second)) => {
+                    cut_vertices.insert(first[0]);
+                    cut_vertices.insert(second[0]);
+                    appended.push(Face::new(second));
+                    Face::new(first)
+                }
+                None if ring.len() != face.vertex_indices.len() => Face::new(ring),
+                None => continue,
+            };
+            rewritten.push(f);
+            original_faces.push(face.clone());
+            cut_faces.push(cut);
+        }
+
+        Ok(Self {
+            new_vertices,
+            rewritten,
+            original_faces,
+            cut_faces,
+            appended,
+            cut_vertices: cut_vertices.into_iter().collect(),
+            vertex_count,
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns how many faces the cut splits in two.
+    pub fn split_count(&self) -> usize {
+        self.appended.len()
+    }
+
+    /// Returns the vertices the cut runs through after execute: vertices
+    /// inserted on crossed edges and existing vertices on the plane.
+    pub fn cut_vertices(&self) -> &[usize] {
+        &self.cut_vertices
+    }
+}
+
+impl Command for CutFaces {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        geo.vertices.extend_from_slice(&self.new_vertices);
+        for (&f, cut) in self.rewritten.iter().zip(&self.cut_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = cut.clone();
+            }
+        }
+        geo.faces.extend(self.appended.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        for (&f, original) in self.rewritten.iter().zip(&self.original_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = original.clone();
+            }
+        }
+        geo.vertices.truncate(self.vertex_count);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Cut Faces"
+    }
+}
+
+/// The face's vertex loop with the split vertex of each crossed edge inserted.
+fn insert_cut_points(face: &Face, split_at: &HashMap<Edge, usize>) -> Vec<usize> {
+    let mut ring = Vec::with_capacity(face.vertex_indices.len());
+    for (a, b) in face.edges() {
+        ring.push(a);
+        if let Some(&v) = split_at.get(&edge_key(a, b)) {
+            ring.push(v);
+        }
+    }
+    ring
+}
+
+/// Split a loop at its two on-plane vertices, if the rest of the loop lies
+/// on opposite sides of the plane. Both halves start at a cut vertex and
+/// keep the loop's winding.
+fn split_ring(ring: &[usize], side: impl Fn(usize) -> f32) -> Option<(Vec<usize>, Vec<usize>)> {
+    let cuts: Vec<usize> = (0..ring.len()).filter(|&i| side(ring[i]) == 0.0).collect();
+    let &[i, j] = cuts.as_slice() else {
+        return None;
+    };
+
+    let first: Vec<usize> = ring[i..=j].to_vec();
+    let second: Vec<usize> = ring[j..].iter().chain(&ring[..=i]).copied().collect();
+    let inner = |half: &[usize]| half[1..half.len() - 1].iter().map(|&v| side(v)).collect();
+    let (first_sides, second_sides): (Vec<f32>, Vec<f32>) = (inner(&first), inner(&second));
+
+    let all = |sides: &[f32], positive: bool| {
+        !sides.is_empty() && sides.iter().all(|&d| (d > 0.0) == positive)
+    };
+    let opposite = (all(&first_sides, true) && all(&second_sides, false))
+        || (all(&first_sides, false) && all(&second_sides, true));
+    opposite.then_some((first, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, single_quad, unit_cube, vectors_approx_equal};
+
+    fn plane(point: Vector3, normal: Vector3) -> CutPlane {
+        CutPlane::new(point, normal).unwrap()
+    }
+
+    #[test]
+    fn test_cut_quad_in_half() {
+        let mut quad = single_quad();
+        let cut = plane(Vector3::new(0.5, 0.0, 0.0), Vector3::RIGHT);
+        let cmd = CutFaces::new(&quad, vec![0], cut).unwrap();
+
+        cmd.execute(&mut quad);
+
+        assert_eq!(cmd.split_count(), 1);
+        assert_eq!(quad.vertices.len(), 6);
+        assert_eq!(quad.faces.len(), 2);
+        assert_eq!(cmd.cut_vertices(), &[4, 5]);
+        for v in [4, 5] {
+            assert!((quad.vertices[v].x - 0.5).abs() < 1e-6);
+        }
+        for face in &quad.faces {
+            assert_eq!(face.vertex_count(), 4);
+        }
+    }
+
+    #[test]
+    fn test_halves_keep_winding() {
+        let mut quad = single_quad();
+        let normal = quad.faces[0].normal(&quad.vertices);
+        let cut = plane(Vector3::new(0.3, 0.0, 0.0), Vector3::RIGHT);
+        let cmd = CutFaces::new(&quad, vec![0], cut).unwrap();
+
+        cmd.execute(&mut quad);
+
+        for face in &quad.faces {
+            assert!(face.normal(&quad.vertices).dot(normal) > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_cut_through_corners() {
+        let mut quad = single_quad();
+        // The diagonal from vertex 0 to vertex 2
+        let cut = plane(Vector3::ZERO, Vector3::new(1.0, -1.0, 0.0));
+        let cmd = CutFaces::new(&quad, vec![0], cut).unwrap();
+
+        cmd.execute(&mut quad);
+
+        assert_eq!(quad.vertices.len(), 4);
+        assert_eq!(cmd.cut_vertices(), &[0, 2]);
+        assert!(quad.faces.iter().all(Face::is_triangle));
+    }
+
+    #[test]
+    fn test_cut_cube_around_middle() {
+        let mut cube = unit_cube();
+        let cut = plane(Vector3::ZERO, Vector3::UP);
+        let cmd = CutFaces::new(&cube, (0..6).collect(), cut).unwrap();
+
+        cmd.execute(&mut cube);
+
+        // Four side faces split, top and bottom untouched
+        assert_eq!(cmd.split_count(), 4);
+        assert_eq!(cube.vertices.len(), 12);
+        assert_eq!(cube.faces.len(), 10);
+        for &v in cmd.cut_vertices() {
+            assert!(cube.vertices[v].y.abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_neighbour_keeps_its_normal() {
+        // Two stacked quads of a wall facing -Z; the upper one starts with
+        // the shared edge, so the cut vertex lands on its first corner
+        let mut wall = BlockotGeometry::new();
+        wall.vertices = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(0.0, 2.0, 0.0),
+        ];
+        wall.faces = vec![Face::quad(0, 1, 2, 3), Face::quad(3, 2, 4, 5)];
+        let cut = plane(Vector3::new(0.5, 0.0, 0.0), Vector3::RIGHT);
+        let cmd = CutFaces::new(&wall, vec![0], cut).unwrap();
+
+        cmd.execute(&mut wall);
+
+        assert_eq!(wall.faces[1].vertex_count(), 5);
+        let normal = wall.faces[1].normal(&wall.vertices);
+        assert!(vectors_approx_equal(
+            normal,
+            Vector3::new(0.0, 0.0, -1.0),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn test_neighbour_gets_shared_vertex() {
+        let mut grid = quad_grid(2, 1);
+        let cut = plane(Vector3::new(0.0, 0.0, 0.5), Vector3::BACK);
+        // Only the left face is cut; the right one shares edge 1-4
+        let cmd = CutFaces::new(&grid, vec![0], cut).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.split_count(), 1);
+        // Edge 1-4 is crossed first, so its vertex is the first one appended
+        let shared = 6;
+        assert!(vectors_approx_equal(
+            grid.vertices[shared],
+            Vector3::new(1.0, 0.0, 0.5),
+            1e-6
+        ));
+        assert_eq!(grid.faces[1].vertex_count(), 5);
+        assert!(grid.faces[1].vertex_indices.contains(&shared));
+    }
+
+    #[test]
+    fn test_cut_along_existing_edge_does_nothing() {
+        let grid = quad_grid(2, 1);
+        let cut = plane(Vector3::new(1.0, 0.0, 0.0), Vector3::RIGHT);
+        let cmd = CutFaces::new(&grid, vec![0, 1], cut).unwrap();
+
+        assert_eq!(cmd.split_count(), 0);
+        assert!(cmd.cut_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_cut_missing_mesh_does_nothing() {
+        let mut quad = single_quad();
+        let cut = plane(Vector3::new(5.0, 0.0, 0.0), Vector3::RIGHT);
+        let cmd = CutFaces::new(&quad, vec![0], cut).unwrap();
+
+        cmd.execute(&mut quad);
+
+        assert_eq!(cmd.split_count(), 0);
+        assert_eq!(quad, single_quad());
+    }
+
+    #[test]
+    fn test_cut_undo_is_exact() {
+        let original = quad_grid(2, 2);
+        let mut grid = original.clone();
+        let cut = plane(Vector3::new(0.7, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.4));
+        let cmd = CutFaces::new(&grid, vec![0, 1, 2, 3], cut).unwrap();
+
+        cmd.execute(&mut grid);
+        assert!(cmd.split_count() > 0);
+        cmd.undo(&mut grid);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_plane_through_perspective_rays() {
+        let origin = Vector3::new(0.0, 2.0, 0.0);
+        let cut = CutPlane::through_rays(
+            origin,
+            Vector3::new(-1.0, 0.0, -1.0),
+            origin,
+            Vector3::new(1.0, 0.0, -1.0),
+        )
+        .unwrap();
+
+        // Both rays run level, so the plane is horizontal through the camera
+        assert!(cut.normal.y.abs() > 0.999);
+        assert!(cut.signed_distance(origin).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_plane_through_orthogonal_rays() {
+        let forward = Vector3::FORWARD;
+        let cut = CutPlane::through_rays(Vector3::ZERO, forward, Vector3::UP, forward).unwrap();
+
+        assert!(cut.normal.x.abs() > 0.999);
+    }
+
+    #[test]
+    fn test_cut_under_line_leaves_back_wall() {
+        let mut cube = unit_cube();
+        let back = cube.faces[1].clone();
+
+        // Vertical line across the front of the cube, seen straight on
+        let (top, bottom) = (
+            Vector3::new(0.0, 1.0, -10.0),
+            Vector3::new(0.0, -1.0, -10.0),
+        );
+        let faces = faces_under_line(&cube, top, Vector3::BACK, bottom, Vector3::BACK, 10);
+        assert_eq!(faces, vec![0]);
+
+        let cut = CutPlane::through_rays(top, Vector3::BACK, bottom, Vector3::BACK).unwrap();
+        let cmd = CutFaces::new(&cube, faces, cut).unwrap();
+        cmd.execute(&mut cube);
+
+        assert_eq!(cmd.split_count(), 1);
+        assert_eq!(cube.faces.len(), 7);
+        assert_eq!(cube.faces[1], back);
+    }
+
+    #[test]
+    fn test_degenerate_plane() {
+        assert!(CutPlane::new(Vector3::ZERO, Vector3::ZERO).is_none());
+        let ray = Vector3::FORWARD;
+        assert!(CutPlane::through_rays(Vector3::ZERO, ray, Vector3::ZERO, ray).is_none());
+    }
+
+    #[test]
+    fn test_cut_errors() {
+        let quad = single_quad();
+        let cut = plane(Vector3::ZERO, Vector3::RIGHT);
+        assert_eq!(
+            CutFaces::new(&quad, vec![], cut).unwrap_err(),
+            BlockotError::EmptySelection
+        );
+        assert_eq!(
+            CutFaces::new(&quad, vec![1], cut).unwrap_err(),
+            BlockotError::InvalidFaceIndex(1)
+        );
+    }
+
+    #[test]
+    fn test_cut_name() {
+        let quad = single_quad();
+        let cmd = CutFaces::new(&quad, vec![0], plane(Vector3::ZERO, Vector3::RIGHT)).unwrap();
+        assert_eq!(cmd.name(), "Cut Faces");
+    }
+}
@@ -0,0 +1,152 @@
+// tools/commands/delete_faces.rs - DeleteFaces command implementation
+//
+// Deletes faces. Vertices that only the deleted faces used are deleted too,
+// and the remaining vertices and faces are compacted, remapping every face
+// (see mesh_edit.rs). Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::mesh_edit::MeshEdit;
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command to delete faces and the vertices only they use.
+#[derive(Debug, Clone)]
+pub struct DeleteFaces {
+    /// Deleted face indices (sorted, unique)
+    faces: Vec<usize>,
+    /// Arrays before and after the delete
+    edit: MeshEdit,
+}
+
+impl DeleteFaces {
+    /// Create a new DeleteFaces command. Duplicate indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if faces is empty, or
+    /// `BlockotError::InvalidFaceIndex` if any index is out of bounds.
+    pub fn new(geo: &BlockotGeometry, faces: Vec<usize>) -> Result<Self, BlockotError> {
+        if faces.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let faces: Vec<usize> = faces
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&f) = faces.iter().find(|&&f| f >= geo.faces.len()) {
+            return Err(BlockotError::InvalidFaceIndex(f));
+        }
+
+        let deleted: HashSet<usize> = faces.iter().copied().collect();
+        let kept = geo
+            .faces
+            .iter()
+            .enumerate()
+            .map(|(f, face)| (!deleted.contains(&f)).then(|| face.clone()))
+            .collect();
+        let edit = MeshEdit::compact(geo, geo.vertices.clone(), kept, &HashSet::new());
+        Ok(Self { faces, edit })
+    }
+
+    /// Returns the deleted face indices.
+    pub fn faces(&self) -> &[usize] {
+        &self.faces
+    }
+
+    /// Returns the number of vertices removed with the faces.
+    pub fn removed_vertex_count(&self) -> usize {
+        self.edit.removed_vertex_count()
+    }
+
+    /// Returns the index a vertex has after execute, None if it is deleted.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for DeleteFaces {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Delete Faces"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::MeshTopology;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    #[test]
+    fn test_delete_cube_face_opens_it() {
+        let mut cube = unit_cube();
+        let cmd = DeleteFaces::new(&cube, vec![2]).unwrap();
+
+        cmd.execute(&mut cube);
+
+        // Every vertex is still used by a side face
+        assert_eq!(cube.vertices.len(), 8);
+        assert_eq!(cube.faces.len(), 5);
+        assert_eq!(cmd.removed_vertex_count(), 0);
+        assert_eq!(MeshTopology::build(&cube).boundary_edges().len(), 4);
+    }
+
+    #[test]
+    fn test_delete_faces_compacts_vertices() {
+        let mut grid = quad_grid(2, 2);
+        let cmd = DeleteFaces::new(&grid, vec![0, 1]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        // The bottom row 0, 1, 2 is gone; the rest shift down by three
+        assert_eq!(grid.vertices.len(), 6);
+        assert_eq!(grid.faces.len(), 2);
+        assert_eq!(grid.faces[0].vertex_indices, vec![0, 1, 4, 3]);
+        assert_eq!(cmd.remapped(1), None);
+        assert_eq!(cmd.remapped(8), Some(5));
+    }
+
+    #[test]
+    fn test_delete_faces_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = DeleteFaces::new(&cube, vec![5, 0, 3]).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_invalid_face() {
+        let cube = unit_cube();
+        let result = DeleteFaces::new(&cube, vec![6]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidFaceIndex(6));
+    }
+
+    #[test]
+    fn test_empty_selection() {
+        let cube = unit_cube();
+        let result = DeleteFaces::new(&cube, vec![]);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_delete_faces_name() {
+        let cube = unit_cube();
+        let cmd = DeleteFaces::new(&cube, vec![1, 1]).unwrap();
+        assert_eq!(cmd.name(), "Delete Faces");
+        assert_eq!(cmd.faces(), &[1]);
+    }
+}
@@ -0,0 +1,154 @@
+// tools/commands/delete_vertices.rs - DeleteVertices command implementation
+//
+// Deletes vertices together with every face using them. Vertices that only
+// those faces used are deleted too, and the remaining vertices are
+// compacted, remapping every face (see mesh_edit.rs).
+// Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::mesh_edit::MeshEdit;
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command to delete vertices and the faces using them.
+#[derive(Debug, Clone)]
+pub struct DeleteVertices {
+    /// Deleted vertex indices (sorted, unique)
+    vertices: Vec<usize>,
+    /// Arrays before and after the delete
+    edit: MeshEdit,
+}
+
+impl DeleteVertices {
+    /// Create a new DeleteVertices command. Duplicate indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if vertices is empty, or
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds.
+    pub fn new(geo: &BlockotGeometry, vertices: Vec<usize>) -> Result<Self, BlockotError> {
+        if vertices.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let vertices: Vec<usize> = vertices
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&v) = vertices.iter().find(|&&v| v >= geo.vertices.len()) {
+            return Err(BlockotError::InvalidVertexIndex(v));
+        }
+
+        let removed: HashSet<usize> = vertices.iter().copied().collect();
+        let faces = geo.faces.iter().cloned().map(Some).collect();
+        let edit = MeshEdit::compact(geo, geo.vertices.clone(), faces, &removed);
+        Ok(Self { vertices, edit })
+    }
+
+    /// Returns the deleted vertex indices.
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    /// Returns the number of vertices removed, including ones left unused.
+    pub fn removed_vertex_count(&self) -> usize {
+        self.edit.removed_vertex_count()
+    }
+
+    /// Returns the number of faces removed.
+    pub fn removed_face_count(&self) -> usize {
+        self.edit.removed_face_count()
+    }
+
+    /// Returns the index a vertex has after execute, None if it is deleted.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for DeleteVertices {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Delete Vertices"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Face;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    #[test]
+    fn test_delete_cube_corner() {
+        let mut cube = unit_cube();
+        let cmd = DeleteVertices::new(&cube, vec![0]).unwrap();
+
+        cmd.execute(&mut cube);
+
+        // Front, bottom and left used vertex 0
+        assert_eq!(cube.vertices.len(), 7);
+        assert_eq!(cube.faces.len(), 3);
+        assert_eq!(cmd.removed_face_count(), 3);
+        // Indices shift down past the deleted vertex
+        assert_eq!(cube.faces[0].vertex_indices, vec![1, 2, 6, 5]);
+        assert_eq!(cmd.remapped(7), Some(6));
+        assert_eq!(cmd.remapped(0), None);
+    }
+
+    #[test]
+    fn test_delete_drops_vertices_left_unused() {
+        let mut grid = quad_grid(2, 1);
+        // Vertex 2 is only used by face 1, which goes with vertex 5
+        let cmd = DeleteVertices::new(&grid, vec![5]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.removed_vertex_count(), 2);
+        assert_eq!(grid.vertices.len(), 4);
+        assert_eq!(grid.faces, vec![Face::quad(0, 1, 3, 2)]);
+    }
+
+    #[test]
+    fn test_delete_vertices_undo_is_exact() {
+        let original = quad_grid(3, 3);
+        let mut grid = original.clone();
+        let cmd = DeleteVertices::new(&grid, vec![5, 10, 6]).unwrap();
+
+        cmd.execute(&mut grid);
+        cmd.undo(&mut grid);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_invalid_vertex() {
+        let cube = unit_cube();
+        let result = DeleteVertices::new(&cube, vec![1, 8]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidVertexIndex(8));
+    }
+
+    #[test]
+    fn test_empty_selection() {
+        let cube = unit_cube();
+        let result = DeleteVertices::new(&cube, vec![]);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_delete_vertices_name() {
+        let cube = unit_cube();
+        let cmd = DeleteVertices::new(&cube, vec![3, 3]).unwrap();
+        assert_eq!(cmd.name(), "Delete Vertices");
+        assert_eq!(cmd.vertices(), &[3]);
+    }
+}
@@ -0,0 +1,237 @@
+// tools/commands/dissolve_edges.rs - DissolveEdges command implementation
+//
+// Dissolves edges: the two faces on either side of each edge merge into one
+// n-gon, keeping the first face's winding. Edges are dissolved one after the
+// other, so a chain of edges merges a whole strip of faces; an edge whose
+// two sides have already merged into the same face is left alone. The merged
+// face takes the lower of the two face slots, corners left dangling by the
+// merge fold away, and the arrays are compacted (see mesh_edit.rs).
+// Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::mesh_edit::{clean_face, MeshEdit};
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to dissolve edges, merging the faces on either side.
+#[derive(Debug, Clone)]
+pub struct DissolveEdges {
+    /// Dissolved edges (normalized, sorted, unique)
+    edges: Vec<Edge>,
+    /// Arrays before and after the dissolve
+    edit: MeshEdit,
+}
+
+impl DissolveEdges {
+    /// Create a new DissolveEdges command. Duplicate edges (in either
+    /// direction) are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if edges is empty,
+    /// `BlockotError::InvalidEdge` if an edge is not in the mesh, or
+    /// `BlockotError::NonManifoldEdge` if an edge does not join exactly two faces.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        edges: Vec<Edge>,
+    ) -> Result<Self, BlockotError> {
+        if edges.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let edges: Vec<Edge> = edges
+            .into_iter()
+            .map(|(a, b)| edge_key(a, b))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        for &(a, b) in &edges {
+            if !topology.has_edge(a, b) {
+                return Err(BlockotError::InvalidEdge(a, b));
+            }
+            if topology.edge_faces(a, b).len() != 2 {
+                return Err(BlockotError::NonManifoldEdge(a, b));
+            }
+        }
+
+        let mut faces: Vec<Option<Face>> = geo.faces.iter().cloned().map(Some).collect();
+        for &(a, b) in &edges {
+            let using = |from: usize, to: usize| {
+                faces.iter().position(|face| {
+                    face.as_ref()
+                        .is_some_and(|face| face.edges().any(|edge| edge == (from, to)))
+                })
+            };
+            let (Some(forward), Some(backward)) = (using(a, b), using(b, a)) else {
+                continue;
+            };
+            if forward == backward {
+                continue;
+            }
+            let merged = match (&faces[forward], &faces[backward]) {
+                (Some(first), Some(second)) => join_faces(first, second, a, b),
+                _ => continue,
+            };
+            faces[forward.max(backward)] = None;
+            faces[forward.min(backward)] = clean_face(merged);
+        }
+
+        let edit = MeshEdit::compact(geo, geo.vertices.clone(), faces, &HashSet::new());
+        Ok(Self { edges, edit })
+    }
+
+    /// Returns the dissolved edges.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the number of faces merged away.
+    pub fn removed_face_count(&self) -> usize {
+        self.edit.removed_face_count()
+    }
+
+    /// Returns the index a vertex has after execute, None if it is removed.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for DissolveEdges {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Dissolve Edges"
+    }
+}
+
+/// Join `first`, which runs `a` -> `b`, with `second`, which runs `b` -> `a`,
+/// dropping the shared edge.
+fn join_faces(first: &Face, second: &Face, a: usize, b: usize) -> Vec<usize> {
+    let from = |face: &Face, start: usize| {
+        let indices = &face.vertex_indices;
+        let pos = indices.iter().position(|&v| v == start).unwrap_or(0);
+        let mut rotated = indices[pos..].to_vec();
+        rotated.extend_from_slice(&indices[..pos]);
+        rotated
+    };
+    // First face from b around to a, then the second face's corners between a and b
+    let mut joined = from(first, b);
+    let rest = from(second, a);
+    joined.extend_from_slice(&rest[1..rest.len() - 1]);
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use godot::prelude::Vector3;
+
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube, vectors_approx_equal};
+
+    #[test]
+    fn test_dissolve_edge_merges_two_quads() {
+        let mut grid = quad_grid(2, 1);
+        let topology = MeshTopology::build(&grid);
+        let cmd = DissolveEdges::new(&grid, &topology, vec![(1, 4)]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(grid.faces.len(), 1);
+        assert_eq!(cmd.removed_face_count(), 1);
+        // All six corners remain, in the original winding
+        assert_eq!(grid.faces[0].vertex_count(), 6);
+        assert_eq!(grid.vertices.len(), 6);
+        let normal = grid.faces[0].normal(&grid.vertices);
+        assert!(vectors_approx_equal(normal, Vector3::UP, 1e-5));
+    }
+
+    #[test]
+    fn test_dissolve_chain_merges_strip() {
+        let mut grid = quad_grid(3, 1);
+        let topology = MeshTopology::build(&grid);
+        let cmd = DissolveEdges::new(&grid, &topology, vec![(1, 5), (2, 6)]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(grid.faces.len(), 1);
+        assert_eq!(grid.faces[0].vertex_count(), 8);
+    }
+
+    #[test]
+    fn test_dissolve_drops_dangling_vertex() {
+        let mut grid = quad_grid(2, 2);
+        let topology = MeshTopology::build(&grid);
+        // Dissolving every edge at the centre merges all four quads; the
+        // centre vertex is left dangling and goes away
+        let cmd =
+            DissolveEdges::new(&grid, &topology, vec![(1, 4), (3, 4), (4, 5), (4, 7)]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(grid.faces.len(), 1);
+        assert_eq!(grid.faces[0].vertex_count(), 8);
+        assert_eq!(grid.vertices.len(), 8);
+        assert_eq!(cmd.remapped(4), None);
+        assert_eq!(cmd.remapped(8), Some(7));
+    }
+
+    #[test]
+    fn test_dissolve_cube_edge() {
+        let mut cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let cmd = DissolveEdges::new(&cube, &topology, vec![(4, 5)]).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.faces.len(), 5);
+        let after = MeshTopology::build(&cube);
+        assert!(after.boundary_edges().is_empty());
+        assert!(!after.has_edge(4, 5));
+    }
+
+    #[test]
+    fn test_dissolve_edges_undo_is_exact() {
+        let original = quad_grid(2, 2);
+        let mut grid = original.clone();
+        let topology = MeshTopology::build(&grid);
+        let cmd = DissolveEdges::new(&grid, &topology, vec![(1, 4), (4, 5)]).unwrap();
+
+        cmd.execute(&mut grid);
+        cmd.undo(&mut grid);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_boundary_edge_is_rejected() {
+        let grid = quad_grid(1, 1);
+        let topology = MeshTopology::build(&grid);
+        let result = DissolveEdges::new(&grid, &topology, vec![(0, 1)]);
+        assert_eq!(result.unwrap_err(), BlockotError::NonManifoldEdge(0, 1));
+    }
+
+    #[test]
+    fn test_invalid_edge() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = DissolveEdges::new(&cube, &topology, vec![(0, 6)]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidEdge(0, 6));
+    }
+
+    #[test]
+    fn test_dissolve_edges_name() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let cmd = DissolveEdges::new(&cube, &topology, vec![(5, 4)]).unwrap();
+        assert_eq!(cmd.name(), "Dissolve Edges");
+        assert_eq!(cmd.edges(), &[(4, 5)]);
+    }
+}
@@ -0,0 +1,244 @@
+// tools/commands/dissolve_vertices.rs - DissolveVertices command implementation
+//
+// Dissolves vertices without opening holes. A vertex joining just two edges
+// is dropped from its faces, so the two edges become one. Any other vertex
+// merges the fan of faces around it into one n-gon running along the fan's
+// outer corners, in the faces' winding; on an open border the n-gon closes
+// straight across where the vertex was. Vertices are dissolved one after
+// the other, the merged face takes the lowest slot of its fan, and the
+// arrays are compacted (see mesh_edit.rs).
+// Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::mesh_edit::{clean_face, MeshEdit};
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, Face};
+use crate::tools::Command;
+
+/// Command to dissolve vertices, merging the faces around them.
+#[derive(Debug, Clone)]
+pub struct DissolveVertices {
+    /// Dissolved vertex indices (sorted, unique)
+    vertices: Vec<usize>,
+    /// Arrays before and after the dissolve
+    edit: MeshEdit,
+}
+
+impl DissolveVertices {
+    /// Create a new DissolveVertices command. Duplicate indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if vertices is empty,
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds, or
+    /// `BlockotError::NonManifoldVertex` if the faces around a vertex do not
+    /// form a single consistently wound fan.
+    pub fn new(geo: &BlockotGeometry, vertices: Vec<usize>) -> Result<Self, BlockotError> {
+        if vertices.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let vertices: Vec<usize> = vertices
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&v) = vertices.iter().find(|&&v| v >= geo.vertices.len()) {
+            return Err(BlockotError::InvalidVertexIndex(v));
+        }
+
+        let mut faces: Vec<Option<Face>> = geo.faces.iter().cloned().map(Some).collect();
+        for &v in &vertices {
+            dissolve_vertex(&mut faces, v)?;
+        }
+
+        let removed: HashSet<usize> = vertices.iter().copied().collect();
+        let edit = MeshEdit::compact(geo, geo.vertices.clone(), faces, &removed);
+        Ok(Self { vertices, edit })
+    }
+
+    /// Returns the dissolved vertex indices.
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    /// Returns the number of faces merged away.
+    pub fn removed_face_count(&self) -> usize {
+        self.edit.removed_face_count()
+    }
+
+    /// Returns the index a vertex has after execute, None if it is removed.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for DissolveVertices {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Dissolve Vertices"
+    }
+}
+
+/// Dissolve `v` in the working faces (None for a face merged away).
+fn dissolve_vertex(faces: &mut [Option<Face>], v: usize) -> Result<(), BlockotError> {
+    // Each face around v, with v's neighbours before and after it
+    let around: Vec<(usize, usize, usize)> = faces
+        .iter()
+        .enumerate()
+        .filter_map(|(f, face)| {
+            let indices = &face.as_ref()?.vertex_indices;
+            let n = indices.len();
+            let pos = indices.iter().position(|&x| x == v)?;
+            Some((f, indices[(pos + n - 1) % n], indices[(pos + 1) % n]))
+        })
+        .collect();
+    if around.is_empty() {
+        return Ok(());
+    }
+
+    let neighbours: HashSet<usize> = around
+        .iter()
+        .flat_map(|&(_, prev, next)| [prev, next])
+        .collect();
+    if neighbours.len() == 2 {
+        // v only splits an edge in two: take it out of its faces
+        for &(f, _, _) in &around {
+            if let Some(face) = faces[f].take() {
+                let indices = face.vertex_indices.into_iter().filter(|&x| x != v);
+                faces[f] = clean_face(indices.collect());
+            }
+        }
+        return Ok(());
+    }
+
+    // Order the fan: each face's next neighbour is the following face's
+    // previous one; an open fan starts where no face leads in
+    let start = around
+        .iter()
+        .position(|&(_, prev, _)| !around.iter().any(|&(_, _, next)| next == prev))
+        .unwrap_or(0);
+    let mut fan = vec![around[start]];
+    while fan.len() < around.len() {
+        let (_, _, next) = fan[fan.len() - 1];
+        let following = around
+            .iter()
+            .find(|&&(_, prev, _)| prev == next)
+            .filter(|&&(f, _, _)| !fan.iter().any(|&(g, _, _)| g == f))
+            .ok_or(BlockotError::NonManifoldVertex(v))?;
+        fan.push(*following);
+    }
+
+    // Walk the fan backwards, each face from after v around to before it
+    let mut merged: Vec<usize> = Vec::new();
+    for &(f, _, _) in fan.iter().rev() {
+        let Some(face) = &faces[f] else {
+            continue;
+        };
+        let indices = &face.vertex_indices;
+        let pos = indices.iter().position(|&x| x == v).unwrap_or(0);
+        for k in 1..indices.len() {
+            let corner = indices[(pos + k) % indices.len()];
+            if merged.last() != Some(&corner) {
+                merged.push(corner);
+            }
+        }
+    }
+    let first = fan.iter().map(|&(f, _, _)| f).min().unwrap_or(0);
+    for &(f, _, _) in &fan {
+        faces[f] = None;
+    }
+    faces[first] = clean_face(merged);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::MeshTopology;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    #[test]
+    fn test_dissolve_grid_centre() {
+        let mut grid = quad_grid(2, 2);
+        let cmd = DissolveVertices::new(&grid, vec![4]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(grid.faces.len(), 1);
+        assert_eq!(grid.faces[0].vertex_count(), 8);
+        assert_eq!(grid.vertices.len(), 8);
+        assert_eq!(cmd.removed_face_count(), 3);
+        assert_eq!(MeshTopology::build(&grid).boundary_edges().len(), 8);
+    }
+
+    #[test]
+    fn test_dissolve_cube_corner_stays_closed() {
+        let mut cube = unit_cube();
+        let cmd = DissolveVertices::new(&cube, vec![6]).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.vertices.len(), 7);
+        assert_eq!(cube.faces.len(), 4);
+        assert!(MeshTopology::build(&cube).boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_dissolve_border_vertex() {
+        let mut grid = quad_grid(2, 1);
+        // Vertex 1 sits on the border between both quads
+        let cmd = DissolveVertices::new(&grid, vec![1]).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(grid.faces.len(), 1);
+        assert_eq!(grid.faces[0].vertex_count(), 5);
+    }
+
+    #[test]
+    fn test_dissolve_vertex_between_two_edges() {
+        let mut quad = quad_grid(1, 1);
+        // Corner 0 of a lone quad joins two edges: the quad becomes a triangle
+        let cmd = DissolveVertices::new(&quad, vec![0]).unwrap();
+
+        cmd.execute(&mut quad);
+
+        assert_eq!(quad.vertices.len(), 3);
+        assert_eq!(quad.faces[0].vertex_count(), 3);
+    }
+
+    #[test]
+    fn test_dissolve_vertices_undo_is_exact() {
+        let original = quad_grid(3, 3);
+        let mut grid = original.clone();
+        let cmd = DissolveVertices::new(&grid, vec![5, 6, 10]).unwrap();
+
+        cmd.execute(&mut grid);
+        cmd.undo(&mut grid);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_invalid_vertex() {
+        let cube = unit_cube();
+        let result = DissolveVertices::new(&cube, vec![9]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidVertexIndex(9));
+    }
+
+    #[test]
+    fn test_dissolve_vertices_name() {
+        let cube = unit_cube();
+        let cmd = DissolveVertices::new(&cube, vec![2, 2]).unwrap();
+        assert_eq!(cmd.name(), "Dissolve Vertices");
+        assert_eq!(cmd.vertices(), &[2]);
+    }
+}
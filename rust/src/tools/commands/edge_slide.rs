@@ -0,0 +1,339 @@
+// tools/commands/edge_slide.rs - EdgeSlide command implementation
+//
+// Slides the vertices of selected edge loops along their adjacent edges.
+// Each loop has two sides: walking the loop, every face next to it is
+// assigned the side of the face it shares a rail with, and in each face a
+// loop vertex's rail is its neighbour that is not on the loop. A positive
+// factor moves vertices toward their side A rail, a negative one toward
+// side B; a vertex without a rail on that side stays put.
+// Stores the original positions for exact undo. Validates at construction,
+// execute/undo are infallible.
+
+use std::collections::{HashMap, HashSet};
+
+use godot::prelude::Vector3;
+
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to slide edge loop vertices along their adjacent edges.
+#[derive(Debug, Clone)]
+pub struct EdgeSlide {
+    /// Vertices that slide (sorted)
+    vertices: Vec<usize>,
+    /// Positions before the slide (parallel to `vertices`), restored on undo
+    originals: Vec<Vector3>,
+    /// Rail end on side A and side B of each vertex (parallel to `vertices`)
+    rails: Vec<[Option<Vector3>; 2]>,
+    /// Slide amount in -1..1: positive toward side A, negative toward side B
+    factor: f32,
+}
+
+impl EdgeSlide {
+    /// Create a new EdgeSlide for the edge loops formed by `vertices`,
+    /// slid by `factor` (clamped to -1..1). Vertices not on a selected edge
+    /// are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::InvalidVertexIndex` if any vertex is out of bounds,
+    /// or `BlockotError::EmptySelection` if the vertices form no edge with
+    /// a face to slide across.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        vertices: &[usize],
+        factor: f32,
+    ) -> Result<Self, BlockotError> {
+        if let Some(&v) = vertices.iter().find(|&&v| v >= geo.vertices.len()) {
+            return Err(BlockotError::InvalidVertexIndex(v));
+        }
+        let selected: HashSet<usize> = vertices.iter().copied().collect();
+        let loop_edges: Vec<Edge> = topology
+            .edges()
+            .iter()
+            .copied()
+            .filter(|(a, b)| selected.contains(a) && selected.contains(b))
+            .collect();
+
+        // Side A/B face of each loop edge, spread along the loops from a seed
+        let mut sides: HashMap<Edge, [Option<usize>; 2]> = HashMap::new();
+        let mut rails: HashMap<usize, [Option<usize>; 2]> = HashMap::new();
+        for &seed in &loop_edges {
+            if sides.contains_key(&seed) {
+                continue;
+            }
+            let faces = topology.edge_faces(seed.0, seed.1);
+            let mut stack = vec![(seed, [faces.first().copied(), faces.get(1).copied()])];
+            while let Some((edge, edge_sides)) = stack.pop() {
+                if sides.contains_key(&edge) {
+                    continue;
+                }
+                sides.insert(edge, edge_sides);
+
+                for (side, face) in edge_sides.iter().enumerate() {
+                    let Some(face) = face.map(|f| &geo.faces[f]) else {
+                        continue;
+                    };
+                    for (v, other) in [(edge.0, edge.1), (edge.1, edge.0)] {
+                        let rail = off_loop_neighbour(face, v, other, &selected);
+                        let entry = rails.entry(v).or_default();
+                        if entry[side].is_none() {
+                            entry[side] = rail;
+                        }
+                    }
+                }
+
+                for v in [edge.0, edge.1] {
+                    let rail_sides = rails.get(&v).copied().unwrap_or_default();
+                    for next in topology.vertex_edges(v) {
+                        if next == edge || !loop_edges.contains(&next) || sides.contains_key(&next)
+                        {
+                            continue;
+                        }
+                        // The next edge's face on each side uses the same rail
+                        let next_faces = topology.edge_faces(next.0, next.1);
+                        let side_face = |rail: Option<usize>| {
+                            let rail = rail?;
+                            next_faces
+                                .iter()
+                                .copied()
+                                .find(|&f| has_edge(&geo.faces[f], v, rail))
+                        };
+                        let mut next_sides = [side_face(rail_sides[0]), side_face(rail_sides[1])];
+                        // Without a rail on one side, the other face fills it
+                        for side in 0..2 {
+                            if next_sides[side].is_none() {
+                                let taken = next_sides[1 - side];
+                                next_sides[side] =
+                                    next_faces.iter().copied().find(|&f| Some(f) != taken);
+                            }
+                        }
+                        stack.push((next, next_sides));
+                    }
+                }
+            }
+        }
+
+        if rails.values().all(|r| r.iter().all(Option::is_none)) {
+            return Err(BlockotError::EmptySelection);
+        }
+        let mut sliding: Vec<usize> = rails.keys().copied().collect();
+        sliding.sort_unstable();
+        let originals = sliding.iter().map(|&v| geo.vertices[v]).collect();
+        let rails = sliding
+            .iter()
+            .map(|v| rails[v].map(|rail| rail.map(|r| geo.vertices[r])))
+            .collect();
+
+        Ok(Self {
+            vertices: sliding,
+            originals,
+            rails,
+            factor: factor.clamp(-1.0, 1.0),
+        })
+    }
+
+    /// Returns the vertices that slide.
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    /// Returns the slide factor: positive toward side A, negative toward side B.
+    pub fn factor(&self) -> f32 {
+        self.factor
+    }
+
+    /// Change the slide factor (clamped to -1..1), e.g. while dragging.
+    pub fn set_factor(&mut self, factor: f32) {
+        self.factor = factor.clamp(-1.0, 1.0);
+    }
+
+    /// Returns a vertex with a side A rail as (position, rail end), which
+    /// tools use to turn mouse motion into a factor.
+    pub fn reference_rail(&self) -> Option<(Vector3, Vector3)> {
+        self.originals
+            .iter()
+            .zip(&self.rails)
+            .find_map(|(&origin, rail)| rail[0].map(|end| (origin, end)))
+    }
+
+    /// Returns each sliding vertex with its position at the current factor.
+    pub fn targets(&self) -> impl Iterator<Item = (usize, Vector3)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.originals.iter().zip(&self.rails))
+            .map(|(&v, (&origin, rail))| {
+                let (end, t) = if self.factor >= 0.0 {
+                    (rail[0], self.factor)
+                } else {
+                    (rail[1], -self.factor)
+                };
+                (v, end.map_or(origin, |end| origin.lerp(end, t)))
+            })
+    }
+}
+
+impl Command for EdgeSlide {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        for (v, target) in self.targets() {
+            if let Some(vertex) = geo.vertices.get_mut(v) {
+                *vertex = target;
+            }
+        }
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        for (&v, &original) in self.vertices.iter().zip(&self.originals) {
+            if let Some(vertex) = geo.vertices.get_mut(v) {
+                *vertex = original;
+            }
+        }
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Edge Slide"
+    }
+}
+
+/// The neighbour of `v` in the face other than `other`, unless it is on the loop.
+fn off_loop_neighbour(
+    face: &Face,
+    v: usize,
+    other: usize,
+    selected: &HashSet<usize>,
+) -> Option<usize> {
+    face.edges()
+        .filter_map(|(x, y)| match (x == v, y == v) {
+            (true, _) => Some(y),
+            (_, true) => Some(x),
+            _ => None,
+        })
+        .find(|&n| n != other && !selected.contains(&n))
+}
+
+/// Returns true if `a`-`b` is an edge of the face, in either direction.
+fn has_edge(face: &Face, a: usize, b: usize) -> bool {
+    face.edges()
+        .any(|(x, y)| (x, y) == (a, b) || (x, y) == (b, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    /// The middle column of a 2x2 grid: vertices 1, 4, 7 at x = 1.
+    fn middle_column() -> (BlockotGeometry, Vec<usize>) {
+        (quad_grid(2, 2), vec![1, 4, 7])
+    }
+
+    #[test]
+    fn test_slide_toward_side_a() {
+        let (mut grid, column) = middle_column();
+        let topology = MeshTopology::build(&grid);
+        let cmd = EdgeSlide::new(&grid, &topology, &column, 0.5).unwrap();
+
+        cmd.execute(&mut grid);
+
+        // All three vertices moved half way to the same side
+        let xs: Vec<f32> = column.iter().map(|&v| grid.vertices[v].x).collect();
+        assert!((xs[0] - 0.5).abs() < 1e-6 || (xs[0] - 1.5).abs() < 1e-6);
+        for x in &xs {
+            assert!((x - xs[0]).abs() < 1e-6);
+        }
+        for &v in &column {
+            assert_eq!(grid.vertices[v].y, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_negative_factor_uses_other_side() {
+        let (grid, column) = middle_column();
+        let topology = MeshTopology::build(&grid);
+        let mut cmd = EdgeSlide::new(&grid, &topology, &column, 1.0).unwrap();
+        let side_a: Vec<Vector3> = cmd.targets().map(|(_, p)| p).collect();
+
+        cmd.set_factor(-1.0);
+        let side_b: Vec<Vector3> = cmd.targets().map(|(_, p)| p).collect();
+
+        for (a, b) in side_a.iter().zip(&side_b) {
+            assert!(((a.x - b.x).abs() - 2.0).abs() < 1e-6);
+            assert!((a.z - b.z).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_reference_rail() {
+        let (grid, column) = middle_column();
+        let topology = MeshTopology::build(&grid);
+        let cmd = EdgeSlide::new(&grid, &topology, &column, 0.0).unwrap();
+
+        let (origin, end) = cmd.reference_rail().unwrap();
+        assert!((origin.x - 1.0).abs() < 1e-6);
+        assert!(((end - origin).length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_closed_loop_slides_consistently() {
+        let mut cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        // The top ring slides down the side faces
+        let top = [4, 5, 6, 7];
+        let cmd = EdgeSlide::new(&cube, &topology, &top, 0.25).unwrap();
+
+        cmd.execute(&mut cube);
+
+        let ys: Vec<f32> = top.iter().map(|&v| cube.vertices[v].y).collect();
+        for y in &ys {
+            assert!((y - ys[0]).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_factor_is_clamped() {
+        let (grid, column) = middle_column();
+        let topology = MeshTopology::build(&grid);
+        let cmd = EdgeSlide::new(&grid, &topology, &column, 3.0).unwrap();
+        assert_eq!(cmd.factor(), 1.0);
+    }
+
+    #[test]
+    fn test_edge_slide_undo_is_exact() {
+        let (original, column) = middle_column();
+        let mut grid = original.clone();
+        let topology = MeshTopology::build(&grid);
+        let cmd = EdgeSlide::new(&grid, &topology, &column, -0.3).unwrap();
+
+        cmd.execute(&mut grid);
+        cmd.undo(&mut grid);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_lone_vertex_has_nothing_to_slide() {
+        let grid = quad_grid(2, 2);
+        let topology = MeshTopology::build(&grid);
+        let result = EdgeSlide::new(&grid, &topology, &[4], 0.5);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_invalid_vertex() {
+        let grid = quad_grid(1, 1);
+        let topology = MeshTopology::build(&grid);
+        let result = EdgeSlide::new(&grid, &topology, &[0, 9], 0.5);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidVertexIndex(9));
+    }
+
+    #[test]
+    fn test_edge_slide_name() {
+        let (grid, column) = middle_column();
+        let topology = MeshTopology::build(&grid);
+        let cmd = EdgeSlide::new(&grid, &topology, &column, 0.0).unwrap();
+        assert_eq!(cmd.name(), "Edge Slide");
+    }
+}
@@ -0,0 +1,282 @@
+// tools/commands/extrude_edges.rs - ExtrudeEdges command implementation
+//
+// Extrudes open boundary edges into new quads: each endpoint is duplicated
+// once (edges sharing a vertex share its copy) and a quad joins every edge
+// to its copy, wound opposite to the face the edge borders so the new strip
+// continues that face's orientation. Only boundary edges qualify - an edge
+// between two faces has no free side to grow from. Vertices alone cannot be
+// extruded yet since the geometry has no loose edges.
+// New vertices and faces are appended; undo truncates them, so the arrays
+// come back exactly. Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+use godot::prelude::Vector3;
+
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to extrude boundary edges into a strip of quads.
+#[derive(Debug, Clone)]
+pub struct ExtrudeEdges {
+    /// Extruded edges (normalized, sorted, unique)
+    edges: Vec<Edge>,
+    /// Edge endpoints; the copy of `duplicated[k]` is vertex `vertex_count + k`
+    duplicated: Vec<usize>,
+    /// New quads, appended after the existing faces
+    new_faces: Vec<Face>,
+    /// Offset applied to the copies
+    offset: Vector3,
+    /// Vertex count before the extrude
+    vertex_count: usize,
+    /// Face count before the extrude
+    face_count: usize,
+}
+
+impl ExtrudeEdges {
+    /// Create a new ExtrudeEdges command placing the copied edges at `offset`
+    /// from the originals. Duplicate edges (in either direction) are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if edges is empty,
+    /// `BlockotError::InvalidEdge` if an edge is not in the mesh, or
+    /// `BlockotError::NotBoundaryEdge` if an edge borders more than one face.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        edges: Vec<Edge>,
+        offset: Vector3,
+    ) -> Result<Self, BlockotError> {
+        if edges.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let edges: Vec<Edge> = edges
+            .into_iter()
+            .map(|(a, b)| edge_key(a, b))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut directed = Vec::with_capacity(edges.len());
+        for &(a, b) in &edges {
+            let face = match topology.edge_faces(a, b) {
+                [] => return Err(BlockotError::InvalidEdge(a, b)),
+                [face] => geo
+                    .faces
+                    .get(*face)
+                    .ok_or(BlockotError::InvalidFaceIndex(*face))?,
+                _ => return Err(BlockotError::NotBoundaryEdge(a, b)),
+            };
+            if a >= geo.vertices.len() || b >= geo.vertices.len() {
+                return Err(BlockotError::InvalidVertexIndex(a.max(b)));
+            }
+            directed.push(if runs_forward(face, a, b) {
+                (a, b)
+            } else {
+                (b, a)
+            });
+        }
+
+        let duplicated: Vec<usize> = edges
+            .iter()
+            .flat_map(|&(a, b)| [a, b])
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let copy_of: HashMap<usize, usize> = duplicated
+            .iter()
+            .enumerate()
+            .map(|(k, &v)| (v, geo.vertices.len() + k))
+            .collect();
+        // The face walks a->b, so the new quad walks b->a to match its winding
+        let new_faces = directed
+            .iter()
+            .map(|&(a, b)| Face::quad(b, a, copy_of[&a], copy_of[&b]))
+            .collect();
+
+        Ok(Self {
+            edges,
+            duplicated,
+            new_faces,
+            offset,
+            vertex_count: geo.vertices.len(),
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the extruded edges (normalized, sorted).
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the offset of the copied edges from the originals.
+    pub fn offset(&self) -> Vector3 {
+        self.offset
+    }
+
+    /// Returns the indices of the copied vertices after execute.
+    pub fn new_vertices(&self) -> Range<usize> {
+        self.vertex_count..self.vertex_count + self.duplicated.len()
+    }
+
+    /// Returns the indices of the new quads after execute.
+    pub fn new_face_indices(&self) -> Range<usize> {
+        self.face_count..self.face_count + self.new_faces.len()
+    }
+}
+
+impl Command for ExtrudeEdges {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        for &v in &self.duplicated {
+            let position = geo.vertices.get(v).copied().unwrap_or(Vector3::ZERO);
+            geo.vertices.push(position + self.offset);
+        }
+        geo.faces.extend(self.new_faces.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        geo.vertices.truncate(self.vertex_count);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Extrude Edges"
+    }
+}
+
+/// Returns true if the face walks from `a` straight to `b`.
+fn runs_forward(face: &Face, a: usize, b: usize) -> bool {
+    let n = face.vertex_indices.len();
+    (0..n).any(|i| face.vertex_indices[i] == a && face.vertex_indices[(i + 1) % n] == b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, single_quad, unit_cube, vectors_approx_equal};
+
+    #[test]
+    fn test_extrude_single_edge() {
+        let mut quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let offset = Vector3::new(0.0, -1.0, 0.0);
+        let cmd = ExtrudeEdges::new(&quad, &topology, vec![(0, 1)], offset).unwrap();
+
+        cmd.execute(&mut quad);
+
+        assert_eq!(quad.vertices.len(), 6);
+        assert_eq!(quad.faces.len(), 2);
+        assert_eq!(cmd.new_vertices(), 4..6);
+        assert!(vectors_approx_equal(
+            quad.vertices[4],
+            Vector3::new(0.0, -1.0, 0.0),
+            1e-6
+        ));
+        assert!(vectors_approx_equal(
+            quad.vertices[5],
+            Vector3::new(1.0, -1.0, 0.0),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_new_face_matches_winding() {
+        let mut quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let cmd = ExtrudeEdges::new(&quad, &topology, vec![(1, 0)], Vector3::new(0.0, -1.0, 0.0))
+            .unwrap();
+
+        cmd.execute(&mut quad);
+
+        // The strip continues the plane, so both faces point the same way
+        let original = quad.faces[0].normal(&quad.vertices);
+        let extruded = quad.faces[1].normal(&quad.vertices);
+        assert!(original.dot(extruded) > 0.99);
+        // The shared edge is walked in opposite directions
+        assert_eq!(quad.faces[1].vertex_indices[..2], [1, 0]);
+    }
+
+    #[test]
+    fn test_connected_edges_share_copies() {
+        let mut grid = quad_grid(2, 1);
+        let topology = MeshTopology::build(&grid);
+        let cmd = ExtrudeEdges::new(
+            &grid,
+            &topology,
+            vec![(0, 1), (1, 2), (2, 1)],
+            Vector3::new(0.0, 0.0, -1.0),
+        )
+        .unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.edges(), &[(0, 1), (1, 2)]);
+        // Three endpoints, one copy each
+        assert_eq!(grid.vertices.len(), 9);
+        assert_eq!(grid.faces.len(), 4);
+        assert_eq!(cmd.new_face_indices(), 2..4);
+    }
+
+    #[test]
+    fn test_extrude_edges_undo_is_exact() {
+        let original = quad_grid(2, 2);
+        let mut grid = original.clone();
+        let topology = MeshTopology::build(&grid);
+        let cmd = ExtrudeEdges::new(
+            &grid,
+            &topology,
+            vec![(0, 1), (2, 5)],
+            Vector3::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+
+        cmd.execute(&mut grid);
+        cmd.undo(&mut grid);
+
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    fn test_interior_edge_rejected() {
+        let grid = quad_grid(2, 1);
+        let topology = MeshTopology::build(&grid);
+        let result = ExtrudeEdges::new(&grid, &topology, vec![(1, 4)], Vector3::ZERO);
+        assert_eq!(result.unwrap_err(), BlockotError::NotBoundaryEdge(1, 4));
+    }
+
+    #[test]
+    fn test_closed_mesh_has_no_boundary() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = ExtrudeEdges::new(&cube, &topology, vec![(0, 1)], Vector3::ZERO);
+        assert_eq!(result.unwrap_err(), BlockotError::NotBoundaryEdge(0, 1));
+    }
+
+    #[test]
+    fn test_missing_edge_rejected() {
+        let quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let result = ExtrudeEdges::new(&quad, &topology, vec![(0, 2)], Vector3::ZERO);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidEdge(0, 2));
+    }
+
+    #[test]
+    fn test_extrude_edges_empty_selection() {
+        let quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let result = ExtrudeEdges::new(&quad, &topology, vec![], Vector3::ZERO);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_extrude_edges_name() {
+        let quad = single_quad();
+        let topology = MeshTopology::build(&quad);
+        let cmd = ExtrudeEdges::new(&quad, &topology, vec![(0, 1)], Vector3::ZERO).unwrap();
+        assert_eq!(cmd.name(), "Extrude Edges");
+    }
+}
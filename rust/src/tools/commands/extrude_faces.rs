@@ -0,0 +1,367 @@
+// tools/commands/extrude_faces.rs - ExtrudeFaces command implementation
+//
+// Extrudes a region of faces: vertices on the region's boundary are
+// duplicated, the region faces are rewired onto the duplicates (the cap),
+// and a quad side wall joins each boundary edge to its copy. Vertices inside
+// the region are only used by the cap, so they move with it in place.
+// New vertices and faces are appended; undo truncates them and restores the
+// rewired faces, so the arrays come back exactly, ordering included.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashMap};
+
+use godot::prelude::Vector3;
+
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face};
+use crate::tools::Command;
+
+/// Command to extrude a region of faces along its averaged normal.
+#[derive(Debug, Clone)]
+pub struct ExtrudeFaces {
+    /// Extruded face indices (sorted, unique)
+    faces: Vec<usize>,
+    /// Region faces before the extrude (parallel to `faces`), restored on undo
+    original_faces: Vec<Face>,
+    /// Region faces rewired onto the cap vertices (parallel to `faces`)
+    cap_faces: Vec<Face>,
+    /// Side wall quads, appended after the existing faces
+    side_faces: Vec<Face>,
+    /// Boundary vertices; the copy of `duplicated[k]` is vertex `vertex_count + k`
+    duplicated: Vec<usize>,
+    /// Interior region vertices, moved in place with the cap
+    interior: Vec<usize>,
+    /// Positions of `interior` before the extrude, restored on undo
+    interior_positions: Vec<Vector3>,
+    /// Averaged unit normal of the region (zero if the normals cancel out)
+    normal: Vector3,
+    /// Distance the cap moves along `normal`
+    distance: f32,
+    /// Vertex count before the extrude
+    vertex_count: usize,
+    /// Face count before the extrude
+    face_count: usize,
+}
+
+impl ExtrudeFaces {
+    /// Create a new ExtrudeFaces command moving the cap `distance` along the
+    /// averaged normal of the faces. Duplicate face indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if faces is empty,
+    /// `BlockotError::InvalidFaceIndex` if any face index is out of bounds, or
+    /// `BlockotError::InvalidVertexIndex` if a face references a missing vertex.
+    pub fn new(
+        geo: &BlockotGeometry,
+        faces: Vec<usize>,
+        distance: f32,
+    ) -> Result<Self, BlockotError> {
+        if faces.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let faces: Vec<usize> = faces
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        for &f in &faces {
+            let face = geo.faces.get(f).ok_or(BlockotError::InvalidFaceIndex(f))?;
+            if let Some(&v) = face
+                .vertex_indices
+                .iter()
+                .find(|&&v| v >= geo.vertices.len())
+            {
+                return Err(BlockotError::InvalidVertexIndex(v));
+            }
+        }
+
+        let original_faces: Vec<Face> = faces.iter().map(|&f| geo.faces[f].clone()).collect();
+        let boundary = boundary_edges(&original_faces);
+
+        let duplicated: Vec<usize> = boundary
+            .iter()
+            .flat_map(|&(a, b)| [a, b])
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let copy_of: HashMap<usize, usize> = duplicated
+            .iter()
+            .enumerate()
+            .map(|(k, &v)| (v, geo.vertices.len() + k))
+            .collect();
+        let interior: Vec<usize> = original_faces
+            .iter()
+            .flat_map(|face| face.vertex_indices.iter().copied())
+            .filter(|v| !copy_of.contains_key(v))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let interior_positions = interior.iter().map(|&v| geo.vertices[v]).collect();
+
+        let cap_faces = original_faces
+            .iter()
+            .map(|face| {
+                Face::new(
+                    face.vertex_indices
+                        .iter()
+                        .map(|v| copy_of.get(v).copied().unwrap_or(*v))
+                        .collect(),
+                )
+            })
+            .collect();
+        // Walls keep the boundary edge's direction so they face outward
+        let side_faces = boundary
+            .iter()
+            .map(|&(a, b)| Face::quad(a, b, copy_of[&b], copy_of[&a]))
+            .collect();
+
+        let sum = original_faces
+            .iter()
+            .filter_map(|face| face.try_normal(&geo.vertices))
+            .fold(Vector3::ZERO, |acc, n| acc + n);
+        let normal = if sum.length() > 1e-5 {
+            sum.normalized()
+        } else {
+            Vector3::ZERO
+        };
+
+        Ok(Self {
+            faces,
+            original_faces,
+            cap_faces,
+            side_faces,
+            duplicated,
+            interior,
+            interior_positions,
+            normal,
+            distance,
+            vertex_count: geo.vertices.len(),
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the extruded face indices (they become the cap).
+    pub fn faces(&self) -> &[usize] {
+        &self.faces
+    }
+
+    /// Returns the averaged unit normal the cap moves along (zero if undefined).
+    pub fn normal(&self) -> Vector3 {
+        self.normal
+    }
+
+    /// Returns the distance the cap moves along the normal.
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Returns the vertices of the cap after execute: the boundary copies
+    /// followed by the interior vertices.
+    pub fn cap_vertices(&self) -> Vec<usize> {
+        (self.vertex_count..self.vertex_count + self.duplicated.len())
+            .chain(self.interior.iter().copied())
+            .collect()
+    }
+
+    /// Returns the indices of the side wall faces after execute.
+    pub fn side_face_indices(&self) -> std::ops::Range<usize> {
+        self.face_count..self.face_count + self.side_faces.len()
+    }
+}
+
+impl Command for ExtrudeFaces {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        let offset = self.normal * self.distance;
+
+        for &v in &self.duplicated {
+            let position = geo.vertices.get(v).copied().unwrap_or(Vector3::ZERO);
+            geo.vertices.push(position + offset);
+        }
+        for (&v, &original) in self.interior.iter().zip(&self.interior_positions) {
+            if let Some(vertex) = geo.vertices.get_mut(v) {
+                *vertex = original + offset;
+            }
+        }
+        for (&f, cap) in self.faces.iter().zip(&self.cap_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = cap.clone();
+            }
+        }
+        geo.faces.extend(self.side_faces.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        for (&f, original) in self.faces.iter().zip(&self.original_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = original.clone();
+            }
+        }
+        geo.vertices.truncate(self.vertex_count);
+        for (&v, &original) in self.interior.iter().zip(&self.interior_positions) {
+            if let Some(vertex) = geo.vertices.get_mut(v) {
+                *vertex = original;
+            }
+        }
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Extrude Faces"
+    }
+}
+
+/// Edges used by exactly one of the faces, directed as in that face,
+/// in face order.
+pub(super) fn boundary_edges(faces: &[Face]) -> Vec<Edge> {
+    let directed: Vec<Edge> = faces
+        .iter()
+        .flat_map(|face| {
+            let n = face.vertex_indices.len();
+            (0..n).map(move |i| (face.vertex_indices[i], face.vertex_indices[(i + 1) % n]))
+        })
+        .collect();
+
+    let mut uses: HashMap<Edge, usize> = HashMap::new();
+    for &(a, b) in &directed {
+        *uses.entry(edge_key(a, b)).or_default() += 1;
+    }
+    directed
+        .into_iter()
+        .filter(|&(a, b)| uses[&edge_key(a, b)] == 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube, vectors_approx_equal};
+
+    const TOP: usize = 2;
+
+    fn centroid(geo: &BlockotGeometry, face: &Face) -> Vector3 {
+        let sum = face
+            .vertex_indices
+            .iter()
+            .fold(Vector3::ZERO, |acc, &v| acc + geo.vertices[v]);
+        sum / face.vertex_indices.len() as f32
+    }
+
+    #[test]
+    fn test_extrude_cube_top() {
+        let mut cube = unit_cube();
+        let cmd = ExtrudeFaces::new(&cube, vec![TOP], 1.0).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.vertices.len(), 12);
+        assert_eq!(cube.faces.len(), 10);
+        assert!(vectors_approx_equal(cmd.normal(), Vector3::UP, 1e-6));
+        // The cap is the old top face on the new vertices, one unit higher
+        assert_eq!(cube.faces[TOP].vertex_indices, vec![8, 9, 10, 11]);
+        for v in cmd.cap_vertices() {
+            assert!((cube.vertices[v].y - 1.5).abs() < 1e-6);
+        }
+        // The old top ring stays put
+        for v in [4, 5, 6, 7] {
+            assert!((cube.vertices[v].y - 0.5).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_side_walls_face_outward() {
+        let mut cube = unit_cube();
+        let cmd = ExtrudeFaces::new(&cube, vec![TOP], 1.0).unwrap();
+        cmd.execute(&mut cube);
+
+        let axis_center = Vector3::new(0.0, 1.0, 0.0);
+        for f in cmd.side_face_indices() {
+            let face = &cube.faces[f];
+            let outward = centroid(&cube, face) - axis_center;
+            assert!(face.normal(&cube.vertices).dot(outward) > 0.0);
+        }
+        assert!(cube.faces[TOP].normal(&cube.vertices).dot(Vector3::UP) > 0.99);
+    }
+
+    #[test]
+    fn test_extrude_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = ExtrudeFaces::new(&cube, vec![TOP, 4], 0.5).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube.vertices, original.vertices);
+        assert_eq!(cube.faces, original.faces);
+    }
+
+    #[test]
+    fn test_extrude_redo_matches_execute() {
+        let mut cube = unit_cube();
+        let cmd = ExtrudeFaces::new(&cube, vec![TOP], 0.5).unwrap();
+
+        cmd.execute(&mut cube);
+        let extruded = cube.clone();
+        cmd.undo(&mut cube);
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.vertices, extruded.vertices);
+        assert_eq!(cube.faces, extruded.faces);
+    }
+
+    #[test]
+    fn test_region_shares_inner_edges() {
+        let mut grid = quad_grid(2, 1);
+        let cmd = ExtrudeFaces::new(&grid, vec![1, 0, 1], 1.0).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.faces(), &[0, 1]);
+        // Six boundary edges get walls; the shared middle edge does not
+        assert_eq!(grid.vertices.len(), 12);
+        assert_eq!(grid.faces.len(), 8);
+    }
+
+    #[test]
+    fn test_interior_vertices_move_in_place() {
+        let mut grid = quad_grid(2, 2);
+        let cmd = ExtrudeFaces::new(&grid, vec![0, 1, 2, 3], 2.0).unwrap();
+
+        cmd.execute(&mut grid);
+
+        // The centre vertex (4) is only used by the region, so it is not copied
+        assert_eq!(grid.vertices.len(), 9 + 8);
+        assert!(cmd.cap_vertices().contains(&4));
+        assert!(vectors_approx_equal(
+            grid.vertices[4],
+            Vector3::new(1.0, 2.0, 1.0),
+            1e-6
+        ));
+
+        cmd.undo(&mut grid);
+        assert_eq!(grid, quad_grid(2, 2));
+    }
+
+    #[test]
+    fn test_extrude_empty_selection() {
+        let cube = unit_cube();
+        let result = ExtrudeFaces::new(&cube, vec![], 1.0);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_extrude_invalid_face() {
+        let cube = unit_cube();
+        let result = ExtrudeFaces::new(&cube, vec![6], 1.0);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidFaceIndex(6));
+    }
+
+    #[test]
+    fn test_extrude_name() {
+        let cube = unit_cube();
+        let cmd = ExtrudeFaces::new(&cube, vec![TOP], 1.0).unwrap();
+        assert_eq!(cmd.name(), "Extrude Faces");
+    }
+}
@@ -0,0 +1,169 @@
+// tools/commands/fill_hole.rs - FillHole command implementation
+//
+// Closes openings: every closed loop of selected boundary edges gets one
+// new n-gon spanning it. The n-gon walks each edge opposite to the face
+// already using it (see boundary_chains.rs), so its normal is consistent
+// with its neighbours, e.g. filling the hole left by a deleted cube face
+// restores an outward-facing face.
+// New faces are appended; undo truncates them, so the arrays come back
+// exactly. Validates at construction, execute/undo are infallible.
+
+use std::ops::Range;
+
+use super::boundary_chains::boundary_chains;
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to fill closed boundary loops with n-gons.
+#[derive(Debug, Clone)]
+pub struct FillHole {
+    /// New n-gons, one per loop, appended after the existing faces
+    new_faces: Vec<Face>,
+    /// Face count before the fill
+    face_count: usize,
+}
+
+impl FillHole {
+    /// Create a new FillHole command for the loops formed by `edges`.
+    /// Duplicate edges (in either direction) are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if edges is empty,
+    /// `BlockotError::InvalidEdge` if an edge is not in the mesh,
+    /// `BlockotError::NotBoundaryEdge` if an edge borders more than one face,
+    /// `BlockotError::EdgeLoopBranches` if more than two edges meet at a
+    /// vertex, or `BlockotError::OpenEdgeLoop` if the edges do not close up.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        edges: Vec<Edge>,
+    ) -> Result<Self, BlockotError> {
+        let chains = boundary_chains(geo, topology, edges)?;
+        let mut new_faces = Vec::with_capacity(chains.len());
+        for chain in chains {
+            if !chain.closed {
+                return Err(BlockotError::OpenEdgeLoop(chain.vertices[0]));
+            }
+            new_faces.push(Face::new(chain.vertices));
+        }
+
+        Ok(Self {
+            new_faces,
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the vertex loops of the new faces.
+    pub fn loops(&self) -> impl Iterator<Item = &[usize]> + '_ {
+        self.new_faces
+            .iter()
+            .map(|face| face.vertex_indices.as_slice())
+    }
+
+    /// Returns the indices of the new faces after execute.
+    pub fn new_face_indices(&self) -> Range<usize> {
+        self.face_count..self.face_count + self.new_faces.len()
+    }
+}
+
+impl Command for FillHole {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        geo.faces.extend(self.new_faces.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Fill Hole"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    #[test]
+    fn test_fill_deleted_cube_face() {
+        let mut cube = unit_cube();
+        let removed = cube.faces.remove(2);
+        let original_normal = removed.normal(&cube.vertices);
+        let topology = MeshTopology::build(&cube);
+        let cmd = FillHole::new(&cube, &topology, topology.boundary_edges()).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.faces.len(), 6);
+        assert_eq!(cmd.new_face_indices(), 5..6);
+        assert_eq!(cube.faces[5].vertex_count(), 4);
+        assert!(cube.faces[5].normal(&cube.vertices).dot(original_normal) > 0.99);
+        assert!(MeshTopology::build(&cube).boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_fill_grid_border_faces_away() {
+        let mut grid = quad_grid(2, 2);
+        let topology = MeshTopology::build(&grid);
+        let cmd = FillHole::new(&grid, &topology, topology.boundary_edges()).unwrap();
+
+        cmd.execute(&mut grid);
+
+        // The grid faces +Y, so its cap faces -Y like the bottom of a box
+        let cap = &grid.faces[4];
+        assert_eq!(cap.vertex_count(), 8);
+        assert!(cap.normal(&grid.vertices).y < -0.99);
+        assert_eq!(cmd.loops().count(), 1);
+    }
+
+    #[test]
+    fn test_fill_two_holes() {
+        let mut cube = unit_cube();
+        // Drop the bottom and top faces, leaving a tube with two openings
+        cube.faces.remove(3);
+        cube.faces.remove(2);
+        let topology = MeshTopology::build(&cube);
+        let cmd = FillHole::new(&cube, &topology, topology.boundary_edges()).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cmd.loops().count(), 2);
+        assert_eq!(cube.faces.len(), 6);
+        assert!(MeshTopology::build(&cube).boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_open_chain_is_rejected() {
+        let grid = quad_grid(1, 1);
+        let topology = MeshTopology::build(&grid);
+        let result = FillHole::new(&grid, &topology, vec![(0, 1), (1, 3)]);
+        assert!(matches!(result, Err(BlockotError::OpenEdgeLoop(_))));
+    }
+
+    #[test]
+    fn test_fill_hole_undo_is_exact() {
+        let mut cube = unit_cube();
+        cube.faces.remove(0);
+        let original = cube.clone();
+        let topology = MeshTopology::build(&cube);
+        let cmd = FillHole::new(&cube, &topology, topology.boundary_edges()).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_fill_hole_name() {
+        let mut cube = unit_cube();
+        cube.faces.remove(0);
+        let topology = MeshTopology::build(&cube);
+        let cmd = FillHole::new(&cube, &topology, topology.boundary_edges()).unwrap();
+        assert_eq!(cmd.name(), "Fill Hole");
+    }
+}
@@ -0,0 +1,130 @@
+// tools/commands/flip_faces.rs - FlipFaces command implementation
+//
+// Reverses the winding of faces, turning their normals around (see
+// Face::flip). Flipping is its own inverse, so undo flips the same faces
+// again and the arrays come back exactly.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::BTreeSet;
+
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command to reverse the winding of faces.
+#[derive(Debug, Clone)]
+pub struct FlipFaces {
+    /// Flipped face indices (sorted, unique)
+    faces: Vec<usize>,
+}
+
+impl FlipFaces {
+    /// Create a new FlipFaces command. Duplicate indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if faces is empty, or
+    /// `BlockotError::InvalidFaceIndex` if any index is out of bounds.
+    pub fn new(geo: &BlockotGeometry, faces: Vec<usize>) -> Result<Self, BlockotError> {
+        if faces.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let faces: Vec<usize> = faces
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&f) = faces.iter().find(|&&f| f >= geo.faces.len()) {
+            return Err(BlockotError::InvalidFaceIndex(f));
+        }
+        Ok(Self { faces })
+    }
+
+    /// Returns the flipped face indices.
+    pub fn faces(&self) -> &[usize] {
+        &self.faces
+    }
+}
+
+impl Command for FlipFaces {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        flip_all(geo, &self.faces);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        flip_all(geo, &self.faces);
+    }
+
+    fn name(&self) -> &'static str {
+        "Flip Faces"
+    }
+}
+
+/// Flip the given faces, skipping out-of-range indices.
+pub(super) fn flip_all(geo: &mut BlockotGeometry, faces: &[usize]) {
+    for &f in faces {
+        if let Some(face) = geo.faces.get_mut(f) {
+            face.flip();
+        }
+    }
+    geo.dirty = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::unit_cube;
+
+    #[test]
+    fn test_flip_turns_normals_around() {
+        let mut cube = unit_cube();
+        let before: Vec<_> = cube
+            .faces
+            .iter()
+            .map(|f| f.normal(&cube.vertices))
+            .collect();
+        let cmd = FlipFaces::new(&cube, vec![0, 2]).unwrap();
+
+        cmd.execute(&mut cube);
+
+        for (f, normal) in before.iter().enumerate() {
+            let after = cube.faces[f].normal(&cube.vertices);
+            let expected = if f == 0 || f == 2 { -*normal } else { *normal };
+            assert!(after.dot(expected) > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_flip_faces_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = FlipFaces::new(&cube, vec![1, 3, 3]).unwrap();
+
+        cmd.execute(&mut cube);
+        assert_ne!(cube, original);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+        assert_eq!(cmd.faces(), &[1, 3]);
+    }
+
+    #[test]
+    fn test_invalid_face() {
+        let cube = unit_cube();
+        let result = FlipFaces::new(&cube, vec![6]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidFaceIndex(6));
+    }
+
+    #[test]
+    fn test_empty_selection() {
+        let cube = unit_cube();
+        let result = FlipFaces::new(&cube, vec![]);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_flip_faces_name() {
+        let cube = unit_cube();
+        let cmd = FlipFaces::new(&cube, vec![0]).unwrap();
+        assert_eq!(cmd.name(), "Flip Faces");
+    }
+}
@@ -0,0 +1,405 @@
+// tools/commands/inset_faces.rs - InsetFaces command implementation
+//
+// Insets faces: vertices on the boundary of each inset group are duplicated
+// and moved inward in the plane of their faces, the faces are rewired onto
+// the copies, and a quad joins each boundary edge to its inner copy. In
+// region mode the selection is one group, so edges between selected faces
+// are kept and only the region's outline is inset; in individual mode every
+// face is its own group. Corners are mitred so the ring has the same
+// thickness along both edges.
+// New vertices and faces are appended; undo truncates them and restores the
+// rewired faces, so the arrays come back exactly, ordering included.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+use godot::prelude::Vector3;
+
+use super::extrude_faces::boundary_edges;
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, Face};
+use crate::tools::Command;
+
+/// Smallest cosine between a corner's miter and its edge normals; keeps
+/// very sharp corners from shooting off.
+const MIN_MITER_COS: f32 = 0.2;
+
+/// Whether selected faces are inset together or one by one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsetMode {
+    /// Inset the outline of the selected region, keeping inner edges
+    Region,
+    /// Inset every selected face on its own
+    Individual,
+}
+
+/// Command to inset faces, joining each to its outer ring with quads.
+#[derive(Debug, Clone)]
+pub struct InsetFaces {
+    /// Inset face indices (sorted, unique)
+    faces: Vec<usize>,
+    /// Faces before the inset (parallel to `faces`), restored on undo
+    original_faces: Vec<Face>,
+    /// Faces rewired onto the inner vertices (parallel to `faces`)
+    inner_faces: Vec<Face>,
+    /// Ring quads, appended after the existing faces
+    ring_faces: Vec<Face>,
+    /// Positions of the inner vertices, appended after the existing vertices
+    inner_positions: Vec<Vector3>,
+    /// Ring thickness
+    thickness: f32,
+    /// Region or individual inset
+    mode: InsetMode,
+    /// Vertex count before the inset
+    vertex_count: usize,
+    /// Face count before the inset
+    face_count: usize,
+}
+
+impl InsetFaces {
+    /// Create a new InsetFaces command with a ring `thickness` wide.
+    /// Duplicate face indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if faces is empty,
+    /// `BlockotError::NonPositiveAmount` if thickness is not above zero,
+    /// `BlockotError::InvalidFaceIndex` if any face index is out of bounds, or
+    /// `BlockotError::InvalidVertexIndex` if a face references a missing vertex.
+    pub fn new(
+        geo: &BlockotGeometry,
+        faces: Vec<usize>,
+        thickness: f32,
+        mode: InsetMode,
+    ) -> Result<Self, BlockotError> {
+        if faces.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        if thickness.is_nan() || thickness <= 0.0 {
+            return Err(BlockotError::NonPositiveAmount);
+        }
+        let faces: Vec<usize> = faces
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        for &f in &faces {
+            let face = geo.faces.get(f).ok_or(BlockotError::InvalidFaceIndex(f))?;
+            if let Some(&v) = face
+                .vertex_indices
+                .iter()
+                .find(|&&v| v >= geo.vertices.len())
+            {
+                return Err(BlockotError::InvalidVertexIndex(v));
+            }
+        }
+
+        let original_faces: Vec<Face> = faces.iter().map(|&f| geo.faces[f].clone()).collect();
+        let groups: Vec<Vec<usize>> = match mode {
+            InsetMode::Region => vec![(0..faces.len()).collect()],
+            InsetMode::Individual => (0..faces.len()).map(|k| vec![k]).collect(),
+        };
+
+        let mut inner_faces = original_faces.clone();
+        let mut ring_faces = Vec::new();
+        let mut inner_positions = Vec::new();
+        for group in groups {
+            let group_faces: Vec<Face> = group.iter().map(|&k| original_faces[k].clone()).collect();
+            let boundary = boundary_edges(&group_faces);
+            let outline: BTreeSet<usize> = boundary.iter().flat_map(|&(a, b)| [a, b]).collect();
+
+            let mut copy_of = HashMap::new();
+            for v in outline {
+                let incoming = boundary.iter().find(|&&(_, b)| b == v).map(|&(a, _)| a);
+                let outgoing = boundary.iter().find(|&&(a, _)| a == v).map(|&(_, b)| b);
+                let normal = group_faces
+                    .iter()
+                    .filter(|face| face.vertex_indices.contains(&v))
+                    .filter_map(|face| face.try_normal(&geo.vertices))
+                    .fold(Vector3::ZERO, |acc, n| acc + n);
+                let offset = match (incoming, outgoing) {
+                    (Some(prev), Some(next)) => {
+                        inset_offset(geo, prev, v, next, normal) * thickness
+                    }
+                    _ => Vector3::ZERO,
+                };
+                copy_of.insert(v, geo.vertices.len() + inner_positions.len());
+                inner_positions.push(geo.vertices[v] + offset);
+            }
+
+            for &k in &group {
+                for v in &mut inner_faces[k].vertex_indices {
+                    if let Some(&copy) = copy_of.get(v) {
+                        *v = copy;
+                    }
+                }
+            }
+            // Ring quads keep the boundary edge's direction, matching the face
+            ring_faces.extend(
+                boundary
+                    .iter()
+                    .map(|&(a, b)| Face::quad(a, b, copy_of[&b], copy_of[&a])),
+            );
+        }
+
+        Ok(Self {
+            faces,
+            original_faces,
+            inner_faces,
+            ring_faces,
+            inner_positions,
+            thickness,
+            mode,
+            vertex_count: geo.vertices.len(),
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the inset face indices (they become the inner faces).
+    pub fn faces(&self) -> &[usize] {
+        &self.faces
+    }
+
+    /// Returns the ring thickness.
+    pub fn thickness(&self) -> f32 {
+        self.thickness
+    }
+
+    /// Returns whether the faces are inset as a region or individually.
+    pub fn mode(&self) -> InsetMode {
+        self.mode
+    }
+
+    /// Returns the indices of the inner vertices after execute.
+    pub fn inner_vertices(&self) -> Range<usize> {
+        self.vertex_count..self.vertex_count + self.inner_positions.len()
+    }
+
+    /// Returns the indices of the ring faces after execute.
+    pub fn ring_face_indices(&self) -> Range<usize> {
+        self.face_count..self.face_count + self.ring_faces.len()
+    }
+}
+
+impl Command for InsetFaces {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        geo.vertices.extend(self.inner_positions.iter().copied());
+        for (&f, inner) in self.faces.iter().zip(&self.inner_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = inner.clone();
+            }
+        }
+        geo.faces.extend(self.ring_faces.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        for (&f, original) in self.faces.iter().zip(&self.original_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = original.clone();
+            }
+        }
+        geo.vertices.truncate(self.vertex_count);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Inset Faces"
+    }
+}
+
+/// Inward offset of boundary vertex `v` for a unit thickness, given its
+/// neighbours along the outline and the summed normal of its faces.
+/// Zero when the normal or an edge is degenerate.
+fn inset_offset(
+    geo: &BlockotGeometry,
+    prev: usize,
+    v: usize,
+    next: usize,
+    normal: Vector3,
+) -> Vector3 {
+    let position = geo.vertices[v];
+    let (incoming, outgoing) = (position - geo.vertices[prev], geo.vertices[next] - position);
+    if normal.length() < 1e-5 || incoming.length() < 1e-5 || outgoing.length() < 1e-5 {
+        return Vector3::ZERO;
+    }
+    let normal = normal.normalized();
+    // Edges run so that their direction crossed with the normal points inside
+    let inward_in = incoming.normalized().cross(normal);
+    let inward_out = outgoing.normalized().cross(normal);
+    let miter = inward_in + inward_out;
+    if miter.length() < 1e-5 {
+        return inward_in;
+    }
+    let miter = miter.normalized();
+    miter / miter.dot(inward_in).max(MIN_MITER_COS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::MeshTopology;
+    use crate::test_utils::{quad_grid, single_quad, unit_cube, vectors_approx_equal};
+
+    #[test]
+    fn test_inset_single_quad() {
+        let mut geo = quad_grid(1, 1);
+        let cmd = InsetFaces::new(&geo, vec![0], 0.25, InsetMode::Region).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(geo.vertices.len(), 8);
+        assert_eq!(geo.faces.len(), 5);
+        assert_eq!(cmd.inner_vertices(), 4..8);
+        // Copies follow the original vertex order
+        assert_eq!(geo.faces[0].vertex_indices, vec![4, 5, 7, 6]);
+        let expected = [
+            Vector3::new(0.25, 0.0, 0.25),
+            Vector3::new(0.75, 0.0, 0.25),
+            Vector3::new(0.25, 0.0, 0.75),
+            Vector3::new(0.75, 0.0, 0.75),
+        ];
+        for (v, expected) in cmd.inner_vertices().zip(expected) {
+            assert!(vectors_approx_equal(geo.vertices[v], expected, 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_ring_faces_keep_orientation() {
+        let mut geo = quad_grid(1, 1);
+        let cmd = InsetFaces::new(&geo, vec![0], 0.2, InsetMode::Region).unwrap();
+
+        cmd.execute(&mut geo);
+
+        for f in cmd.ring_face_indices().chain([0]) {
+            let normal = geo.faces[f].normal(&geo.vertices);
+            assert!(vectors_approx_equal(normal, Vector3::UP, 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_region_keeps_inner_edges() {
+        let mut grid = quad_grid(2, 1);
+        let cmd = InsetFaces::new(&grid, vec![0, 1], 0.25, InsetMode::Region).unwrap();
+
+        cmd.execute(&mut grid);
+
+        // Six outline edges get ring quads; the shared middle edge does not
+        assert_eq!(cmd.inner_vertices().len(), 6);
+        assert_eq!(cmd.ring_face_indices().len(), 6);
+        // The middle edge's copies only move off the outline
+        let middle: Vec<Vector3> = cmd
+            .inner_vertices()
+            .map(|v| grid.vertices[v])
+            .filter(|p| (p.x - 1.0).abs() < 1e-6)
+            .collect();
+        assert_eq!(middle.len(), 2);
+        for p in middle {
+            assert!((p.z - 0.25).abs() < 1e-6 || (p.z - 0.75).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_individual_insets_each_face() {
+        let mut grid = quad_grid(2, 1);
+        let cmd = InsetFaces::new(&grid, vec![0, 1], 0.25, InsetMode::Individual).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.inner_vertices().len(), 8);
+        assert_eq!(cmd.ring_face_indices().len(), 8);
+        // The inner faces no longer share any vertex
+        let shared = grid.faces[0]
+            .vertex_indices
+            .iter()
+            .filter(|v| grid.faces[1].vertex_indices.contains(v))
+            .count();
+        assert_eq!(shared, 0);
+    }
+
+    #[test]
+    fn test_inset_cube_face_stays_closed() {
+        let mut cube = unit_cube();
+        let cmd = InsetFaces::new(&cube, vec![2], 0.1, InsetMode::Region).unwrap();
+
+        cmd.execute(&mut cube);
+
+        let topology = MeshTopology::build(&cube);
+        assert!(topology.boundary_edges().is_empty());
+        // The inner face stays in the face's plane
+        for v in cmd.inner_vertices() {
+            assert!((cube.vertices[v].y - 0.5).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_inset_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = InsetFaces::new(&cube, vec![2, 4], 0.1, InsetMode::Individual).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_inset_triangle_mitres_corners() {
+        let mut geo = crate::test_utils::single_face();
+        let cmd = InsetFaces::new(&geo, vec![0], 0.05, InsetMode::Region).unwrap();
+
+        cmd.execute(&mut geo);
+
+        // Each inner edge sits the thickness away from its outer edge
+        for (k, (a, b)) in geo.faces[0].edges().enumerate() {
+            let (outer_a, outer_b) = (k, (k + 1) % 3);
+            let outer = geo.vertices[outer_b] - geo.vertices[outer_a];
+            for p in [geo.vertices[a], geo.vertices[b]] {
+                let rel = p - geo.vertices[outer_a];
+                let distance = outer.cross(rel).length() / outer.length();
+                assert!((distance - 0.05).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicate_faces_ignored() {
+        let geo = single_quad();
+        let cmd = InsetFaces::new(&geo, vec![0, 0], 0.1, InsetMode::Region).unwrap();
+        assert_eq!(cmd.faces(), &[0]);
+    }
+
+    #[test]
+    fn test_empty_selection() {
+        let geo = single_quad();
+        let result = InsetFaces::new(&geo, vec![], 0.1, InsetMode::Region);
+        assert_eq!(result.unwrap_err(), BlockotError::EmptySelection);
+    }
+
+    #[test]
+    fn test_non_positive_thickness() {
+        let geo = single_quad();
+        for thickness in [0.0, -0.1, f32::NAN] {
+            let result = InsetFaces::new(&geo, vec![0], thickness, InsetMode::Region);
+            assert_eq!(result.unwrap_err(), BlockotError::NonPositiveAmount);
+        }
+    }
+
+    #[test]
+    fn test_invalid_face() {
+        let geo = single_quad();
+        let result = InsetFaces::new(&geo, vec![3], 0.1, InsetMode::Individual);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidFaceIndex(3));
+    }
+
+    #[test]
+    fn test_inset_name() {
+        let geo = single_quad();
+        let cmd = InsetFaces::new(&geo, vec![0], 0.1, InsetMode::Region).unwrap();
+        assert_eq!(cmd.name(), "Inset Faces");
+        assert_eq!(cmd.mode(), InsetMode::Region);
+        assert_eq!(cmd.thickness(), 0.1);
+    }
+}
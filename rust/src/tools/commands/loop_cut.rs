@@ -0,0 +1,469 @@
+// tools/commands/loop_cut.rs - LoopCut command implementation
+//
+// Inserts edge loops through the ring of quads around an edge (see
+// MeshTopology::edge_ring). Every ring edge gets one new vertex per cut,
+// all at the same fraction measured from the same side of the ring, and
+// each ring quad is split into strips between them: the first strip keeps
+// the quad's index, the rest are appended. Faces at the ends of an open
+// ring that are not quads get the new vertices inserted into their loop,
+// so the mesh stays watertight.
+// One cut sits at the given fraction; several cuts are spaced evenly.
+// Undo truncates the appended vertices/faces and restores rewritten faces,
+// so the arrays come back exactly. Validates at construction, execute/undo
+// are infallible.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use godot::prelude::Vector3;
+
+use crate::error::BlockotError;
+use crate::geometry::{edge_key, BlockotGeometry, Edge, Face, MeshTopology};
+use crate::tools::Command;
+
+/// Command to cut edge loops through a ring of quads.
+#[derive(Debug, Clone)]
+pub struct LoopCut {
+    /// Ring edges in walking order, oriented so they all start on the same side
+    ring: Vec<Edge>,
+    /// Whether the ring closes on itself
+    closed: bool,
+    /// Number of loops inserted
+    cuts: usize,
+    /// Positions of the new vertices; ring edge `i`, cut `k` is entry `i * cuts + k`
+    new_vertices: Vec<Vector3>,
+    /// Faces rewritten in place (first strip of a quad, or inserted vertices)
+    rewritten: Vec<usize>,
+    /// `rewritten` faces before the cut, restored on undo
+    original_faces: Vec<Face>,
+    /// `rewritten` faces after the cut
+    cut_faces: Vec<Face>,
+    /// Remaining strips of the split quads, appended after the existing faces
+    appended: Vec<Face>,
+    /// Vertex count before the cut
+    vertex_count: usize,
+    /// Face count before the cut
+    face_count: usize,
+}
+
+impl LoopCut {
+    /// Create a new LoopCut inserting `cuts` loops (at least one) through the
+    /// edge ring of `a`-`b`. A single cut sits at `fraction` (clamped to 0..1)
+    /// of the way from `a` to `b`; several cuts are spaced evenly.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::InvalidVertexIndex` if `a` or `b` is out of bounds,
+    /// `BlockotError::InvalidEdge` if `a`-`b` is not an edge, or
+    /// `BlockotError::NoEdgeRing` if no quad uses the edge.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        (a, b): Edge,
+        cuts: usize,
+        fraction: f32,
+    ) -> Result<Self, BlockotError> {
+        if let Some(&v) = [a, b].iter().find(|&&v| v >= geo.vertices.len()) {
+            return Err(BlockotError::InvalidVertexIndex(v));
+        }
+        let ring = topology.edge_ring(a, b);
+        let Some(start) = ring.iter().position(|&edge| edge == edge_key(a, b)) else {
+            return Err(BlockotError::InvalidEdge(a, b));
+        };
+        let no_ring = || BlockotError::NoEdgeRing(a, b);
+
+        let quad_between = |e: Edge, f: Edge| {
+            topology.edge_faces(e.0, e.1).iter().copied().find(|face| {
+                geo.faces[*face].is_quad() && topology.edge_faces(f.0, f.1).contains(face)
+            })
+        };
+        let mut quads: Vec<usize> = ring
+            .windows(2)
+            .map(|pair| quad_between(pair[0], pair[1]))
+            .collect::<Option<_>>()
+            .ok_or_else(no_ring)?;
+        let wrap = match ring.as_slice() {
+            [first, .., last] if ring.len() > 2 => quad_between(*last, *first),
+            _ => None,
+        };
+        let closed = wrap.is_some_and(|face| !quads.contains(&face));
+        if let Some(face) = wrap.filter(|_| closed) {
+            quads.push(face);
+        }
+        if quads.is_empty() {
+            return Err(no_ring());
+        }
+
+        // Orient every ring edge like `a`-`b`, stepping across the quads
+        let mut oriented = ring.clone();
+        oriented[start] = (a, b);
+        for i in start..ring.len() - 1 {
+            oriented[i + 1] = across(&geo.faces[quads[i]], oriented[i]).ok_or_else(no_ring)?;
+        }
+        for i in (0..start).rev() {
+            oriented[i] = across(&geo.faces[quads[i]], oriented[i + 1]).ok_or_else(no_ring)?;
+        }
+
+        let cuts = cuts.max(1);
+        let fraction = fraction.clamp(0.0, 1.0);
+        let vertex_count = geo.vertices.len();
+        let new_vertices = oriented
+            .iter()
+            .flat_map(|&(from, to)| {
+                (0..cuts).map(move |k| {
+                    let t = if cuts == 1 {
+                        fraction
+                    } else {
+                        (k + 1) as f32 / (cuts + 1) as f32
+                    };
+                    geo.vertices[from].lerp(geo.vertices[to], t)
+                })
+            })
+            .collect();
+        // Ring edge `i` from its start to its end, through the new vertices
+        let levels = |i: usize| -> Vec<usize> {
+            let (from, to) = oriented[i];
+            let base = vertex_count + i * cuts;
+            std::iter::once(from)
+                .chain(base..base + cuts)
+                .chain(std::iter::once(to))
+                .collect()
+        };
+
+        let mut rewritten = Vec::new();
+        let mut original_faces = Vec::new();
+        let mut cut_faces = Vec::new();
+        let mut appended = Vec::new();
+        for (j, &f) in quads.iter().enumerate() {
+            let (near, far) = (levels(j), levels((j + 1) % ring.len()));
+            let face = &geo.faces[f];
+            // The quad either walks along the near edge first, or across to the far one
+            let along = face.edges().any(|edge| edge == (near[0], near[cuts + 1]));
+            let mut strips = (0..=cuts).map(|k| {
+                if along {
+                    Face::quad(near[k], near[k + 1], far[k + 1], far[k])
+                } else {
+                    Face::quad(near[k], far[k], far[k + 1], near[k + 1])
+                }
+            });
+            rewritten.push(f);
+            original_faces.push(face.clone());
+            cut_faces.extend(strips.next());
+            appended.extend(strips);
+        }
+
+        let ring_index: HashMap<Edge, usize> = ring
+            .iter()
+            .enumerate()
+            .map(|(i, &edge)| (edge, i))
+            .collect();
+        for (f, face) in geo.faces.iter().enumerate() {
+            if quads.contains(&f) {
+                continue;
+            }
+            let mut vertex_loop = Vec::with_capacity(face.vertex_count());
+            for (x, y) in face.edges() {
+                vertex_loop.push(x);
+                if let Some(&i) = ring_index.get(&edge_key(x, y)) {
+                    let inner = &levels(i)[1..=cuts];
+                    if x == oriented[i].0 {
+                        vertex_loop.extend(inner.iter());
+                    } else {
+                        vertex_loop.extend(inner.iter().rev());
+                    }
+                }
+            }
+            if vertex_loop.len() != face.vertex_count() {
+                rewritten.push(f);
+                original_faces.push(face.clone());
+                cut_faces.push(Face::new(vertex_loop));
+            }
+        }
+
+        Ok(Self {
+            ring: oriented,
+            closed,
+            cuts,
+            new_vertices,
+            rewritten,
+            original_faces,
+            cut_faces,
+            appended,
+            vertex_count,
+            face_count: geo.faces.len(),
+        })
+    }
+
+    /// Returns the ring edges in walking order, all oriented the same way.
+    pub fn ring(&self) -> &[Edge] {
+        &self.ring
+    }
+
+    /// Returns true if the ring closes on itself (and so do the new loops).
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the number of loops inserted.
+    pub fn cuts(&self) -> usize {
+        self.cuts
+    }
+
+    /// Returns the indices of the new vertices after execute.
+    pub fn new_vertices(&self) -> Range<usize> {
+        self.vertex_count..self.vertex_count + self.new_vertices.len()
+    }
+
+    /// Returns the vertices of each new loop after execute, in ring order.
+    pub fn loops(&self) -> Vec<Vec<usize>> {
+        (0..self.cuts)
+            .map(|k| {
+                (0..self.ring.len())
+                    .map(|i| self.vertex_count + i * self.cuts + k)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the positions of each new loop, in ring order (for previews).
+    pub fn loop_positions(&self) -> Vec<Vec<Vector3>> {
+        self.loops()
+            .iter()
+            .map(|vertices| {
+                vertices
+                    .iter()
+                    .map(|&v| self.new_vertices[v - self.vertex_count])
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Command for LoopCut {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        geo.vertices.extend_from_slice(&self.new_vertices);
+        for (&f, cut) in self.rewritten.iter().zip(&self.cut_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = cut.clone();
+            }
+        }
+        geo.faces.extend(self.appended.iter().cloned());
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        geo.faces.truncate(self.face_count);
+        for (&f, original) in self.rewritten.iter().zip(&self.original_faces) {
+            if let Some(face) = geo.faces.get_mut(f) {
+                *face = original.clone();
+            }
+        }
+        geo.vertices.truncate(self.vertex_count);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Loop Cut"
+    }
+}
+
+/// The quad's edge opposite `from`, oriented so its start is the quad
+/// neighbour of `from`'s start. None if `from` is not an edge of the quad.
+fn across(quad: &Face, (from, to): Edge) -> Option<Edge> {
+    let v = &quad.vertex_indices;
+    let i = v.iter().position(|&x| x == from)?;
+    let (next, prev) = (v[(i + 1) % 4], v[(i + 3) % 4]);
+    if next == to {
+        Some((prev, v[(i + 2) % 4]))
+    } else if prev == to {
+        Some((next, v[(i + 2) % 4]))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, single_quad, unit_cube, vectors_approx_equal};
+
+    fn build(geo: &BlockotGeometry, edge: Edge, cuts: usize, fraction: f32) -> LoopCut {
+        LoopCut::new(geo, &MeshTopology::build(geo), edge, cuts, fraction).unwrap()
+    }
+
+    #[test]
+    fn test_cut_single_quad() {
+        let mut quad = single_quad();
+        // Edge 0-1 runs along X; the ring crosses to edge 3-2
+        let cmd = build(&quad, (0, 1), 1, 0.25);
+
+        cmd.execute(&mut quad);
+
+        assert!(!cmd.is_closed());
+        assert_eq!(quad.vertices.len(), 6);
+        assert_eq!(quad.faces.len(), 2);
+        assert!(vectors_approx_equal(
+            quad.vertices[4],
+            Vector3::new(0.25, 0.0, 0.0),
+            1e-6
+        ));
+        assert!(vectors_approx_equal(
+            quad.vertices[5],
+            Vector3::new(0.25, 1.0, 0.0),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_fraction_is_measured_from_first_vertex() {
+        let quad = single_quad();
+        let cmd = build(&quad, (1, 0), 1, 0.25);
+
+        let positions = &cmd.loop_positions()[0];
+        assert!(vectors_approx_equal(
+            positions[0],
+            Vector3::new(0.75, 0.0, 0.0),
+            1e-6
+        ));
+        // The far edge is measured from the same side
+        assert!((positions[1].x - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_strips_keep_winding() {
+        let mut quad = single_quad();
+        let normal = quad.faces[0].normal(&quad.vertices);
+        let cmd = build(&quad, (1, 2), 3, 0.5);
+
+        cmd.execute(&mut quad);
+
+        assert_eq!(quad.faces.len(), 4);
+        for face in &quad.faces {
+            assert!(face.is_quad());
+            assert!(face.normal(&quad.vertices).dot(normal) > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_several_cuts_are_even() {
+        let quad = single_quad();
+        let cmd = build(&quad, (0, 1), 3, 0.9);
+
+        let xs: Vec<f32> = cmd.loop_positions().iter().map(|l| l[0].x).collect();
+        for (x, expected) in xs.iter().zip([0.25, 0.5, 0.75]) {
+            assert!((x - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_cube_ring_is_closed() {
+        let mut cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let vertical = topology
+            .edges()
+            .iter()
+            .copied()
+            .find(|&(a, b)| (cube.vertices[a].y - cube.vertices[b].y).abs() > 0.5)
+            .unwrap();
+        let cmd = build(&cube, vertical, 1, 0.5);
+
+        cmd.execute(&mut cube);
+
+        assert!(cmd.is_closed());
+        assert_eq!(cmd.ring().len(), 4);
+        assert_eq!(cube.vertices.len(), 12);
+        assert_eq!(cube.faces.len(), 10);
+        for v in cmd.new_vertices() {
+            assert!(cube.vertices[v].y.abs() < 1e-6);
+        }
+        // Every edge of a closed mesh still has two faces
+        let after = MeshTopology::build(&cube);
+        assert!(after.boundary_edges().is_empty());
+    }
+
+    #[test]
+    fn test_grid_ring_spans_all_quads() {
+        let mut grid = quad_grid(3, 1);
+        // Edge 0-4 is the left side; its ring crosses all three quads
+        let cmd = build(&grid, (0, 4), 2, 0.5);
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.ring().len(), 4);
+        assert_eq!(cmd.loops().len(), 2);
+        assert_eq!(grid.vertices.len(), 8 + 8);
+        assert_eq!(grid.faces.len(), 9);
+    }
+
+    #[test]
+    fn test_end_ngon_gets_new_vertices() {
+        let mut geo = single_quad();
+        // A triangle on the quad's right edge ends the ring
+        geo.vertices.push(Vector3::new(2.0, 0.5, 0.0));
+        geo.faces.push(Face::triangle(1, 4, 2));
+        let cmd = build(&geo, (0, 3), 1, 0.5);
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(geo.faces[1].vertex_count(), 4);
+        assert!(geo.faces[1].vertex_indices.contains(&6));
+    }
+
+    #[test]
+    fn test_end_face_keeps_its_normal() {
+        let mut geo = single_quad();
+        // The triangle's first edge is the one the ring crosses, so the cut
+        // puts a straight corner at its start
+        geo.vertices.push(Vector3::new(0.5, 2.0, 0.0));
+        geo.faces.push(Face::triangle(3, 2, 4));
+        let normal = geo.faces[1].normal(&geo.vertices);
+        let cmd = build(&geo, (0, 1), 1, 0.5);
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(geo.faces[1].vertex_count(), 4);
+        assert!(vectors_approx_equal(
+            geo.faces[1].normal(&geo.vertices),
+            normal,
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn test_loop_cut_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = build(&cube, (0, 1), 2, 0.5);
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_loop_cut_errors() {
+        let mut geo = single_quad();
+        let topology = MeshTopology::build(&geo);
+        assert_eq!(
+            LoopCut::new(&geo, &topology, (0, 2), 1, 0.5).unwrap_err(),
+            BlockotError::InvalidEdge(0, 2)
+        );
+        assert_eq!(
+            LoopCut::new(&geo, &topology, (0, 9), 1, 0.5).unwrap_err(),
+            BlockotError::InvalidVertexIndex(9)
+        );
+
+        geo.faces = vec![Face::triangle(0, 1, 2)];
+        let topology = MeshTopology::build(&geo);
+        assert_eq!(
+            LoopCut::new(&geo, &topology, (0, 1), 1, 0.5).unwrap_err(),
+            BlockotError::NoEdgeRing(0, 1)
+        );
+    }
+
+    #[test]
+    fn test_loop_cut_name() {
+        let quad = single_quad();
+        assert_eq!(build(&quad, (0, 1), 1, 0.5).name(), "Loop Cut");
+    }
+}
@@ -0,0 +1,281 @@
+// tools/commands/merge_by_distance.rs - MergeByDistance command implementation
+//
+// Welds vertices lying within a threshold of each other, e.g. the coincident
+// copies cuts and extrudes leave behind. Vertices are visited in index
+// order; each one not merged yet gathers the remaining vertices within the
+// threshold of it and keeps its position. Candidates come from a spatial
+// hash with cells as wide as the threshold, so only vertices in neighbouring
+// cells are compared. Faces are rewritten to the surviving vertices, faces
+// left with fewer than three distinct corners are dropped, and the arrays
+// are compacted (see mesh_edit.rs).
+// Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashMap};
+
+use godot::prelude::Vector3;
+
+use super::mesh_edit::{merge_vertices, MeshEdit};
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command to merge vertices closer than a threshold.
+#[derive(Debug, Clone)]
+pub struct MergeByDistance {
+    /// Largest distance between merged vertices
+    threshold: f32,
+    /// Arrays before and after the merge
+    edit: MeshEdit,
+}
+
+impl MergeByDistance {
+    /// Create a new MergeByDistance command over `vertices` (usually the
+    /// selection, or every vertex). Negative thresholds count as zero, which
+    /// still merges exactly coincident vertices.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if vertices is empty, or
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds.
+    pub fn new(
+        geo: &BlockotGeometry,
+        vertices: Vec<usize>,
+        threshold: f32,
+    ) -> Result<Self, BlockotError> {
+        if vertices.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let vertices: Vec<usize> = vertices
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&v) = vertices.iter().find(|&&v| v >= geo.vertices.len()) {
+            return Err(BlockotError::InvalidVertexIndex(v));
+        }
+        let threshold = threshold.max(0.0);
+
+        // Any cell size works for exact matches; a zero one would not index
+        let grid = SpatialHash::new(
+            vertices.iter().map(|&v| geo.vertices[v]),
+            if threshold > 0.0 { threshold } else { 1.0 },
+        );
+        let mut merged = vec![false; vertices.len()];
+        let mut groups = Vec::new();
+        for (i, &v) in vertices.iter().enumerate() {
+            if merged[i] {
+                continue;
+            }
+            let position = geo.vertices[v];
+            let mut near: Vec<usize> = grid
+                .near(position)
+                .filter(|&j| {
+                    j > i
+                        && !merged[j]
+                        && geo.vertices[vertices[j]].distance_to(position) <= threshold
+                })
+                .collect();
+            if near.is_empty() {
+                continue;
+            }
+            near.sort_unstable();
+            let mut group = vec![v];
+            for j in near {
+                merged[j] = true;
+                group.push(vertices[j]);
+            }
+            groups.push((group, position));
+        }
+
+        let edit = merge_vertices(geo, &groups);
+        Ok(Self { threshold, edit })
+    }
+
+    /// Returns the merge threshold.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Returns the number of vertices the merge removes.
+    pub fn removed_vertex_count(&self) -> usize {
+        self.edit.removed_vertex_count()
+    }
+
+    /// Returns the number of faces dropped as degenerate.
+    pub fn removed_face_count(&self) -> usize {
+        self.edit.removed_face_count()
+    }
+
+    /// Returns the index a vertex has after execute; merged vertices map to
+    /// the vertex they merged into.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for MergeByDistance {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Merge by Distance"
+    }
+}
+
+/// Positions bucketed into cubic cells, for finding neighbours within one
+/// cell size without comparing every pair.
+struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<(i32, i32, i32), Vec<usize>>,
+}
+
+impl SpatialHash {
+    fn new(positions: impl Iterator<Item = Vector3>, cell_size: f32) -> Self {
+        let mut hash = Self {
+            cell_size,
+            cells: HashMap::new(),
+        };
+        for (i, pos) in positions.enumerate() {
+            hash.cells.entry(hash.cell(pos)).or_default().push(i);
+        }
+        hash
+    }
+
+    fn cell(&self, pos: Vector3) -> (i32, i32, i32) {
+        (
+            (pos.x / self.cell_size).floor() as i32,
+            (pos.y / self.cell_size).floor() as i32,
+            (pos.z / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Indices in the cell of `pos` and the 26 around it.
+    fn near(&self, pos: Vector3) -> impl Iterator<Item = usize> + '_ {
+        let (x, y, z) = self.cell(pos);
+        (-1..=1)
+            .flat_map(move |dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+            .filter_map(move |(dx, dy, dz)| {
+                self.cells.get(&(
+                    x.saturating_add(dx),
+                    y.saturating_add(dy),
+                    z.saturating_add(dz),
+                ))
+            })
+            .flatten()
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::MeshTopology;
+    use crate::test_utils::{quad_grid, unit_cube, unwelded_grid};
+
+    fn all_vertices(geo: &BlockotGeometry) -> Vec<usize> {
+        (0..geo.vertices.len()).collect()
+    }
+
+    #[test]
+    fn test_merge_coincident_vertices() {
+        let mut geo = unwelded_grid(2, 2);
+        let cmd = MergeByDistance::new(&geo, all_vertices(&geo), 0.001).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(cmd.removed_vertex_count(), 16 - 9);
+        assert_eq!(cmd.removed_face_count(), 0);
+        assert_eq!(geo.vertices.len(), 9);
+        assert_eq!(geo.faces.len(), 4);
+        // The quads are stitched together: only the outer border stays open
+        assert_eq!(MeshTopology::build(&geo).boundary_edges().len(), 8);
+    }
+
+    #[test]
+    fn test_merge_drops_degenerate_faces() {
+        let mut geo = quad_grid(2, 1);
+        // Squash the right quad onto the middle edge
+        geo.vertices[2] = Vector3::new(1.0, 0.0, 0.0);
+        geo.vertices[5] = Vector3::new(1.0, 0.0, 1.0);
+        let cmd = MergeByDistance::new(&geo, all_vertices(&geo), 0.001).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(cmd.removed_vertex_count(), 2);
+        assert_eq!(cmd.removed_face_count(), 1);
+        assert_eq!(geo.faces.len(), 1);
+        assert_eq!(cmd.remapped(2), cmd.remapped(1));
+    }
+
+    #[test]
+    fn test_merge_only_given_vertices() {
+        let mut geo = unwelded_grid(2, 1);
+        // Quad 0 uses vertices 0..4, quad 1 uses 4..8; 1 and 4 coincide, as do 2 and 7
+        let cmd = MergeByDistance::new(&geo, vec![1, 4], 0.001).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(geo.vertices.len(), 7);
+        assert_eq!(cmd.remapped(4), Some(1));
+    }
+
+    #[test]
+    fn test_merge_across_cells() {
+        let mut geo = quad_grid(1, 1);
+        // Either side of a cell border, and of the origin
+        geo.vertices.push(Vector3::new(0.199, 0.0, 0.0));
+        geo.vertices.push(Vector3::new(0.201, 0.0, 0.0));
+        geo.vertices.push(Vector3::new(-0.001, 0.0, -0.001));
+        let cmd = MergeByDistance::new(&geo, vec![0, 4, 5, 6], 0.1).unwrap();
+
+        cmd.execute(&mut geo);
+
+        assert_eq!(cmd.removed_vertex_count(), 2);
+        assert_eq!(cmd.remapped(6), Some(0));
+        assert_eq!(cmd.remapped(5), cmd.remapped(4));
+    }
+
+    #[test]
+    fn test_threshold_keeps_distant_vertices() {
+        let mut cube = unit_cube();
+        let original = cube.clone();
+        let cmd = MergeByDistance::new(&cube, all_vertices(&cube), 0.5).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cmd.removed_vertex_count(), 0);
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_merge_by_distance_undo_is_exact() {
+        let original = unwelded_grid(3, 2);
+        let mut geo = original.clone();
+        let cmd = MergeByDistance::new(&geo, all_vertices(&geo), 0.001).unwrap();
+
+        cmd.execute(&mut geo);
+        cmd.undo(&mut geo);
+
+        assert_eq!(geo, original);
+    }
+
+    #[test]
+    fn test_invalid_vertex() {
+        let cube = unit_cube();
+        let result = MergeByDistance::new(&cube, vec![0, 8], 0.1);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidVertexIndex(8));
+    }
+
+    #[test]
+    fn test_merge_by_distance_name() {
+        let cube = unit_cube();
+        let cmd = MergeByDistance::new(&cube, vec![0], -1.0).unwrap();
+        assert_eq!(cmd.name(), "Merge by Distance");
+        assert_eq!(cmd.threshold(), 0.0);
+    }
+}
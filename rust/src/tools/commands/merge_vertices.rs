@@ -0,0 +1,235 @@
+// tools/commands/merge_vertices.rs - MergeVertices command implementation
+//
+// Welds the given vertices into one, placed at their centre or at the first
+// or last of them (callers pass the vertices in pick order). The survivor is
+// the vertex merged at, or the lowest index for the centre. Faces are
+// rewritten to it, faces left with fewer than three distinct corners are
+// dropped, and the arrays are compacted (see mesh_edit.rs).
+// Undo restores the exact original arrays.
+// Validates at construction, execute/undo are infallible.
+
+use godot::prelude::Vector3;
+
+use super::mesh_edit::{merge_vertices, MeshEdit};
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Where merged vertices end up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeAt {
+    /// The average of the merged vertices
+    #[default]
+    Center,
+    /// The first vertex given
+    First,
+    /// The last vertex given
+    Last,
+}
+
+/// Command to merge vertices into one.
+#[derive(Debug, Clone)]
+pub struct MergeVertices {
+    /// Merged vertex indices, in the order given (unique)
+    vertices: Vec<usize>,
+    /// Where the vertices merge
+    at: MergeAt,
+    /// Arrays before and after the merge
+    edit: MeshEdit,
+}
+
+impl MergeVertices {
+    /// Create a new MergeVertices command. Duplicate indices are ignored,
+    /// keeping the first occurrence.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if vertices is empty, or
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds.
+    pub fn new(
+        geo: &BlockotGeometry,
+        vertices: Vec<usize>,
+        at: MergeAt,
+    ) -> Result<Self, BlockotError> {
+        if vertices.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let mut unique: Vec<usize> = Vec::with_capacity(vertices.len());
+        for v in vertices {
+            if !unique.contains(&v) {
+                unique.push(v);
+            }
+        }
+        let vertices = unique;
+        if let Some(&v) = vertices.iter().find(|&&v| v >= geo.vertices.len()) {
+            return Err(BlockotError::InvalidVertexIndex(v));
+        }
+
+        // The survivor goes first in the group
+        let mut group = vertices.clone();
+        let position = match at {
+            MergeAt::Center => {
+                group.sort_unstable();
+                let sum = group
+                    .iter()
+                    .fold(Vector3::ZERO, |acc, &v| acc + geo.vertices[v]);
+                sum / group.len() as f32
+            }
+            MergeAt::First => geo.vertices[group[0]],
+            MergeAt::Last => {
+                group.rotate_right(1);
+                geo.vertices[group[0]]
+            }
+        };
+
+        let edit = merge_vertices(geo, &[(group, position)]);
+        Ok(Self { vertices, at, edit })
+    }
+
+    /// Returns the merged vertex indices.
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    /// Returns where the vertices merge.
+    pub fn at(&self) -> MergeAt {
+        self.at
+    }
+
+    /// Returns the number of vertices the merge removes.
+    pub fn removed_vertex_count(&self) -> usize {
+        self.edit.removed_vertex_count()
+    }
+
+    /// Returns the number of faces dropped as degenerate.
+    pub fn removed_face_count(&self) -> usize {
+        self.edit.removed_face_count()
+    }
+
+    /// Returns the index a vertex has after execute; merged vertices map to
+    /// the vertex they merged into.
+    pub fn remapped(&self, v: usize) -> Option<usize> {
+        self.edit.remapped(v)
+    }
+}
+
+impl Command for MergeVertices {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        self.edit.apply(geo);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        self.edit.revert(geo);
+    }
+
+    fn name(&self) -> &'static str {
+        "Merge Vertices"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, single_face, unit_cube, vectors_approx_equal};
+
+    #[test]
+    fn test_merge_at_center() {
+        let mut grid = quad_grid(2, 1);
+        let cmd = MergeVertices::new(&grid, vec![4, 1], MergeAt::Center).unwrap();
+
+        cmd.execute(&mut grid);
+
+        assert_eq!(cmd.remapped(1), Some(1));
+        assert_eq!(cmd.remapped(4), Some(1));
+        assert!(vectors_approx_equal(
+            grid.vertices[1],
+            Vector3::new(1.0, 0.0, 0.5),
+            1e-6
+        ));
+        assert!(grid.faces.iter().all(|face| face.is_triangle()));
+        assert_eq!(cmd.removed_vertex_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_at_first_and_last() {
+        let grid = quad_grid(2, 1);
+
+        let mut first = grid.clone();
+        let cmd = MergeVertices::new(&first, vec![5, 0, 2], MergeAt::First).unwrap();
+        cmd.execute(&mut first);
+        let survivor = cmd.remapped(5).unwrap();
+        assert_eq!(cmd.remapped(0), Some(survivor));
+        assert!(vectors_approx_equal(
+            first.vertices[survivor],
+            Vector3::new(2.0, 0.0, 1.0),
+            1e-6
+        ));
+
+        let mut last = grid.clone();
+        let cmd = MergeVertices::new(&last, vec![5, 0, 2], MergeAt::Last).unwrap();
+        cmd.execute(&mut last);
+        let survivor = cmd.remapped(2).unwrap();
+        assert_eq!(cmd.remapped(5), Some(survivor));
+        assert!(vectors_approx_equal(
+            last.vertices[survivor],
+            Vector3::new(2.0, 0.0, 0.0),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_merge_drops_degenerate_faces() {
+        let mut cube = unit_cube();
+        // Merging a whole face's corners leaves no face around it with 3 corners
+        let top = cube.faces[0].vertex_indices.clone();
+        let cmd = MergeVertices::new(&cube, top, MergeAt::Center).unwrap();
+
+        cmd.execute(&mut cube);
+
+        assert_eq!(cube.vertices.len(), 5);
+        assert_eq!(cmd.removed_vertex_count(), 3);
+        assert_eq!(cmd.removed_face_count(), 1);
+        assert_eq!(cube.faces.len(), 5);
+        assert!(cube.faces.iter().all(|face| face.vertex_count() >= 3));
+    }
+
+    #[test]
+    fn test_merge_whole_triangle() {
+        let mut tri = single_face();
+        let cmd = MergeVertices::new(&tri, vec![0, 1, 2], MergeAt::First).unwrap();
+
+        cmd.execute(&mut tri);
+
+        // The merged vertex is left without faces, so it goes too
+        assert!(tri.faces.is_empty());
+        assert!(tri.vertices.is_empty());
+        assert_eq!(cmd.removed_face_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_vertices_undo_is_exact() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let cmd = MergeVertices::new(&cube, vec![6, 0, 3], MergeAt::Last).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_invalid_vertex() {
+        let cube = unit_cube();
+        let result = MergeVertices::new(&cube, vec![1, 12], MergeAt::Center);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidVertexIndex(12));
+    }
+
+    #[test]
+    fn test_merge_vertices_name() {
+        let cube = unit_cube();
+        let cmd = MergeVertices::new(&cube, vec![3, 1, 3], MergeAt::First).unwrap();
+        assert_eq!(cmd.name(), "Merge Vertices");
+        assert_eq!(cmd.vertices(), &[3, 1]);
+        assert_eq!(cmd.at(), MergeAt::First);
+    }
+}
@@ -0,0 +1,319 @@
+// tools/commands/mesh_edit.rs - Shared support for commands that remove elements
+//
+// Removing vertices or faces shifts every index after them, so instead of
+// patching the arrays in place these commands keep the arrays from before
+// the edit and swap them back in on undo, which restores the exact original
+// arrays, ordering included, as index-based selections rely on. The state
+// after the edit is rebuilt on execute from those arrays, the vertex remap
+// and the few faces and positions the edit changes. `MeshEdit::compact`
+// works out that change from a working copy of the faces: removed faces are
+// dropped, faces left with repeated corners are cleaned up (or dropped below
+// three vertices), and vertices that are removed or no longer used by any
+// face are compacted away, remapping every face. Vertices no face used
+// before stay.
+
+use std::collections::{HashMap, HashSet};
+
+use godot::prelude::Vector3;
+
+use crate::geometry::{BlockotGeometry, Face};
+
+/// The arrays before an edit that removes elements, and how to get from
+/// them to the arrays after it.
+#[derive(Debug, Clone)]
+pub(super) struct MeshEdit {
+    before_vertices: Vec<Vector3>,
+    before_faces: Vec<Face>,
+    /// New index of each vertex from before the edit, None if removed
+    remap: Vec<Option<usize>>,
+    /// Removed vertices that follow another one (before indices)
+    redirects: HashMap<usize, usize>,
+    /// Kept vertices the edit moves, with their new position
+    moved: Vec<(usize, Vector3)>,
+    /// Faces the edit changes, in before indices and ascending face order;
+    /// None for a removed face
+    changed_faces: Vec<(usize, Option<Face>)>,
+}
+
+impl MeshEdit {
+    /// Build the edit turning `geo` into `vertices` and `faces` (None for a
+    /// removed face), compacted. `removed` vertices are dropped along with
+    /// every face still using them.
+    pub(super) fn compact(
+        geo: &BlockotGeometry,
+        vertices: Vec<Vector3>,
+        faces: Vec<Option<Face>>,
+        removed: &HashSet<usize>,
+    ) -> Self {
+        let faces: Vec<Option<Face>> = faces
+            .into_iter()
+            .map(|face| {
+                face.filter(|face| !face.vertex_indices.iter().any(|v| removed.contains(v)))
+                    .and_then(|face| clean_face(face.vertex_indices))
+            })
+            .collect();
+
+        let used_before: HashSet<usize> = geo
+            .faces
+            .iter()
+            .flat_map(|face| face.vertex_indices.iter().copied())
+            .collect();
+        let used_after: HashSet<usize> = faces
+            .iter()
+            .flatten()
+            .flat_map(|face| face.vertex_indices.iter().copied())
+            .collect();
+
+        let mut remap = vec![None; vertices.len()];
+        let mut moved = Vec::new();
+        let mut kept = 0;
+        for (v, position) in vertices.into_iter().enumerate() {
+            let keep =
+                !removed.contains(&v) && (used_after.contains(&v) || !used_before.contains(&v));
+            if keep {
+                remap[v] = Some(kept);
+                kept += 1;
+                if geo.vertices.get(v) != Some(&position) {
+                    moved.push((v, position));
+                }
+            }
+        }
+        let changed_faces = faces
+            .into_iter()
+            .enumerate()
+            .filter(|(f, face)| face.as_ref() != geo.faces.get(*f))
+            .collect();
+
+        Self {
+            before_vertices: geo.vertices.clone(),
+            before_faces: geo.faces.clone(),
+            remap,
+            redirects: HashMap::new(),
+            moved,
+            changed_faces,
+        }
+    }
+
+    /// Make vertex `from` follow `to` in `remapped` (e.g. merged into it).
+    pub(super) fn redirect(&mut self, from: usize, to: usize) {
+        self.redirects.insert(from, to);
+    }
+
+    /// Returns the index a vertex has after the edit, None if it is removed.
+    pub(super) fn remapped(&self, v: usize) -> Option<usize> {
+        let kept = self.remap.get(v).copied().flatten();
+        kept.or_else(|| {
+            let to = *self.redirects.get(&v)?;
+            self.remap.get(to).copied().flatten()
+        })
+    }
+
+    /// Returns how many vertices the edit removes.
+    pub(super) fn removed_vertex_count(&self) -> usize {
+        self.remap.iter().filter(|slot| slot.is_none()).count()
+    }
+
+    /// Returns how many faces the edit removes.
+    pub(super) fn removed_face_count(&self) -> usize {
+        self.changed_faces
+            .iter()
+            .filter(|(_, face)| face.is_none())
+            .count()
+    }
+
+    /// Rebuild the arrays after the edit and swap them in.
+    pub(super) fn apply(&self, geo: &mut BlockotGeometry) {
+        let mut vertices: Vec<Vector3> = self
+            .before_vertices
+            .iter()
+            .zip(&self.remap)
+            .filter(|(_, slot)| slot.is_some())
+            .map(|(&position, _)| position)
+            .collect();
+        for &(v, position) in &self.moved {
+            if let Some(i) = self.remapped(v) {
+                vertices[i] = position;
+            }
+        }
+
+        let mut changed = self.changed_faces.iter().peekable();
+        let mut faces = Vec::with_capacity(self.before_faces.len());
+        for (f, face) in self.before_faces.iter().enumerate() {
+            let face = match changed.next_if(|(changed_f, _)| *changed_f == f) {
+                Some((_, changed_face)) => changed_face.as_ref(),
+                None => Some(face),
+            };
+            let remapped = face.and_then(|face| {
+                face.vertex_indices
+                    .iter()
+                    .map(|&v| self.remap.get(v).copied().flatten())
+                    .collect::<Option<Vec<_>>>()
+            });
+            faces.extend(remapped.map(Face::new));
+        }
+
+        geo.vertices = vertices;
+        geo.faces = faces;
+        geo.dirty = true;
+    }
+
+    /// Swap the original arrays back in.
+    pub(super) fn revert(&self, geo: &mut BlockotGeometry) {
+        geo.vertices.clone_from(&self.before_vertices);
+        geo.faces.clone_from(&self.before_faces);
+        geo.dirty = true;
+    }
+}
+
+/// Merge each group of vertices into its first one, moved to the group's
+/// position, and compact. Merged vertices remap to the vertex they joined.
+pub(super) fn merge_vertices(geo: &BlockotGeometry, groups: &[(Vec<usize>, Vector3)]) -> MeshEdit {
+    let mut target: Vec<usize> = (0..geo.vertices.len()).collect();
+    let mut vertices = geo.vertices.clone();
+    for (group, position) in groups {
+        let Some(&first) = group.first() else {
+            continue;
+        };
+        vertices[first] = *position;
+        for &v in group {
+            target[v] = first;
+        }
+    }
+    let faces = geo
+        .faces
+        .iter()
+        .map(|face| {
+            Some(Face::new(
+                face.vertex_indices.iter().map(|&v| target[v]).collect(),
+            ))
+        })
+        .collect();
+    let merged: HashSet<usize> = (0..target.len()).filter(|&v| target[v] != v).collect();
+
+    let mut edit = MeshEdit::compact(geo, vertices, faces, &merged);
+    for &v in &merged {
+        edit.redirect(v, target[v]);
+    }
+    edit
+}
+
+/// Clean up a face whose corners may repeat after vertices were merged or
+/// removed: repeated neighbours collapse into one and spikes (`a, b, a`)
+/// fold away. Returns None when fewer than three corners are left.
+pub(super) fn clean_face(mut indices: Vec<usize>) -> Option<Face> {
+    loop {
+        let n = indices.len();
+        if n < 3 {
+            return None;
+        }
+        let repeated = (0..n).find(|&i| indices[i] == indices[(i + 1) % n]);
+        if let Some(i) = repeated {
+            indices.remove(i);
+            continue;
+        }
+        // A spike a, b, a: drop the tip and one of the repeated corners
+        let spike = (0..n).find(|&i| indices[i] == indices[(i + 2) % n]);
+        match spike {
+            Some(i) => {
+                let tip = (i + 1) % n;
+                let (first, second) = if tip > i { (i, tip) } else { (tip, i) };
+                indices.remove(second);
+                indices.remove(first);
+            }
+            None => return Some(Face::new(indices)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    #[test]
+    fn test_clean_face_keeps_valid_faces() {
+        assert_eq!(clean_face(vec![0, 1, 2, 3]), Some(Face::quad(0, 1, 2, 3)));
+    }
+
+    #[test]
+    fn test_clean_face_collapses_repeats() {
+        assert_eq!(clean_face(vec![0, 1, 1, 2]), Some(Face::triangle(0, 1, 2)));
+        assert_eq!(clean_face(vec![3, 0, 1, 3]), Some(Face::triangle(3, 0, 1)));
+        assert_eq!(clean_face(vec![0, 0, 1, 1]), None);
+    }
+
+    #[test]
+    fn test_clean_face_folds_spikes() {
+        assert_eq!(
+            clean_face(vec![0, 1, 2, 5, 2, 3]),
+            Some(Face::quad(0, 1, 2, 3))
+        );
+        assert_eq!(clean_face(vec![0, 1, 0]), None);
+    }
+
+    #[test]
+    fn test_compact_drops_unused_vertices() {
+        let grid = quad_grid(2, 1);
+        let faces = vec![Some(grid.faces[0].clone()), None];
+        let edit = MeshEdit::compact(&grid, grid.vertices.clone(), faces, &HashSet::new());
+
+        let mut geo = grid.clone();
+        edit.apply(&mut geo);
+
+        // Vertices 2 and 5 were only used by the removed face
+        assert_eq!(geo.vertices.len(), 4);
+        assert_eq!(geo.faces, vec![Face::quad(0, 1, 3, 2)]);
+        assert_eq!(edit.remapped(4), Some(3));
+        assert_eq!(edit.remapped(5), None);
+        assert_eq!(edit.removed_vertex_count(), 2);
+        assert_eq!(edit.removed_face_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_keeps_only_changes() {
+        let grid = quad_grid(3, 1);
+        let mut geo = grid.clone();
+        // Merge the last bottom corner into its neighbour, moved halfway
+        let position = Vector3::new(2.5, 0.0, 0.0);
+        let edit = merge_vertices(&grid, &[(vec![2, 3], position)]);
+
+        // Only the last quad uses the vertex merged away
+        assert_eq!(edit.changed_faces.len(), 1);
+        assert_eq!(edit.moved, vec![(2, position)]);
+
+        edit.apply(&mut geo);
+        assert_eq!(geo.vertices.len(), 7);
+        assert_eq!(geo.vertices[2], position);
+        assert_eq!(geo.faces[0], Face::quad(0, 1, 4, 3));
+        assert_eq!(geo.faces[2], Face::triangle(2, 6, 5));
+        assert_eq!(edit.remapped(3), Some(2));
+        assert_eq!(edit.remapped(7), Some(6));
+        edit.revert(&mut geo);
+        assert_eq!(geo, grid);
+    }
+
+    #[test]
+    fn test_compact_keeps_loose_vertices() {
+        let mut cube = unit_cube();
+        cube.vertices.push(Vector3::new(3.0, 0.0, 0.0));
+        let faces = cube.faces.iter().cloned().map(Some).collect();
+        let edit = MeshEdit::compact(&cube, cube.vertices.clone(), faces, &HashSet::new());
+        assert_eq!(edit.removed_vertex_count(), 0);
+    }
+
+    #[test]
+    fn test_revert_restores_exact_arrays() {
+        let original = unit_cube();
+        let mut cube = original.clone();
+        let faces = cube.faces.iter().cloned().map(Some).collect();
+        let removed = HashSet::from([0]);
+        let edit = MeshEdit::compact(&cube, cube.vertices.clone(), faces, &removed);
+
+        edit.apply(&mut cube);
+        assert_eq!(cube.vertices.len(), 7);
+        assert_eq!(cube.faces.len(), 3);
+        edit.revert(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+}
@@ -1,5 +1,49 @@
 // tools/commands/mod.rs - Re-exports for command implementations
 
+mod bevel_edges;
+mod boundary_chains;
+mod bridge_edge_loops;
+mod collapse_edges;
+mod composite;
+mod cut_faces;
+mod delete_faces;
+mod delete_vertices;
+mod dissolve_edges;
+mod dissolve_vertices;
+mod edge_slide;
+mod extrude_edges;
+mod extrude_faces;
+mod fill_hole;
+mod flip_faces;
+mod inset_faces;
+mod loop_cut;
+mod merge_by_distance;
+mod merge_vertices;
+mod mesh_edit;
 mod move_vertices;
+mod recalculate_normals;
+mod rotate_vertices;
+mod scale_vertices;
 
+pub use bevel_edges::BevelEdges;
+pub use bridge_edge_loops::BridgeEdgeLoops;
+pub use collapse_edges::CollapseEdges;
+pub use composite::CompositeCommand;
+pub use cut_faces::{faces_under_line, CutFaces, CutPlane};
+pub use delete_faces::DeleteFaces;
+pub use delete_vertices::DeleteVertices;
+pub use dissolve_edges::DissolveEdges;
+pub use dissolve_vertices::DissolveVertices;
+pub use edge_slide::EdgeSlide;
+pub use extrude_edges::ExtrudeEdges;
+pub use extrude_faces::ExtrudeFaces;
+pub use fill_hole::FillHole;
+pub use flip_faces::FlipFaces;
+pub use inset_faces::{InsetFaces, InsetMode};
+pub use loop_cut::LoopCut;
+pub use merge_by_distance::MergeByDistance;
+pub use merge_vertices::{MergeAt, MergeVertices};
 pub use move_vertices::MoveVertices;
+pub use recalculate_normals::RecalculateNormals;
+pub use rotate_vertices::RotateVertices;
+pub use scale_vertices::ScaleVertices;
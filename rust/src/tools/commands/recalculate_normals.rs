@@ -0,0 +1,277 @@
+// tools/commands/recalculate_normals.rs - RecalculateNormals command implementation
+//
+// Repairs winding. Starting from the lowest face of each connected group,
+// orientation spreads across every edge shared by exactly two of the faces,
+// so that neighbours walk their shared edge in opposite directions. A
+// closed group is then turned so its signed volume is positive, i.e. its
+// normals point outward; an open group has no inside, so it keeps the
+// orientation most of its faces already had. Non-manifold edges do not pass
+// orientation on.
+// Only flips are stored: undo flips the same faces again, so the arrays
+// come back exactly. Validates at construction, execute/undo are infallible.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::flip_faces::flip_all;
+use crate::error::BlockotError;
+use crate::geometry::{BlockotGeometry, MeshTopology};
+use crate::tools::Command;
+
+/// Command to make face winding consistent and closed parts face outward.
+#[derive(Debug, Clone)]
+pub struct RecalculateNormals {
+    /// Faces taken into account (sorted, unique)
+    faces: Vec<usize>,
+    /// Faces whose winding gets reversed (sorted)
+    flipped: Vec<usize>,
+}
+
+impl RecalculateNormals {
+    /// Create a new RecalculateNormals command over `faces` (usually the
+    /// selection, or every face). Only edges between these faces connect
+    /// them. Duplicate indices are ignored.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if faces is empty, or
+    /// `BlockotError::InvalidFaceIndex` if any index is out of bounds.
+    pub fn new(
+        geo: &BlockotGeometry,
+        topology: &MeshTopology,
+        faces: Vec<usize>,
+    ) -> Result<Self, BlockotError> {
+        if faces.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let faces: Vec<usize> = faces
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let Some(&f) = faces.iter().find(|&&f| f >= geo.faces.len()) {
+            return Err(BlockotError::InvalidFaceIndex(f));
+        }
+        let included: HashSet<usize> = faces.iter().copied().collect();
+
+        // Whether each face needs flipping to agree with its group's first face
+        let mut flip: HashMap<usize, bool> = HashMap::new();
+        let mut flipped = Vec::new();
+        for &seed in &faces {
+            if flip.contains_key(&seed) {
+                continue;
+            }
+            flip.insert(seed, false);
+            let mut group = vec![seed];
+            let mut closed = true;
+            let mut k = 0;
+            while k < group.len() {
+                let f = group[k];
+                k += 1;
+                for (a, b) in geo.faces[f].edges() {
+                    let shared = topology.edge_faces(a, b);
+                    let neighbour = match shared {
+                        [x, y] if included.contains(x) && included.contains(y) => {
+                            if *x == f {
+                                *y
+                            } else {
+                                *x
+                            }
+                        }
+                        _ => {
+                            closed = false;
+                            continue;
+                        }
+                    };
+                    if flip.contains_key(&neighbour) {
+                        continue;
+                    }
+                    // f walks a->b unless flipped; the neighbour must walk the other way
+                    let forward = geo.faces[neighbour].edges().any(|edge| edge == (a, b));
+                    flip.insert(neighbour, forward ^ flip[&f]);
+                    group.push(neighbour);
+                }
+            }
+
+            let turn_around = if closed {
+                signed_volume(geo, &group, &flip) < 0.0
+            } else {
+                2 * group.iter().filter(|f| flip[f]).count() > group.len()
+            };
+            flipped.extend(group.into_iter().filter(|f| flip[f] != turn_around));
+        }
+        flipped.sort_unstable();
+
+        Ok(Self { faces, flipped })
+    }
+
+    /// Returns the faces taken into account.
+    pub fn faces(&self) -> &[usize] {
+        &self.faces
+    }
+
+    /// Returns the faces whose winding gets reversed.
+    pub fn flipped_faces(&self) -> &[usize] {
+        &self.flipped
+    }
+}
+
+impl Command for RecalculateNormals {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        flip_all(geo, &self.flipped);
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        flip_all(geo, &self.flipped);
+    }
+
+    fn name(&self) -> &'static str {
+        "Recalculate Normals"
+    }
+}
+
+/// Six times the volume enclosed by the faces, with the planned flips
+/// applied; positive when their normals point outward.
+fn signed_volume(geo: &BlockotGeometry, group: &[usize], flip: &HashMap<usize, bool>) -> f32 {
+    group
+        .iter()
+        .map(|f| {
+            let mut face = geo.faces[*f].clone();
+            if flip[f] {
+                face.flip();
+            }
+            let position = |k: usize| geo.vertices[face.vertex_indices[k]];
+            // Fan triangles from the first vertex; outward is (v2 - v0) x (v1 - v0)
+            (1..face.vertex_count().saturating_sub(1))
+                .map(|k| position(0).dot(position(k + 1).cross(position(k))))
+                .sum::<f32>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Face;
+    use crate::test_utils::{quad_grid, unit_cube};
+    use godot::prelude::Vector3;
+
+    fn all_faces(geo: &BlockotGeometry) -> Vec<usize> {
+        (0..geo.faces.len()).collect()
+    }
+
+    fn recalculated(geo: &BlockotGeometry) -> (BlockotGeometry, RecalculateNormals) {
+        let topology = MeshTopology::build(geo);
+        let cmd = RecalculateNormals::new(geo, &topology, all_faces(geo)).unwrap();
+        let mut result = geo.clone();
+        cmd.execute(&mut result);
+        (result, cmd)
+    }
+
+    #[test]
+    fn test_outward_cube_is_left_alone() {
+        let cube = unit_cube();
+        let (result, cmd) = recalculated(&cube);
+
+        assert!(cmd.flipped_faces().is_empty());
+        assert_eq!(result, cube);
+    }
+
+    #[test]
+    fn test_repairs_flipped_faces() {
+        let mut cube = unit_cube();
+        cube.faces[1].flip();
+        cube.faces[4].flip();
+
+        let (result, cmd) = recalculated(&cube);
+
+        assert_eq!(cmd.flipped_faces(), &[1, 4]);
+        assert_eq!(result, unit_cube());
+    }
+
+    #[test]
+    fn test_turns_inside_out_cube_outward() {
+        let mut cube = unit_cube();
+        for face in &mut cube.faces {
+            face.flip();
+        }
+
+        let (result, cmd) = recalculated(&cube);
+
+        assert_eq!(cmd.flipped_faces().len(), 6);
+        assert_eq!(result, unit_cube());
+    }
+
+    #[test]
+    fn test_components_are_oriented_separately() {
+        // An inside-out cube next to a correct one, away from the origin
+        let mut geo = unit_cube();
+        let offset = geo.vertices.len();
+        let shifted: Vec<Vector3> = geo
+            .vertices
+            .iter()
+            .map(|&v| v + Vector3::new(5.0, 2.0, 0.0))
+            .collect();
+        geo.vertices.extend(shifted);
+        let copies: Vec<Face> = geo
+            .faces
+            .iter()
+            .map(|face| {
+                let mut copy = Face::new(face.vertex_indices.iter().map(|&v| v + offset).collect());
+                copy.flip();
+                copy
+            })
+            .collect();
+        geo.faces.extend(copies);
+
+        let (result, cmd) = recalculated(&geo);
+
+        assert_eq!(cmd.flipped_faces(), &[6, 7, 8, 9, 10, 11]);
+        for f in 0..6 {
+            let normal = result.faces[f].normal(&result.vertices);
+            let copy = result.faces[f + 6].normal(&result.vertices);
+            assert!(normal.dot(copy) > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_open_mesh_follows_majority() {
+        let mut grid = quad_grid(3, 1);
+        grid.faces[1].flip();
+
+        let (result, cmd) = recalculated(&grid);
+
+        assert_eq!(cmd.flipped_faces(), &[1]);
+        assert_eq!(result, quad_grid(3, 1));
+    }
+
+    #[test]
+    fn test_recalculate_normals_undo_is_exact() {
+        let mut cube = unit_cube();
+        cube.faces[0].flip();
+        cube.faces[5].flip();
+        let original = cube.clone();
+        let topology = MeshTopology::build(&cube);
+        let cmd = RecalculateNormals::new(&cube, &topology, all_faces(&cube)).unwrap();
+
+        cmd.execute(&mut cube);
+        cmd.undo(&mut cube);
+
+        assert_eq!(cube, original);
+    }
+
+    #[test]
+    fn test_invalid_face() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let result = RecalculateNormals::new(&cube, &topology, vec![0, 9]);
+        assert_eq!(result.unwrap_err(), BlockotError::InvalidFaceIndex(9));
+    }
+
+    #[test]
+    fn test_recalculate_normals_name() {
+        let cube = unit_cube();
+        let topology = MeshTopology::build(&cube);
+        let cmd = RecalculateNormals::new(&cube, &topology, vec![2, 2]).unwrap();
+        assert_eq!(cmd.name(), "Recalculate Normals");
+        assert_eq!(cmd.faces(), &[2]);
+    }
+}
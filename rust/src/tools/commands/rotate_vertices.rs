@@ -0,0 +1,200 @@
+// tools/commands/rotate_vertices.rs - RotateVertices command implementation
+//
+// Rotates selected vertices around a pivot by a rotation Basis.
+// Stores the original positions so undo is exact (no inverse-rotation drift).
+// Validates at construction, execute/undo are infallible.
+
+use godot::prelude::{Basis, Vector3};
+
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command to rotate vertices around a pivot.
+#[derive(Debug, Clone)]
+pub struct RotateVertices {
+    /// Indices of vertices to rotate
+    indices: Vec<usize>,
+    /// Point the rotation is centred on
+    pivot: Vector3,
+    /// Rotation to apply
+    rotation: Basis,
+    /// Positions before the rotation (parallel to `indices`), restored on undo
+    original_positions: Vec<Vector3>,
+}
+
+impl RotateVertices {
+    /// Create a new RotateVertices command, capturing the current positions.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if indices is empty, or
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds.
+    pub fn new(
+        geo: &BlockotGeometry,
+        indices: Vec<usize>,
+        pivot: Vector3,
+        rotation: Basis,
+    ) -> Result<Self, BlockotError> {
+        if indices.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let original_positions = indices
+            .iter()
+            .map(|&idx| {
+                geo.vertices
+                    .get(idx)
+                    .copied()
+                    .ok_or(BlockotError::InvalidVertexIndex(idx))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            indices,
+            pivot,
+            rotation,
+            original_positions,
+        })
+    }
+
+    /// Returns the indices of vertices this command affects.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the pivot of the rotation.
+    pub fn pivot(&self) -> Vector3 {
+        self.pivot
+    }
+
+    /// Returns the rotation applied by this command.
+    pub fn rotation(&self) -> Basis {
+        self.rotation
+    }
+}
+
+impl Command for RotateVertices {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        // Always rotate the stored originals so redo reproduces the same result
+        for (&idx, &original) in self.indices.iter().zip(&self.original_positions) {
+            if let Some(v) = geo.vertices.get_mut(idx) {
+                *v = self.pivot + self.rotation * (original - self.pivot);
+            }
+        }
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        for (&idx, &original) in self.indices.iter().zip(&self.original_positions) {
+            if let Some(v) = geo.vertices.get_mut(idx) {
+                *v = original;
+            }
+        }
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Rotate Vertices"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{geometries_equal, unit_cube, vectors_approx_equal};
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_rotate_vertices_roundtrip() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        // Quarter turn of the top face around +Y through the origin
+        let rotation = Basis::from_axis_angle(Vector3::UP, FRAC_PI_2);
+        let cmd = RotateVertices::new(&geo, vec![4, 5, 6, 7], Vector3::ZERO, rotation).unwrap();
+
+        cmd.execute(&mut geo);
+        // (-0.5, 0.5, -0.5) rotated 90° CCW around Y -> (-0.5, 0.5, 0.5)
+        assert!(vectors_approx_equal(
+            geo.vertices[4],
+            Vector3::new(-0.5, 0.5, 0.5),
+            1e-5
+        ));
+        assert_eq!(geo.vertices[0], original.vertices[0]);
+
+        cmd.undo(&mut geo);
+        assert_eq!(geo, original);
+    }
+
+    #[test]
+    fn test_rotate_vertices_around_pivot() {
+        let mut geo = unit_cube();
+        let pivot = Vector3::new(0.5, -0.5, -0.5); // vertex 1
+        let rotation = Basis::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+
+        let cmd = RotateVertices::new(&geo, vec![0, 1], pivot, rotation).unwrap();
+        cmd.execute(&mut geo);
+
+        // The pivot vertex stays put, vertex 0 swings down around it
+        assert!(vectors_approx_equal(geo.vertices[1], pivot, 1e-5));
+        assert!(vectors_approx_equal(
+            geo.vertices[0],
+            Vector3::new(0.5, -1.5, -0.5),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn test_rotate_vertices_undo_is_exact_after_repeats() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+        let rotation = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.37);
+        let cmd = RotateVertices::new(&geo, vec![0, 2, 5], Vector3::ONE, rotation).unwrap();
+
+        cmd.execute(&mut geo);
+        let executed = geo.clone();
+        for _ in 0..10 {
+            cmd.undo(&mut geo);
+            cmd.execute(&mut geo);
+        }
+        assert_eq!(geo.vertices, executed.vertices);
+
+        cmd.undo(&mut geo);
+        assert_eq!(geo.vertices, original.vertices);
+        assert!(geometries_equal(&geo, &original));
+    }
+
+    #[test]
+    fn test_rotate_vertices_empty_selection() {
+        let geo = unit_cube();
+        let result = RotateVertices::new(&geo, vec![], Vector3::ZERO, Basis::IDENTITY);
+        assert!(matches!(result, Err(BlockotError::EmptySelection)));
+    }
+
+    #[test]
+    fn test_rotate_vertices_invalid_index() {
+        let geo = unit_cube();
+        let result = RotateVertices::new(&geo, vec![0, 100], Vector3::ZERO, Basis::IDENTITY);
+        assert!(matches!(result, Err(BlockotError::InvalidVertexIndex(100))));
+    }
+
+    #[test]
+    fn test_rotate_vertices_sets_dirty_flag() {
+        let mut geo = unit_cube();
+        let cmd = RotateVertices::new(&geo, vec![0], Vector3::ZERO, Basis::IDENTITY).unwrap();
+
+        geo.dirty = false;
+        cmd.execute(&mut geo);
+        assert!(geo.dirty);
+
+        geo.dirty = false;
+        cmd.undo(&mut geo);
+        assert!(geo.dirty);
+    }
+
+    #[test]
+    fn test_rotate_vertices_name() {
+        let geo = unit_cube();
+        let cmd = RotateVertices::new(&geo, vec![0], Vector3::ZERO, Basis::IDENTITY).unwrap();
+        assert_eq!(cmd.name(), "Rotate Vertices");
+    }
+}
@@ -0,0 +1,285 @@
+// tools/commands/scale_vertices.rs - ScaleVertices command implementation
+//
+// Scales selected vertices around a pivot, uniformly or per axis.
+// Stores the original positions so undo is exact (no division by the factor).
+// A negative scale mirrors the geometry; faces made only of scaled vertices
+// get their winding reversed so their normals stay outward.
+// Validates at construction, execute/undo are infallible.
+
+use godot::prelude::{Basis, Vector3};
+
+use crate::error::BlockotError;
+use crate::geometry::BlockotGeometry;
+use crate::tools::Command;
+
+/// Command to scale vertices around a pivot.
+#[derive(Debug, Clone)]
+pub struct ScaleVertices {
+    /// Indices of vertices to scale
+    indices: Vec<usize>,
+    /// Point the scale is centred on
+    pivot: Vector3,
+    /// Scale matrix (diagonal for per-axis scale in local space)
+    scale: Basis,
+    /// Positions before the scale (parallel to `indices`), restored on undo
+    original_positions: Vec<Vector3>,
+    /// Faces whose winding is reversed because the scale mirrors them
+    flipped_faces: Vec<usize>,
+}
+
+impl ScaleVertices {
+    /// Create a new ScaleVertices command scaling along the local axes.
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if indices is empty, or
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds.
+    pub fn new(
+        geo: &BlockotGeometry,
+        indices: Vec<usize>,
+        pivot: Vector3,
+        scale: Vector3,
+    ) -> Result<Self, BlockotError> {
+        Self::with_basis(geo, indices, pivot, Basis::from_scale(scale))
+    }
+
+    /// Create a new ScaleVertices command from a scale matrix.
+    ///
+    /// Use this when the scale axes are not the local axes (e.g. a world-space
+    /// axis constraint on a rotated node).
+    ///
+    /// # Errors
+    /// Returns `BlockotError::EmptySelection` if indices is empty, or
+    /// `BlockotError::InvalidVertexIndex` if any index is out of bounds.
+    pub fn with_basis(
+        geo: &BlockotGeometry,
+        indices: Vec<usize>,
+        pivot: Vector3,
+        scale: Basis,
+    ) -> Result<Self, BlockotError> {
+        if indices.is_empty() {
+            return Err(BlockotError::EmptySelection);
+        }
+        let original_positions = indices
+            .iter()
+            .map(|&idx| {
+                geo.vertices
+                    .get(idx)
+                    .copied()
+                    .ok_or(BlockotError::InvalidVertexIndex(idx))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let flipped_faces = if scale.determinant() < 0.0 {
+            geo.faces
+                .iter()
+                .enumerate()
+                .filter(|(_, face)| face.vertex_indices.iter().all(|v| indices.contains(v)))
+                .map(|(i, _)| i)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            indices,
+            pivot,
+            scale,
+            original_positions,
+            flipped_faces,
+        })
+    }
+
+    /// Returns the indices of vertices this command affects.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the pivot of the scale.
+    pub fn pivot(&self) -> Vector3 {
+        self.pivot
+    }
+
+    /// Returns the scale matrix applied by this command.
+    pub fn scale(&self) -> Basis {
+        self.scale
+    }
+
+    /// Returns the faces whose winding this command reverses (mirroring scales only).
+    pub fn flipped_faces(&self) -> &[usize] {
+        &self.flipped_faces
+    }
+
+    fn flip_faces(&self, geo: &mut BlockotGeometry) {
+        for &face_idx in &self.flipped_faces {
+            if let Some(face) = geo.faces.get_mut(face_idx) {
+                face.flip();
+            }
+        }
+    }
+}
+
+impl Command for ScaleVertices {
+    fn execute(&self, geo: &mut BlockotGeometry) {
+        // Always scale the stored originals so redo reproduces the same result
+        for (&idx, &original) in self.indices.iter().zip(&self.original_positions) {
+            if let Some(v) = geo.vertices.get_mut(idx) {
+                *v = self.pivot + self.scale * (original - self.pivot);
+            }
+        }
+        self.flip_faces(geo);
+        geo.dirty = true;
+    }
+
+    fn undo(&self, geo: &mut BlockotGeometry) {
+        for (&idx, &original) in self.indices.iter().zip(&self.original_positions) {
+            if let Some(v) = geo.vertices.get_mut(idx) {
+                *v = original;
+            }
+        }
+        // Reversing twice restores the original order exactly
+        self.flip_faces(geo);
+        geo.dirty = true;
+    }
+
+    fn name(&self) -> &'static str {
+        "Scale Vertices"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{unit_cube, vectors_approx_equal};
+
+    #[test]
+    fn test_scale_vertices_roundtrip() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        let all: Vec<usize> = (0..8).collect();
+        let cmd = ScaleVertices::new(&geo, all, Vector3::ZERO, Vector3::splat(2.0)).unwrap();
+
+        cmd.execute(&mut geo);
+        assert_eq!(geo.vertices[0], Vector3::new(-1.0, -1.0, -1.0));
+        assert_eq!(geo.vertices[6], Vector3::new(1.0, 1.0, 1.0));
+
+        cmd.undo(&mut geo);
+        assert_eq!(geo, original);
+    }
+
+    #[test]
+    fn test_scale_vertices_per_axis_around_pivot() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+        let pivot = Vector3::new(0.0, -0.5, 0.0);
+
+        // Stretch the top face up only
+        let cmd =
+            ScaleVertices::new(&geo, vec![4, 5, 6, 7], pivot, Vector3::new(1.0, 3.0, 1.0)).unwrap();
+        cmd.execute(&mut geo);
+
+        assert!(vectors_approx_equal(
+            geo.vertices[4],
+            Vector3::new(-0.5, 2.5, -0.5),
+            1e-5
+        ));
+        assert_eq!(geo.vertices[0], original.vertices[0]);
+    }
+
+    #[test]
+    fn test_scale_vertices_undo_is_exact() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+
+        // 1/3 is not exactly representable: dividing back would drift
+        let cmd = ScaleVertices::new(
+            &geo,
+            vec![1, 2, 3],
+            Vector3::new(0.1, 0.2, 0.3),
+            Vector3::splat(1.0 / 3.0),
+        )
+        .unwrap();
+
+        for _ in 0..10 {
+            cmd.execute(&mut geo);
+            cmd.undo(&mut geo);
+        }
+        assert_eq!(geo.vertices, original.vertices);
+    }
+
+    #[test]
+    fn test_negative_scale_flips_winding() {
+        let mut geo = unit_cube();
+        let original = geo.clone();
+        let all: Vec<usize> = (0..8).collect();
+
+        let cmd =
+            ScaleVertices::new(&geo, all, Vector3::ZERO, Vector3::new(-1.0, 1.0, 1.0)).unwrap();
+        assert_eq!(cmd.flipped_faces().len(), 6);
+
+        cmd.execute(&mut geo);
+        // Mirrored across X, the front face must still face -Z (outward)
+        let face = &geo.faces[0].vertex_indices;
+        let (v0, v1, v2) = (
+            geo.vertices[face[0]],
+            geo.vertices[face[1]],
+            geo.vertices[face[2]],
+        );
+        let normal = (v2 - v0).cross(v1 - v0);
+        assert!(normal.z < 0.0);
+        // Flipped like FlipFaces: the first vertex stays first
+        assert_eq!(face[0], original.faces[0].vertex_indices[0]);
+
+        cmd.undo(&mut geo);
+        assert_eq!(geo, original);
+        assert_eq!(
+            geo.faces[0].vertex_indices,
+            original.faces[0].vertex_indices
+        );
+    }
+
+    #[test]
+    fn test_negative_scale_only_flips_fully_selected_faces() {
+        let geo = unit_cube();
+        // Top face vertices only: just the top face is fully selected
+        let cmd = ScaleVertices::new(
+            &geo,
+            vec![4, 5, 6, 7],
+            Vector3::ZERO,
+            Vector3::new(1.0, -1.0, 1.0),
+        )
+        .unwrap();
+        assert_eq!(cmd.flipped_faces(), &[2]);
+
+        // Even number of negative axes is a rotation, not a mirror
+        let cmd = ScaleVertices::new(
+            &geo,
+            vec![4, 5, 6, 7],
+            Vector3::ZERO,
+            Vector3::new(-1.0, -1.0, 1.0),
+        )
+        .unwrap();
+        assert!(cmd.flipped_faces().is_empty());
+    }
+
+    #[test]
+    fn test_scale_vertices_empty_selection() {
+        let geo = unit_cube();
+        let result = ScaleVertices::new(&geo, vec![], Vector3::ZERO, Vector3::ONE);
+        assert!(matches!(result, Err(BlockotError::EmptySelection)));
+    }
+
+    #[test]
+    fn test_scale_vertices_invalid_index() {
+        let geo = unit_cube();
+        let result = ScaleVertices::new(&geo, vec![8], Vector3::ZERO, Vector3::ONE);
+        assert!(matches!(result, Err(BlockotError::InvalidVertexIndex(8))));
+    }
+
+    #[test]
+    fn test_scale_vertices_name() {
+        let geo = unit_cube();
+        let cmd = ScaleVertices::new(&geo, vec![0], Vector3::ZERO, Vector3::ONE).unwrap();
+        assert_eq!(cmd.name(), "Scale Vertices");
+    }
+}
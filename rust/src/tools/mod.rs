@@ -4,17 +4,27 @@
 // Commands validate at construction, execute/undo are infallible.
 
 pub mod commands;
+pub mod modal;
+pub mod orientation;
+pub mod pivot;
+pub mod proximity;
+pub mod snap;
 
 use crate::geometry::BlockotGeometry;
 
 /// Trait for reversible geometry commands.
 ///
+/// The trait is object-safe so heterogeneous commands can be stored together
+/// as `Box<dyn Command>` (undo registry, composite commands, macros).
+/// Cloning goes through `CommandClone::box_clone`, which is implemented
+/// automatically for every `Command + Clone` type.
+///
 /// # Rules
 /// 1. Validate at construction (`new()` returns `Result<Self, BlockotError>`)
 /// 2. `execute()` and `undo()` are INFALLIBLE - all validation happens in constructor
 /// 3. Commands NEVER trigger cache rebuild - they only mutate `BlockotGeometry`
 /// 4. Store inverse transform for exact undo (not negated delta)
-pub trait Command: Clone + Send + Sync {
+pub trait Command: CommandClone + Send + Sync {
     /// Execute the command on the geometry.
     /// This is infallible - validation happens at construction.
     fn execute(&self, geo: &mut BlockotGeometry);
@@ -26,3 +36,23 @@ pub trait Command: Clone + Send + Sync {
     /// Returns a human-readable name for this command (for undo menu).
     fn name(&self) -> &'static str;
 }
+
+/// Object-safe cloning for boxed commands.
+///
+/// Do not implement this by hand: derive `Clone` on the command instead.
+pub trait CommandClone {
+    /// Clone this command into a new box.
+    fn box_clone(&self) -> Box<dyn Command>;
+}
+
+impl<T: Command + Clone + 'static> CommandClone for T {
+    fn box_clone(&self) -> Box<dyn Command> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Command> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
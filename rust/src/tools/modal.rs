@@ -0,0 +1,629 @@
+// tools/modal.rs - Pure state for modal transform tools (G/R/S)
+//
+// Holds what the user has chosen while a modal tool runs: axis constraint,
+// typed numeric value and the current mouse-driven amount. The editor turns
+// mouse motion into world-space amounts; this module turns those into the
+// final transform. Constraint axes follow the tool's orientation basis (see
+// orientation.rs); the identity basis means world axes. Dragged amounts snap
+// when the editor sets a snap step (see snap.rs); typed values never snap.
+// Pure Rust - no Godot types except the Vector3 and Basis math types.
+
+use std::f32::consts::{PI, TAU};
+
+use godot::prelude::{Basis, Vector3};
+
+use super::snap::{snap_to_step, snap_translation};
+
+/// A principal axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    /// Unit vector along this axis.
+    pub fn vector(self) -> Vector3 {
+        match self {
+            Axis::X => Vector3::new(1.0, 0.0, 0.0),
+            Axis::Y => Vector3::new(0.0, 1.0, 0.0),
+            Axis::Z => Vector3::new(0.0, 0.0, 1.0),
+        }
+    }
+}
+
+/// Axis constraint chosen with X/Y/Z while a modal tool runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AxisConstraint {
+    /// No constraint (move in the view plane)
+    #[default]
+    Free,
+    /// Restrict to a single axis
+    Axis(Axis),
+    /// Restrict to the plane of the other two axes (Shift+X/Y/Z)
+    Exclude(Axis),
+}
+
+impl AxisConstraint {
+    /// Press an axis key: selects that axis, or frees it when pressed again.
+    pub fn toggled(self, axis: Axis) -> Self {
+        if self == AxisConstraint::Axis(axis) {
+            AxisConstraint::Free
+        } else {
+            AxisConstraint::Axis(axis)
+        }
+    }
+
+    /// Press Shift + an axis key: excludes that axis, or frees it when pressed again.
+    pub fn toggled_exclude(self, axis: Axis) -> Self {
+        if self == AxisConstraint::Exclude(axis) {
+            AxisConstraint::Free
+        } else {
+            AxisConstraint::Exclude(axis)
+        }
+    }
+
+    /// Axis a typed value applies to: the locked axis, else the first free one.
+    pub fn primary_axis(self) -> Axis {
+        match self {
+            AxisConstraint::Axis(axis) => axis,
+            AxisConstraint::Exclude(Axis::X) => Axis::Y,
+            AxisConstraint::Free | AxisConstraint::Exclude(_) => Axis::X,
+        }
+    }
+
+    /// Constrain a vector: keep only its component along the locked axis,
+    /// or drop its component along the excluded one.
+    pub fn apply(self, v: Vector3) -> Vector3 {
+        match self {
+            AxisConstraint::Free => v,
+            AxisConstraint::Axis(axis) => {
+                let dir = axis.vector();
+                dir * v.dot(dir)
+            }
+            AxisConstraint::Exclude(axis) => {
+                let dir = axis.vector();
+                v - dir * v.dot(dir)
+            }
+        }
+    }
+
+    /// Constrain a world-space vector along the axes of an orthonormal
+    /// `orientation` basis (columns are its X, Y and Z axes).
+    pub fn apply_in(self, v: Vector3, orientation: Basis) -> Vector3 {
+        orientation * self.apply(orientation.transposed() * v)
+    }
+}
+
+/// Typed numeric value entered while a modal tool runs (e.g. `G X 2.5`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NumericInput {
+    /// Digits and decimal point typed so far
+    text: String,
+    /// Sign toggled with '-'
+    negative: bool,
+}
+
+impl NumericInput {
+    /// Feed a typed character. Digits and one '.' are appended; '-' toggles the sign.
+    /// Returns false if the character is not part of a number.
+    pub fn push(&mut self, c: char) -> bool {
+        match c {
+            '0'..='9' => self.text.push(c),
+            '.' if !self.text.contains('.') => self.text.push(c),
+            '-' => self.negative = !self.negative,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Remove the last typed character (Backspace).
+    pub fn backspace(&mut self) {
+        if self.text.pop().is_none() {
+            self.negative = false;
+        }
+    }
+
+    /// Returns true if nothing has been typed.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && !self.negative
+    }
+
+    /// The typed value, or None until a digit has been entered.
+    pub fn value(&self) -> Option<f32> {
+        let magnitude: f32 = self.text.parse().ok()?;
+        Some(if self.negative { -magnitude } else { magnitude })
+    }
+
+    /// Display text, e.g. "-2.5".
+    pub fn text(&self) -> String {
+        let sign = if self.negative { "-" } else { "" };
+        format!("{sign}{}", self.text)
+    }
+}
+
+/// Modal move (G): translation from mouse drag or typed distance.
+#[derive(Debug, Clone, Default)]
+pub struct MoveTool {
+    /// Axis constraint (X/Y/Z keys)
+    pub constraint: AxisConstraint,
+    /// Orthonormal basis the constraint axes follow
+    pub orientation: Basis,
+    /// Grid size while snapping is active
+    pub snap: Option<f32>,
+    /// Pivot in world space before the move; snapping puts it on the grid
+    pub anchor: Vector3,
+    /// World position to move the anchor onto (proximity snapping), overrides the grid
+    pub target: Option<Vector3>,
+    /// Typed distance, overrides the mouse when present
+    pub numeric: NumericInput,
+    /// Unconstrained mouse-driven offset in the view plane
+    drag_offset: Vector3,
+}
+
+impl MoveTool {
+    /// Create a move tool with no offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the offset dragged with the mouse (before constraints).
+    pub fn set_drag_offset(&mut self, offset: Vector3) {
+        self.drag_offset = offset;
+    }
+
+    /// The translation to apply.
+    ///
+    /// A typed distance moves along the constraint's primary axis (X when free).
+    /// A snap target moves the anchor onto it, as far as the constraint allows.
+    /// Otherwise the drag offset is projected onto the constraint and, while
+    /// snapping, adjusted so the anchor lands on the grid.
+    pub fn offset(&self) -> Vector3 {
+        if let Some(distance) = self.numeric.value() {
+            return self.orientation * (self.constraint.primary_axis().vector() * distance);
+        }
+        if let Some(target) = self.target {
+            return self
+                .constraint
+                .apply_in(target - self.anchor, self.orientation);
+        }
+        let offset = self.constraint.apply_in(self.drag_offset, self.orientation);
+        match self.snap {
+            Some(grid_size) => snap_translation(
+                self.anchor,
+                offset,
+                grid_size,
+                self.constraint,
+                self.orientation,
+            ),
+            None => offset,
+        }
+    }
+}
+
+/// Modal rotate (R): rotation around the pivot from mouse drag or typed degrees.
+#[derive(Debug, Clone, Default)]
+pub struct RotateTool {
+    /// Axis constraint (X/Y/Z keys); free rotates around the view axis
+    pub constraint: AxisConstraint,
+    /// Orthonormal basis the constraint axes follow
+    pub orientation: Basis,
+    /// Typed angle in degrees, overrides the mouse when present
+    pub numeric: NumericInput,
+    /// Angle increment in degrees while snapping is active
+    pub snap: Option<f32>,
+    /// Accumulated drag angle in radians, counter-clockwise on screen
+    drag_angle: f32,
+    /// Screen angle of the previous drag sample
+    last_screen_angle: Option<f32>,
+}
+
+impl RotateTool {
+    /// Create a rotate tool with no rotation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the mouse's angle around the pivot on screen (radians, counter-clockwise).
+    ///
+    /// Angles are accumulated across samples, so dragging more than a full turn
+    /// keeps rotating instead of wrapping back.
+    pub fn drag_to(&mut self, screen_angle: f32) {
+        if let Some(last) = self.last_screen_angle {
+            let mut delta = screen_angle - last;
+            if delta > PI {
+                delta -= TAU;
+            } else if delta < -PI {
+                delta += TAU;
+            }
+            self.drag_angle += delta;
+        }
+        self.last_screen_angle = Some(screen_angle);
+    }
+
+    /// The rotation angle in radians (typed degrees, or the possibly snapped drag).
+    pub fn angle(&self) -> f32 {
+        if let Some(degrees) = self.numeric.value() {
+            return degrees.to_radians();
+        }
+        match self.snap {
+            Some(step_degrees) => snap_to_step(self.drag_angle, step_degrees.to_radians()),
+            None => self.drag_angle,
+        }
+    }
+
+    /// The rotation axis: the locked axis, or `view_axis` when free.
+    /// Excluding an axis rotates in the plane of the other two, i.e. around it.
+    pub fn axis(&self, view_axis: Vector3) -> Vector3 {
+        match self.constraint {
+            AxisConstraint::Axis(axis) | AxisConstraint::Exclude(axis) => {
+                self.orientation * axis.vector()
+            }
+            AxisConstraint::Free => view_axis,
+        }
+    }
+
+    /// The rotation to apply. `view_axis` is a unit vector pointing at the viewer.
+    ///
+    /// Dragged rotations always follow the mouse on screen, so the angle is
+    /// flipped when the locked axis points away from the viewer. Typed angles
+    /// are used as-is (right-handed around the axis).
+    pub fn rotation(&self, view_axis: Vector3) -> Basis {
+        let axis = self.axis(view_axis);
+        let mut angle = self.angle();
+        if self.numeric.value().is_none() && axis.dot(view_axis) < 0.0 {
+            angle = -angle;
+        }
+        Basis::from_axis_angle(axis, angle)
+    }
+}
+
+/// Modal scale (S): scale factor from mouse distance to the pivot or typed value.
+#[derive(Debug, Clone)]
+pub struct ScaleTool {
+    /// Axis constraint: X/Y/Z scales one axis, Shift+X/Y/Z all but one
+    pub constraint: AxisConstraint,
+    /// Orthonormal basis the constraint axes follow
+    pub orientation: Basis,
+    /// Factor increment while snapping is active
+    pub snap: Option<f32>,
+    /// Typed factor, overrides the mouse when present (negative mirrors)
+    pub numeric: NumericInput,
+    /// Mouse-driven factor
+    drag_factor: f32,
+}
+
+impl Default for ScaleTool {
+    fn default() -> Self {
+        Self {
+            constraint: AxisConstraint::Free,
+            orientation: Basis::IDENTITY,
+            snap: None,
+            numeric: NumericInput::default(),
+            drag_factor: 1.0,
+        }
+    }
+}
+
+impl ScaleTool {
+    /// Create a scale tool with factor 1.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the factor dragged with the mouse.
+    pub fn set_drag_factor(&mut self, factor: f32) {
+        self.drag_factor = factor;
+    }
+
+    /// The scale factor (typed, or dragged and possibly snapped). A snapped
+    /// factor stays at least one step away from 0, on the dragged side, so
+    /// snapping never flattens the selection.
+    pub fn factor(&self) -> f32 {
+        if let Some(factor) = self.numeric.value() {
+            return factor;
+        }
+        match self.snap {
+            Some(step) => {
+                let snapped = snap_to_step(self.drag_factor, step);
+                if snapped == 0.0 {
+                    step.copysign(self.drag_factor)
+                } else {
+                    snapped
+                }
+            }
+            None => self.drag_factor,
+        }
+    }
+
+    /// Per-axis scale in the orientation's frame: the factor on the
+    /// constrained axes, 1 elsewhere.
+    pub fn scale(&self) -> Vector3 {
+        let f = self.factor();
+        match self.constraint {
+            AxisConstraint::Free => Vector3::splat(f),
+            AxisConstraint::Axis(axis) => Vector3::ONE + axis.vector() * (f - 1.0),
+            AxisConstraint::Exclude(axis) => Vector3::splat(f) + axis.vector() * (1.0 - f),
+        }
+    }
+
+    /// The scale as a world-space basis: `scale()` applied along the orientation's axes.
+    pub fn scale_basis(&self) -> Basis {
+        self.orientation * Basis::from_scale(self.scale()) * self.orientation.transposed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constraint_toggle() {
+        let c = AxisConstraint::Free.toggled(Axis::X);
+        assert_eq!(c, AxisConstraint::Axis(Axis::X));
+        assert_eq!(c.toggled(Axis::Y), AxisConstraint::Axis(Axis::Y));
+        assert_eq!(c.toggled(Axis::X), AxisConstraint::Free);
+    }
+
+    #[test]
+    fn test_constraint_apply_projects_onto_axis() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(AxisConstraint::Free.apply(v), v);
+        assert_eq!(
+            AxisConstraint::Axis(Axis::Y).apply(v),
+            Vector3::new(0.0, 2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_numeric_input() {
+        let mut input = NumericInput::default();
+        assert_eq!(input.value(), None);
+
+        for c in "2.5".chars() {
+            assert!(input.push(c));
+        }
+        assert!(!input.push('.')); // Second '.' is ignored
+        assert!(!input.push('x'));
+        assert_eq!(input.value(), Some(2.5));
+
+        input.push('-');
+        assert_eq!(input.value(), Some(-2.5));
+        assert_eq!(input.text(), "-2.5");
+
+        input.backspace();
+        assert_eq!(input.value(), Some(-2.0));
+    }
+
+    #[test]
+    fn test_numeric_input_sign_only_has_no_value() {
+        let mut input = NumericInput::default();
+        input.push('-');
+        assert!(!input.is_empty());
+        assert_eq!(input.value(), None);
+
+        input.backspace();
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_move_tool_drag_with_constraint() {
+        let mut tool = MoveTool::new();
+        tool.set_drag_offset(Vector3::new(1.0, 2.0, 0.5));
+        assert_eq!(tool.offset(), Vector3::new(1.0, 2.0, 0.5));
+
+        tool.constraint = AxisConstraint::Axis(Axis::Z);
+        assert_eq!(tool.offset(), Vector3::new(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn test_move_tool_typed_distance_overrides_drag() {
+        let mut tool = MoveTool::new();
+        tool.set_drag_offset(Vector3::new(1.0, 2.0, 0.5));
+        tool.constraint = AxisConstraint::Axis(Axis::Y);
+        tool.numeric.push('3');
+
+        assert_eq!(tool.offset(), Vector3::new(0.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_move_tool_typed_distance_defaults_to_x() {
+        let mut tool = MoveTool::new();
+        tool.numeric.push('2');
+        assert_eq!(tool.offset(), Vector3::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_rotate_tool_accumulates_past_half_turn() {
+        let mut tool = RotateTool::new();
+        tool.drag_to(3.0);
+        tool.drag_to(-3.0); // Crossed the ±π seam: +0.283 rad, not -6 rad
+        assert!((tool.angle() - (TAU - 6.0)).abs() < 1e-5);
+
+        tool.drag_to(3.0);
+        assert!(tool.angle().abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_rotate_tool_snapping() {
+        let mut tool = RotateTool::new();
+        tool.drag_to(0.0);
+        tool.drag_to(20f32.to_radians());
+
+        tool.snap = Some(15.0);
+        assert!((tool.angle() - 15f32.to_radians()).abs() < 1e-5);
+        tool.snap = None;
+        assert!((tool.angle() - 20f32.to_radians()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_rotate_tool_typed_degrees() {
+        let mut tool = RotateTool::new();
+        tool.drag_to(0.0);
+        tool.drag_to(1.0);
+        tool.constraint = AxisConstraint::Axis(Axis::Z);
+        for c in "90".chars() {
+            tool.numeric.push(c);
+        }
+
+        let rotated = tool.rotation(Vector3::new(0.0, 0.0, -1.0)) * Vector3::new(1.0, 0.0, 0.0);
+        assert!((rotated - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_rotate_tool_drag_follows_screen_for_reversed_axis() {
+        let mut tool = RotateTool::new();
+        tool.drag_to(0.0);
+        tool.drag_to(0.5);
+        let p = Vector3::new(1.0, 0.0, 0.0);
+
+        for view_axis in [Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, -1.0)] {
+            tool.constraint = AxisConstraint::Free;
+            let free = tool.rotation(view_axis) * p;
+            // Locking Z gives the same on-screen rotation whichever side we look from
+            tool.constraint = AxisConstraint::Axis(Axis::Z);
+            let locked = tool.rotation(view_axis) * p;
+            assert!((free - locked).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_exclude_constraint() {
+        let c = AxisConstraint::Axis(Axis::X).toggled_exclude(Axis::Z);
+        assert_eq!(c, AxisConstraint::Exclude(Axis::Z));
+        assert_eq!(c.toggled_exclude(Axis::Z), AxisConstraint::Free);
+        assert_eq!(
+            c.apply(Vector3::new(1.0, 2.0, 3.0)),
+            Vector3::new(1.0, 2.0, 0.0)
+        );
+        assert_eq!(AxisConstraint::Exclude(Axis::X).primary_axis(), Axis::Y);
+    }
+
+    #[test]
+    fn test_scale_tool_constraints() {
+        let mut tool = ScaleTool::new();
+        assert_eq!(tool.scale(), Vector3::ONE);
+
+        tool.set_drag_factor(2.0);
+        assert_eq!(tool.scale(), Vector3::splat(2.0));
+
+        tool.constraint = AxisConstraint::Axis(Axis::Y);
+        assert_eq!(tool.scale(), Vector3::new(1.0, 2.0, 1.0));
+
+        tool.constraint = AxisConstraint::Exclude(Axis::Y);
+        assert_eq!(tool.scale(), Vector3::new(2.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn test_scale_tool_typed_negative_factor() {
+        let mut tool = ScaleTool::new();
+        tool.set_drag_factor(3.0);
+        tool.constraint = AxisConstraint::Axis(Axis::X);
+        tool.numeric.push('-');
+        tool.numeric.push('1');
+
+        assert_eq!(tool.factor(), -1.0);
+        assert_eq!(tool.scale(), Vector3::new(-1.0, 1.0, 1.0));
+    }
+
+    /// Orientation whose X points along world -Z, Y stays up, Z along world +X.
+    fn quarter_turn() -> Basis {
+        Basis::from_axis_angle(Vector3::UP, std::f32::consts::FRAC_PI_2)
+    }
+
+    fn approx(a: Vector3, b: Vector3) -> bool {
+        (a - b).length() < 1e-5
+    }
+
+    #[test]
+    fn test_move_tool_constraint_follows_orientation() {
+        let mut tool = MoveTool::new();
+        tool.orientation = quarter_turn();
+        tool.constraint = AxisConstraint::Axis(Axis::X);
+        tool.set_drag_offset(Vector3::new(1.0, 2.0, -3.0));
+
+        // Orientation X is world -Z: only the world Z part survives
+        assert!(approx(tool.offset(), Vector3::new(0.0, 0.0, -3.0)));
+
+        tool.numeric.push('2');
+        assert!(approx(tool.offset(), Vector3::new(0.0, 0.0, -2.0)));
+    }
+
+    #[test]
+    fn test_rotate_tool_axis_follows_orientation() {
+        let mut tool = RotateTool::new();
+        tool.orientation = quarter_turn();
+        tool.constraint = AxisConstraint::Axis(Axis::Z);
+
+        assert!(approx(tool.axis(Vector3::BACK), Vector3::RIGHT));
+    }
+
+    #[test]
+    fn test_scale_basis_follows_orientation() {
+        let mut tool = ScaleTool::new();
+        tool.orientation = quarter_turn();
+        tool.constraint = AxisConstraint::Axis(Axis::X);
+        tool.set_drag_factor(2.0);
+
+        let basis = tool.scale_basis();
+        assert!(approx(basis * Vector3::BACK, Vector3::new(0.0, 0.0, 2.0)));
+        assert!(approx(basis * Vector3::RIGHT, Vector3::RIGHT));
+        assert!(approx(basis * Vector3::UP, Vector3::UP));
+    }
+
+    #[test]
+    fn test_move_tool_snaps_anchor_to_grid() {
+        let mut tool = MoveTool::new();
+        tool.anchor = Vector3::new(0.5, 0.0, 0.0);
+        tool.set_drag_offset(Vector3::new(1.2, 0.3, 0.0));
+
+        tool.snap = Some(1.0);
+        assert!(approx(
+            tool.anchor + tool.offset(),
+            Vector3::new(2.0, 0.0, 0.0)
+        ));
+
+        // Typed distances are exact
+        tool.numeric.push('1');
+        assert!(approx(tool.offset(), Vector3::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_scale_tool_snapping() {
+        let mut tool = ScaleTool::new();
+        tool.set_drag_factor(1.34);
+        tool.snap = Some(0.1);
+        assert!((tool.factor() - 1.3).abs() < 1e-5);
+
+        tool.snap = None;
+        assert!((tool.factor() - 1.34).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_scale_tool_snapping_skips_zero() {
+        let mut tool = ScaleTool::new();
+        tool.snap = Some(0.5);
+        tool.set_drag_factor(0.1);
+        assert_eq!(tool.factor(), 0.5);
+        tool.set_drag_factor(-0.1);
+        assert_eq!(tool.factor(), -0.5);
+        tool.set_drag_factor(-0.3);
+        assert_eq!(tool.factor(), -0.5);
+    }
+
+    #[test]
+    fn test_move_tool_snap_target_overrides_grid() {
+        let mut tool = MoveTool::new();
+        tool.anchor = Vector3::new(0.5, 0.0, 0.0);
+        tool.snap = Some(1.0);
+        tool.set_drag_offset(Vector3::new(1.2, 0.0, 0.0));
+        tool.target = Some(Vector3::new(1.75, 0.25, 0.0));
+
+        assert!(approx(tool.offset(), Vector3::new(1.25, 0.25, 0.0)));
+
+        // Constraints still apply
+        tool.constraint = AxisConstraint::Axis(Axis::X);
+        assert!(approx(tool.offset(), Vector3::new(1.25, 0.0, 0.0)));
+    }
+}
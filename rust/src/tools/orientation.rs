@@ -0,0 +1,245 @@
+// tools/orientation.rs - Transform orientations for axis constraints
+//
+// Decides which X/Y/Z axes a constrained move/rotate/scale follows: the world
+// axes, the node's local basis, the averaged normal of the selection, or the
+// camera view. An orientation is an orthonormal world-space basis whose
+// columns are its X, Y and Z axes, so world <-> orientation conversion is a
+// multiply by the basis or its transpose.
+// Pure Rust - no Godot types except math types.
+
+use godot::prelude::{Basis, Vector3};
+
+use crate::geometry::BlockotGeometry;
+use crate::selection::Selection;
+
+/// Which axes X/Y/Z constraints follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformOrientation {
+    /// World axes
+    #[default]
+    Global,
+    /// The node's own axes (scale removed)
+    Local,
+    /// Z along the averaged normal of the selected faces
+    Normal,
+    /// X right, Y up, Z towards the viewer
+    View,
+}
+
+impl TransformOrientation {
+    /// The next orientation, for cycling through them with a hotkey.
+    pub fn next(self) -> Self {
+        match self {
+            TransformOrientation::Global => TransformOrientation::Local,
+            TransformOrientation::Local => TransformOrientation::Normal,
+            TransformOrientation::Normal => TransformOrientation::View,
+            TransformOrientation::View => TransformOrientation::Global,
+        }
+    }
+}
+
+/// Build the orientation basis in world space.
+///
+/// `node_basis` is the node's global basis, `view_basis` the camera's and
+/// `normal` the selection normal in world space. Falls back to the world
+/// axes when the requested frame is degenerate or there is no normal.
+pub fn orientation_basis(
+    orientation: TransformOrientation,
+    node_basis: Basis,
+    view_basis: Basis,
+    normal: Option<Vector3>,
+) -> Basis {
+    let basis = match orientation {
+        TransformOrientation::Global => None,
+        TransformOrientation::Local => orthonormalize(node_basis),
+        TransformOrientation::Normal => normal.and_then(basis_from_normal),
+        TransformOrientation::View => orthonormalize(view_basis),
+    };
+    basis.unwrap_or(Basis::IDENTITY)
+}
+
+/// Averaged unit normal of the selection in local space.
+///
+/// Uses the selected faces; when none are selected, the faces touching a
+/// selected vertex. Normals are computed like the rendered flat normals.
+/// Returns None if there are no such faces or their normals cancel out.
+pub fn selection_normal(selection: &Selection, geo: &BlockotGeometry) -> Option<Vector3> {
+    let faces: Vec<usize> = if selection.selected_faces.is_empty() {
+        geo.faces
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| {
+                face.vertex_indices
+                    .iter()
+                    .any(|v| selection.vertex_indices.contains(v))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    } else {
+        selection.selected_faces.clone()
+    };
+
+    let sum = faces
+        .iter()
+        .filter_map(|&i| geo.faces.get(i)?.try_normal(&geo.vertices))
+        .fold(Vector3::ZERO, |acc, n| acc + n);
+    let length = sum.length();
+    if length < 1e-5 {
+        return None;
+    }
+    Some(sum / length)
+}
+
+/// Basis with Z along `normal` and X kept horizontal where possible.
+fn basis_from_normal(normal: Vector3) -> Option<Basis> {
+    let z = unit(normal)?;
+    // Cross with up gives a horizontal X, unless the normal is (nearly) vertical
+    let reference = if z.y.abs() > 0.999 {
+        Vector3::new(0.0, 0.0, -z.y)
+    } else {
+        Vector3::UP
+    };
+    let x = reference.cross(z).normalized();
+    let y = z.cross(x);
+    Some(Basis::from_cols(x, y, z))
+}
+
+/// Gram-Schmidt: unit, mutually perpendicular axes in the same directions.
+fn orthonormalize(basis: Basis) -> Option<Basis> {
+    let a = basis.col_a();
+    let b = basis.col_b();
+    let c = basis.col_c();
+
+    let x = unit(a)?;
+    let y = unit(b - x * b.dot(x))?;
+    let z = unit(c - x * c.dot(x) - y * c.dot(y))?;
+    Some(Basis::from_cols(x, y, z))
+}
+
+/// Unit vector in the direction of `v`, or None if it is (nearly) zero.
+fn unit(v: Vector3) -> Option<Vector3> {
+    let length = v.length();
+    (length > 1e-6).then(|| v / length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::{SelectOp, SelectionMode};
+    use crate::test_utils::{quad_grid, unit_cube, vectors_approx_equal};
+
+    fn assert_orthonormal(basis: Basis) {
+        let product = basis.transposed() * basis;
+        assert!(vectors_approx_equal(product.col_a(), Vector3::RIGHT, 1e-5));
+        assert!(vectors_approx_equal(product.col_b(), Vector3::UP, 1e-5));
+        assert!(vectors_approx_equal(product.col_c(), Vector3::BACK, 1e-5));
+    }
+
+    #[test]
+    fn test_orientation_cycle() {
+        let mut orientation = TransformOrientation::default();
+        assert_eq!(orientation, TransformOrientation::Global);
+        for _ in 0..4 {
+            orientation = orientation.next();
+        }
+        assert_eq!(orientation, TransformOrientation::Global);
+    }
+
+    #[test]
+    fn test_global_ignores_node_and_view() {
+        let rotated = Basis::from_axis_angle(Vector3::UP, 0.5);
+        let basis = orientation_basis(
+            TransformOrientation::Global,
+            rotated,
+            rotated,
+            Some(Vector3::RIGHT),
+        );
+        assert_eq!(basis, Basis::IDENTITY);
+    }
+
+    #[test]
+    fn test_local_removes_scale() {
+        let rotation = Basis::from_axis_angle(Vector3::UP, 0.5);
+        let node_basis = rotation * Basis::from_scale(Vector3::new(2.0, 3.0, 0.5));
+
+        let basis = orientation_basis(
+            TransformOrientation::Local,
+            node_basis,
+            Basis::IDENTITY,
+            None,
+        );
+
+        assert_orthonormal(basis);
+        assert!(vectors_approx_equal(basis.col_a(), rotation.col_a(), 1e-5));
+        assert!(vectors_approx_equal(basis.col_c(), rotation.col_c(), 1e-5));
+    }
+
+    #[test]
+    fn test_normal_orientation_z_follows_normal() {
+        let normal = Vector3::new(1.0, 1.0, 0.0).normalized();
+        let basis = orientation_basis(
+            TransformOrientation::Normal,
+            Basis::IDENTITY,
+            Basis::IDENTITY,
+            Some(normal),
+        );
+
+        assert_orthonormal(basis);
+        assert!(vectors_approx_equal(basis.col_c(), normal, 1e-5));
+        // X stays horizontal
+        assert!(basis.col_a().y.abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_normal_orientation_vertical_normal() {
+        let basis = orientation_basis(
+            TransformOrientation::Normal,
+            Basis::IDENTITY,
+            Basis::IDENTITY,
+            Some(Vector3::UP),
+        );
+
+        assert_orthonormal(basis);
+        assert!(vectors_approx_equal(basis.col_c(), Vector3::UP, 1e-5));
+    }
+
+    #[test]
+    fn test_normal_orientation_without_normal_is_global() {
+        let basis = orientation_basis(
+            TransformOrientation::Normal,
+            Basis::IDENTITY,
+            Basis::IDENTITY,
+            None,
+        );
+        assert_eq!(basis, Basis::IDENTITY);
+    }
+
+    #[test]
+    fn test_selection_normal_from_selected_faces() {
+        let grid = quad_grid(2, 1);
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_face(0, &grid, SelectOp::Replace);
+
+        let normal = selection_normal(&sel, &grid).unwrap();
+        assert!(vectors_approx_equal(normal, Vector3::UP, 1e-5));
+    }
+
+    #[test]
+    fn test_selection_normal_falls_back_to_touching_faces() {
+        let cube = unit_cube();
+        let mut sel = Selection::new(SelectionMode::Vertex);
+        sel.pick_vertex(0, SelectOp::Replace);
+
+        // A corner touches three perpendicular faces; their average points out diagonally
+        let normal = selection_normal(&sel, &cube).unwrap();
+        let corner = cube.vertices[0].normalized();
+        assert!(vectors_approx_equal(normal, corner, 1e-5));
+    }
+
+    #[test]
+    fn test_selection_normal_empty() {
+        let cube = unit_cube();
+        let sel = Selection::new(SelectionMode::Vertex);
+        assert_eq!(selection_normal(&sel, &cube), None);
+    }
+}
@@ -0,0 +1,272 @@
+// tools/pivot.rs - Pivot point modes for transform tools
+//
+// Computes where rotate/scale are centred from the current Selection and
+// BlockotGeometry. Most modes give one pivot for the whole selection;
+// individual origins splits the selection into groups with their own pivot.
+// Pure Rust - no Godot types except Vector3 (math type).
+
+use std::collections::HashMap;
+
+use godot::prelude::Vector3;
+
+use crate::geometry::BlockotGeometry;
+use crate::selection::Selection;
+
+/// Where transform tools put their pivot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotMode {
+    /// Average position of the selected vertices
+    #[default]
+    Median,
+    /// Centre of the selected vertices' axis-aligned bounding box
+    BoundingBoxCenter,
+    /// The 3D cursor
+    Cursor,
+    /// Each selected face region around its own centre
+    IndividualOrigins,
+    /// The node's origin
+    NodeOrigin,
+}
+
+impl PivotMode {
+    /// The next mode, for cycling through them with a hotkey.
+    pub fn next(self) -> Self {
+        match self {
+            PivotMode::Median => PivotMode::BoundingBoxCenter,
+            PivotMode::BoundingBoxCenter => PivotMode::Cursor,
+            PivotMode::Cursor => PivotMode::IndividualOrigins,
+            PivotMode::IndividualOrigins => PivotMode::NodeOrigin,
+            PivotMode::NodeOrigin => PivotMode::Median,
+        }
+    }
+}
+
+/// Vertices that transform together around one pivot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotGroup {
+    /// Vertex indices in the group (sorted)
+    pub indices: Vec<usize>,
+    /// Pivot in the geometry's local space
+    pub pivot: Vector3,
+}
+
+/// Split the selection into pivot groups for the given mode.
+///
+/// `cursor` is the 3D cursor in the geometry's local space. Out-of-range
+/// vertex indices are ignored; an empty selection gives no groups.
+///
+/// With `IndividualOrigins`, selected faces that share vertices form one
+/// region pivoting around its median (so shared vertices are never pulled two
+/// ways). Selected vertices outside any selected face pivot on themselves.
+pub fn pivot_groups(
+    mode: PivotMode,
+    selection: &Selection,
+    geo: &BlockotGeometry,
+    cursor: Vector3,
+) -> Vec<PivotGroup> {
+    let mut indices: Vec<usize> = selection
+        .vertex_indices
+        .iter()
+        .copied()
+        .filter(|&i| i < geo.vertices.len())
+        .collect();
+    if indices.is_empty() {
+        return Vec::new();
+    }
+    indices.sort_unstable();
+
+    let pivot = match mode {
+        PivotMode::Median => median(geo, &indices),
+        PivotMode::BoundingBoxCenter => bounding_box_center(geo, &indices),
+        PivotMode::Cursor => cursor,
+        PivotMode::NodeOrigin => Vector3::ZERO,
+        PivotMode::IndividualOrigins => return individual_groups(selection, geo, &indices),
+    };
+    vec![PivotGroup { indices, pivot }]
+}
+
+/// Average position of the given vertices (which must be non-empty and in range).
+pub fn median(geo: &BlockotGeometry, indices: &[usize]) -> Vector3 {
+    let sum = indices
+        .iter()
+        .fold(Vector3::ZERO, |acc, &i| acc + geo.vertices[i]);
+    sum / indices.len() as f32
+}
+
+/// Centre of the bounding box of the given vertices (which must be non-empty and in range).
+pub fn bounding_box_center(geo: &BlockotGeometry, indices: &[usize]) -> Vector3 {
+    let first = geo.vertices[indices[0]];
+    let (min, max) = indices.iter().fold((first, first), |(min, max), &i| {
+        let v = geo.vertices[i];
+        (
+            Vector3::new(min.x.min(v.x), min.y.min(v.y), min.z.min(v.z)),
+            Vector3::new(max.x.max(v.x), max.y.max(v.y), max.z.max(v.z)),
+        )
+    });
+    (min + max) / 2.0
+}
+
+/// Group selected vertices by connected selected-face regions.
+fn individual_groups(
+    selection: &Selection,
+    geo: &BlockotGeometry,
+    indices: &[usize],
+) -> Vec<PivotGroup> {
+    // Union-find over selected vertices, joined through selected faces
+    let slot: HashMap<usize, usize> = indices.iter().enumerate().map(|(s, &v)| (v, s)).collect();
+    let mut parent: Vec<usize> = (0..indices.len()).collect();
+
+    for &face_idx in &selection.selected_faces {
+        let Some(face) = geo.faces.get(face_idx) else {
+            continue;
+        };
+        let slots: Vec<usize> = face
+            .vertex_indices
+            .iter()
+            .filter_map(|v| slot.get(v).copied())
+            .collect();
+        for pair in slots.windows(2) {
+            let (a, b) = (
+                find_root(&mut parent, pair[0]),
+                find_root(&mut parent, pair[1]),
+            );
+            parent[a] = b;
+        }
+    }
+
+    // Collect groups in order of their first (lowest) vertex
+    let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for (s, &v) in indices.iter().enumerate() {
+        let root = find_root(&mut parent, s);
+        let group = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(v);
+    }
+
+    groups
+        .into_iter()
+        .map(|indices| PivotGroup {
+            pivot: median(geo, &indices),
+            indices,
+        })
+        .collect()
+}
+
+/// Union-find root lookup with path halving.
+fn find_root(parent: &mut [usize], mut s: usize) -> usize {
+    while parent[s] != s {
+        parent[s] = parent[parent[s]];
+        s = parent[s];
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::{SelectOp, SelectionMode};
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    fn select_vertices(indices: &[usize]) -> Selection {
+        let mut sel = Selection::new(SelectionMode::Vertex);
+        sel.pick_vertices(indices, SelectOp::Replace);
+        sel
+    }
+
+    #[test]
+    fn test_median_pivot() {
+        let cube = unit_cube();
+        let sel = select_vertices(&[0, 1, 4]);
+
+        let groups = pivot_groups(PivotMode::Median, &sel, &cube, Vector3::ZERO);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].indices, vec![0, 1, 4]);
+        let expected = Vector3::new(-0.5 / 3.0, -0.5 / 3.0, -0.5);
+        assert!((groups[0].pivot - expected).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_bounding_box_center_differs_from_median() {
+        let cube = unit_cube();
+        let sel = select_vertices(&[0, 1, 4]);
+
+        let groups = pivot_groups(PivotMode::BoundingBoxCenter, &sel, &cube, Vector3::ZERO);
+
+        assert_eq!(groups[0].pivot, Vector3::new(0.0, 0.0, -0.5));
+    }
+
+    #[test]
+    fn test_cursor_and_node_origin() {
+        let cube = unit_cube();
+        let sel = select_vertices(&[6]);
+        let cursor = Vector3::new(3.0, 2.0, 1.0);
+
+        let at_cursor = pivot_groups(PivotMode::Cursor, &sel, &cube, cursor);
+        let at_origin = pivot_groups(PivotMode::NodeOrigin, &sel, &cube, cursor);
+
+        assert_eq!(at_cursor[0].pivot, cursor);
+        assert_eq!(at_origin[0].pivot, Vector3::ZERO);
+    }
+
+    #[test]
+    fn test_individual_origins_per_face_region() {
+        // 3x1 strip: faces 0 and 2 are not connected, each gets its own pivot
+        let grid = quad_grid(3, 1);
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_face(0, &grid, SelectOp::Replace);
+        sel.pick_face(2, &grid, SelectOp::Add);
+
+        let groups = pivot_groups(PivotMode::IndividualOrigins, &sel, &grid, Vector3::ZERO);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].indices, vec![0, 1, 4, 5]);
+        assert_eq!(groups[0].pivot, Vector3::new(0.5, 0.0, 0.5));
+        assert_eq!(groups[1].indices, vec![2, 3, 6, 7]);
+        assert_eq!(groups[1].pivot, Vector3::new(2.5, 0.0, 0.5));
+    }
+
+    #[test]
+    fn test_individual_origins_connected_faces_share_pivot() {
+        let grid = quad_grid(3, 1);
+        let mut sel = Selection::new(SelectionMode::Face);
+        sel.pick_face(0, &grid, SelectOp::Replace);
+        sel.pick_face(1, &grid, SelectOp::Add);
+
+        let groups = pivot_groups(PivotMode::IndividualOrigins, &sel, &grid, Vector3::ZERO);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].indices.len(), 6);
+    }
+
+    #[test]
+    fn test_individual_origins_loose_vertices_pivot_on_themselves() {
+        let cube = unit_cube();
+        let sel = select_vertices(&[0, 6]);
+
+        let groups = pivot_groups(PivotMode::IndividualOrigins, &sel, &cube, Vector3::ZERO);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].indices, vec![6]);
+        assert_eq!(groups[1].pivot, cube.vertices[6]);
+    }
+
+    #[test]
+    fn test_empty_selection_has_no_groups() {
+        let cube = unit_cube();
+        let sel = Selection::default();
+        assert!(pivot_groups(PivotMode::Median, &sel, &cube, Vector3::ZERO).is_empty());
+    }
+
+    #[test]
+    fn test_pivot_mode_cycle() {
+        let mut mode = PivotMode::default();
+        for _ in 0..5 {
+            mode = mode.next();
+        }
+        assert_eq!(mode, PivotMode::Median);
+    }
+}
@@ -0,0 +1,510 @@
+// tools/proximity.rs - Snapping dragged vertices onto nearby geometry
+//
+// Collects snap candidates (vertices, edges and faces of the edited node and
+// other nodes, in world space) into a SnapIndex. Candidates are projected to
+// the screen once per camera position and kept in spatial hashes (edges in
+// every cell their segment crosses), so every mouse move only looks at the
+// vertices and edges in the cells around the cursor.
+// Targets are picked by priority: vertex over edge over face. Faces only
+// count when seen from the front and near the dragged point, so a face being
+// moved is not pulled through its own mesh onto the faces behind it.
+// Pure Rust - no Godot types except math types.
+
+use std::collections::{HashMap, HashSet};
+
+use godot::prelude::{Vector2, Vector3};
+
+use crate::geometry::{BlockotGeometry, Edge, Face, MeshTopology};
+use crate::selection::raycast_faces;
+
+/// Distance from the mouse within which vertices and edges are snapped to, in pixels.
+pub const SNAP_DISTANCE_PX: f32 = 12.0;
+/// Distance from the dragged point within which faces are snapped to, in
+/// pixels at the dragged point's depth.
+pub const FACE_SNAP_DISTANCE_PX: f32 = 48.0;
+/// Edges crossing more grid cells than this on screen are checked on every
+/// query instead of being hashed into each cell.
+const MAX_EDGE_CELLS: i64 = 256;
+
+/// What kind of element a snap target lies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapKind {
+    /// Exactly on a vertex
+    Vertex,
+    /// The closest point on an edge
+    Edge,
+    /// On a face, under the mouse
+    Face,
+}
+
+/// Where a dragged vertex should snap to, in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapTarget {
+    pub kind: SnapKind,
+    pub position: Vector3,
+}
+
+/// Snap candidates from one or more meshes, merged in world space.
+#[derive(Debug, Clone, Default)]
+pub struct SnapIndex {
+    /// World-space vertices of all meshes; faces touching excluded vertices are left out
+    geometry: BlockotGeometry,
+    /// Vertices that cannot be snapped to (being moved)
+    excluded: HashSet<usize>,
+    /// Edges of the kept faces
+    edges: Vec<Edge>,
+    /// Screen position of every vertex (None = excluded or behind the camera)
+    screen_positions: Vec<Option<Vector2>>,
+    /// Spatial hash over `screen_positions`
+    grid: ScreenGrid,
+    /// Spatial hash over the screen segments of `edges`, by edge index
+    edge_grid: ScreenGrid,
+    /// Edges too long on screen for `edge_grid`
+    long_edges: Vec<usize>,
+}
+
+impl SnapIndex {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a mesh given by its world-space vertices and faces.
+    ///
+    /// `excluded` vertices (e.g. the ones being dragged) are not snapped to,
+    /// and neither are the edges and faces that use them.
+    pub fn add_mesh(&mut self, vertices: &[Vector3], faces: &[Face], excluded: &HashSet<usize>) {
+        let offset = self.geometry.vertices.len();
+        self.geometry.vertices.extend_from_slice(vertices);
+        self.excluded.extend(
+            excluded
+                .iter()
+                .filter(|&&v| v < vertices.len())
+                .map(|v| v + offset),
+        );
+
+        for face in faces {
+            let valid = face.vertex_indices.iter().all(|&v| v < vertices.len());
+            let moving = face.vertex_indices.iter().any(|v| excluded.contains(v));
+            if valid && !moving {
+                let indices = face.vertex_indices.iter().map(|v| v + offset).collect();
+                self.geometry.faces.push(Face::new(indices));
+            }
+        }
+
+        self.edges = MeshTopology::build(&self.geometry).edges().to_vec();
+        self.screen_positions.clear();
+        self.grid = ScreenGrid::default();
+        self.edge_grid = ScreenGrid::default();
+        self.long_edges.clear();
+    }
+
+    /// Returns true if nothing can be snapped to.
+    pub fn is_empty(&self) -> bool {
+        self.geometry.vertices.len() == self.excluded.len()
+    }
+
+    /// Project the candidates to the screen. Call again whenever the camera moves.
+    ///
+    /// `project` maps a world position to screen space, or None if it is
+    /// behind the camera.
+    pub fn project(&mut self, project: impl Fn(Vector3) -> Option<Vector2>) {
+        self.screen_positions = self
+            .geometry
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                if self.excluded.contains(&i) {
+                    None
+                } else {
+                    project(v)
+                }
+            })
+            .collect();
+        self.grid = ScreenGrid::new(&self.screen_positions, SNAP_DISTANCE_PX);
+
+        self.edge_grid = ScreenGrid::empty(SNAP_DISTANCE_PX);
+        self.long_edges.clear();
+        for (i, &(a, b)) in self.edges.iter().enumerate() {
+            let (Some(Some(pa)), Some(Some(pb))) =
+                (self.screen_positions.get(a), self.screen_positions.get(b))
+            else {
+                continue;
+            };
+            if !self.edge_grid.insert_segment(i, *pa, *pb) {
+                self.long_edges.push(i);
+            }
+        }
+    }
+
+    /// Find the snap target for the mouse, by priority vertex > edge > face.
+    ///
+    /// Vertices and edges must be within `threshold` pixels of the mouse; faces
+    /// are hit by the world-space camera ray `ray_origin + ray_direction * t`
+    /// and count when the nearest hit faces the camera and lies within
+    /// `face_range` of `dragged`, the unsnapped position of the dragged point.
+    /// Requires `project` to have been called.
+    pub fn find_target(
+        &self,
+        mouse: Vector2,
+        ray_origin: Vector3,
+        ray_direction: Vector3,
+        threshold: f32,
+        dragged: Vector3,
+        face_range: f32,
+    ) -> Option<SnapTarget> {
+        self.nearest_vertex(mouse, threshold)
+            .map(|position| SnapTarget {
+                kind: SnapKind::Vertex,
+                position,
+            })
+            .or_else(|| {
+                self.nearest_edge_point(mouse, threshold)
+                    .map(|position| SnapTarget {
+                        kind: SnapKind::Edge,
+                        position,
+                    })
+            })
+            .or_else(|| {
+                self.face_point(ray_origin, ray_direction, dragged, face_range)
+                    .map(|position| SnapTarget {
+                        kind: SnapKind::Face,
+                        position,
+                    })
+            })
+    }
+
+    /// Where the camera ray first hits a candidate face, if that face is seen
+    /// from the front and the hit lies within `range` of `dragged`.
+    fn face_point(
+        &self,
+        ray_origin: Vector3,
+        ray_direction: Vector3,
+        dragged: Vector3,
+        range: f32,
+    ) -> Option<Vector3> {
+        let hit = raycast_faces(&self.geometry, ray_origin, ray_direction)?;
+        let normal = self.geometry.faces[hit.face].normal(&self.geometry.vertices);
+        let position = ray_origin + ray_direction * hit.distance;
+        (normal.dot(ray_direction) < 0.0 && position.distance_to(dragged) <= range)
+            .then_some(position)
+    }
+
+    /// The candidate vertex closest to the mouse on screen, within `threshold`.
+    fn nearest_vertex(&self, mouse: Vector2, threshold: f32) -> Option<Vector3> {
+        let mut best: Option<(usize, f32)> = None;
+        for i in self.grid.query(mouse, threshold) {
+            let Some(Some(screen)) = self.screen_positions.get(i) else {
+                continue;
+            };
+            let dist_sq = screen.distance_squared_to(mouse);
+            if dist_sq <= threshold * threshold && best.is_none_or(|(_, d)| dist_sq < d) {
+                best = Some((i, dist_sq));
+            }
+        }
+        best.map(|(i, _)| self.geometry.vertices[i])
+    }
+
+    /// The point on a candidate edge closest to the mouse on screen, within `threshold`.
+    ///
+    /// The world position interpolates the edge linearly by the screen-space
+    /// parameter, which is exact for orthographic views and close for short edges.
+    fn nearest_edge_point(&self, mouse: Vector2, threshold: f32) -> Option<Vector3> {
+        let mut best: Option<(Vector3, f32)> = None;
+        let candidates = self.edge_grid.query(mouse, threshold);
+        for i in candidates.chain(self.long_edges.iter().copied()) {
+            let (a, b) = self.edges[i];
+            let (Some(Some(pa)), Some(Some(pb))) =
+                (self.screen_positions.get(a), self.screen_positions.get(b))
+            else {
+                continue;
+            };
+            let ab = *pb - *pa;
+            let length_sq = ab.length_squared();
+            if length_sq <= 0.0 {
+                continue;
+            }
+            let t = ((mouse - *pa).dot(ab) / length_sq).clamp(0.0, 1.0);
+            let dist_sq = (*pa + ab * t).distance_squared_to(mouse);
+            if dist_sq <= threshold * threshold && best.is_none_or(|(_, d)| dist_sq < d) {
+                let (wa, wb) = (self.geometry.vertices[a], self.geometry.vertices[b]);
+                best = Some((wa.lerp(wb, t), dist_sq));
+            }
+        }
+        best.map(|(position, _)| position)
+    }
+}
+
+/// Uniform grid over screen positions for fast neighbourhood queries.
+#[derive(Debug, Clone, Default)]
+struct ScreenGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl ScreenGrid {
+    fn empty(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn new(positions: &[Option<Vector2>], cell_size: f32) -> Self {
+        let mut grid = Self::empty(cell_size);
+        for (i, pos) in positions.iter().enumerate() {
+            if let Some(pos) = pos {
+                grid.cells.entry(grid.cell(*pos)).or_default().push(i);
+            }
+        }
+        grid
+    }
+
+    fn cell(&self, pos: Vector2) -> (i32, i32) {
+        (
+            (pos.x / self.cell_size).floor() as i32,
+            (pos.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Add `index` to every cell the segment `a`-`b` crosses, column by
+    /// column. Returns false, adding nothing, if it crosses more than
+    /// `MAX_EDGE_CELLS`.
+    fn insert_segment(&mut self, index: usize, a: Vector2, b: Vector2) -> bool {
+        let (a, b) = if a.x <= b.x { (a, b) } else { (b, a) };
+        let (start, end) = (self.cell(a), self.cell(b));
+        let span =
+            i64::from(end.0) - i64::from(start.0) + (i64::from(end.1) - i64::from(start.1)).abs();
+        if self.cell_size <= 0.0 || span >= MAX_EDGE_CELLS {
+            return false;
+        }
+        let y_at = |x: f32| a.y + (b.y - a.y) * ((x - a.x) / (b.x - a.x));
+        for column in start.0..=end.0 {
+            // The part of the segment inside this column
+            let left = (column as f32 * self.cell_size).max(a.x);
+            let right = ((column + 1) as f32 * self.cell_size).min(b.x);
+            let (y0, y1) = if b.x > a.x {
+                (y_at(left), y_at(right))
+            } else {
+                (a.y, b.y)
+            };
+            let low = self.cell(Vector2::new(left, y0.min(y1))).1;
+            let high = self.cell(Vector2::new(left, y0.max(y1))).1;
+            for row in low..=high {
+                self.cells.entry((column, row)).or_default().push(index);
+            }
+        }
+        true
+    }
+
+    /// Indices in the cells overlapping the square of half-size `radius` around `center`.
+    fn query(&self, center: Vector2, radius: f32) -> impl Iterator<Item = usize> + '_ {
+        let (min, max) = if self.cell_size > 0.0 {
+            (
+                self.cell(center - Vector2::new(radius, radius)),
+                self.cell(center + Vector2::new(radius, radius)),
+            )
+        } else {
+            ((0, 0), (-1, -1))
+        };
+        (min.0..=max.0)
+            .flat_map(move |x| (min.1..=max.1).map(move |y| (x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quad_grid, unit_cube};
+
+    /// Top-down orthographic projection: world (x, z) * 100 px.
+    fn top_down(v: Vector3) -> Option<Vector2> {
+        Some(Vector2::new(v.x * 100.0, v.z * 100.0))
+    }
+
+    /// Ray straight down through the screen point.
+    fn ray_at(mouse: Vector2) -> (Vector3, Vector3) {
+        (
+            Vector3::new(mouse.x / 100.0, 10.0, mouse.y / 100.0),
+            Vector3::new(0.0, -1.0, 0.0),
+        )
+    }
+
+    fn grid_index(excluded: &[usize]) -> SnapIndex {
+        let grid = quad_grid(2, 2);
+        let mut index = SnapIndex::new();
+        index.add_mesh(
+            &grid.vertices,
+            &grid.faces,
+            &excluded.iter().copied().collect(),
+        );
+        index.project(top_down);
+        index
+    }
+
+    /// Dragging a point just above the grid, under the mouse.
+    fn find(index: &SnapIndex, mouse: Vector2) -> Option<SnapTarget> {
+        let (origin, direction) = ray_at(mouse);
+        let dragged = Vector3::new(origin.x, 0.2, origin.z);
+        index.find_target(mouse, origin, direction, SNAP_DISTANCE_PX, dragged, 0.5)
+    }
+
+    #[test]
+    fn test_vertex_has_priority() {
+        let index = grid_index(&[]);
+
+        // Near vertex (1, 1), which also lies on edges and faces
+        let target = find(&index, Vector2::new(105.0, 96.0)).unwrap();
+
+        assert_eq!(target.kind, SnapKind::Vertex);
+        assert_eq!(target.position, Vector3::new(1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_edge_over_face() {
+        let index = grid_index(&[]);
+
+        // Halfway along the edge (0, 0)-(1, 0), slightly off it
+        let target = find(&index, Vector2::new(50.0, 5.0)).unwrap();
+
+        assert_eq!(target.kind, SnapKind::Edge);
+        assert!((target.position - Vector3::new(0.5, 0.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_edge_is_hashed_into_every_cell_it_crosses() {
+        let mut grid = ScreenGrid::empty(10.0);
+        assert!(grid.insert_segment(0, Vector2::new(35.0, 25.0), Vector2::new(5.0, 5.0)));
+
+        for t in 0..=30 {
+            let point = Vector2::new(5.0 + t as f32, 5.0 + t as f32 * 2.0 / 3.0);
+            assert!(grid.query(point, 0.0).any(|i| i == 0));
+        }
+        assert!(!grid.query(Vector2::new(5.0, 25.0), 0.0).any(|i| i == 0));
+        assert!(!grid.insert_segment(1, Vector2::ZERO, Vector2::new(1e5, 0.0)));
+    }
+
+    #[test]
+    fn test_edge_longer_than_the_grid() {
+        let geo = quad_grid(1, 1);
+        let mut index = SnapIndex::new();
+        index.add_mesh(&geo.vertices, &geo.faces, &HashSet::new());
+        // 100000 px per unit: every edge spans thousands of cells
+        index.project(|v| Some(Vector2::new(v.x * 1e5, v.z * 1e5)));
+
+        let (origin, direction) = (Vector3::new(0.5, 10.0, 0.0), Vector3::DOWN);
+        let target = index
+            .find_target(Vector2::new(5e4, 5.0), origin, direction, 12.0, origin, 0.0)
+            .unwrap();
+
+        assert_eq!(target.kind, SnapKind::Edge);
+        assert!((target.position - Vector3::new(0.5, 0.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_face_when_nothing_close() {
+        let index = grid_index(&[]);
+
+        let target = find(&index, Vector2::new(50.0, 50.0)).unwrap();
+
+        assert_eq!(target.kind, SnapKind::Face);
+        assert!((target.position - Vector3::new(0.5, 0.0, 0.5)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_nothing_outside_geometry() {
+        let index = grid_index(&[]);
+        assert_eq!(find(&index, Vector2::new(500.0, 500.0)), None);
+    }
+
+    #[test]
+    fn test_excluded_vertex_and_its_faces_are_skipped() {
+        // Centre vertex (1, 1) has index 4 and touches all four faces
+        let index = grid_index(&[4]);
+
+        let target = find(&index, Vector2::new(100.0, 100.0));
+        assert_eq!(target, None);
+
+        // Its neighbours are still candidates
+        let target = find(&index, Vector2::new(198.0, 100.0)).unwrap();
+        assert_eq!(target.kind, SnapKind::Vertex);
+        assert_eq!(target.position, Vector3::new(2.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_multiple_meshes() {
+        let grid = quad_grid(1, 1);
+        let moved: Vec<Vector3> = grid
+            .vertices
+            .iter()
+            .map(|v| *v + Vector3::new(5.0, 0.0, 0.0))
+            .collect();
+
+        let mut index = SnapIndex::new();
+        index.add_mesh(&grid.vertices, &grid.faces, &[0, 1, 2, 3].into());
+        index.add_mesh(&moved, &grid.faces, &HashSet::new());
+        index.project(top_down);
+
+        assert!(!index.is_empty());
+        let target = find(&index, Vector2::new(600.0, 0.0)).unwrap();
+        assert_eq!(target.kind, SnapKind::Vertex);
+        assert_eq!(target.position, Vector3::new(6.0, 0.0, 0.0));
+        assert_eq!(find(&index, Vector2::new(0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_behind_camera_is_not_snapped() {
+        let grid = quad_grid(1, 1);
+        let mut index = SnapIndex::new();
+        index.add_mesh(&grid.vertices, &grid.faces, &HashSet::new());
+        index.project(|_| None);
+
+        let mouse = Vector2::new(0.0, 0.0);
+        let origin = Vector3::new(5.0, 10.0, 5.0);
+        let target = index.find_target(mouse, origin, Vector3::UP, 12.0, origin, 100.0);
+        assert_eq!(target, None);
+    }
+
+    #[test]
+    fn test_face_far_from_dragged_point_is_not_snapped() {
+        let index = grid_index(&[]);
+        let mouse = Vector2::new(50.0, 50.0);
+        let (origin, direction) = ray_at(mouse);
+
+        // The grid lies 5 below the dragged point
+        let dragged = Vector3::new(0.5, 5.0, 0.5);
+        let target = index.find_target(mouse, origin, direction, SNAP_DISTANCE_PX, dragged, 1.0);
+        assert_eq!(target, None);
+    }
+
+    #[test]
+    fn test_moved_face_does_not_snap_through_own_mesh() {
+        let cube = unit_cube();
+
+        // Moving the top face seen from above: only the bottom face is left,
+        // and the ray reaches it from inside
+        let mut index = SnapIndex::new();
+        index.add_mesh(&cube.vertices, &cube.faces, &[4, 5, 6, 7].into());
+        index.project(top_down);
+        for mouse in [Vector2::new(0.0, 0.0), Vector2::new(20.0, -25.0)] {
+            let (origin, direction) = ray_at(mouse);
+            let dragged = Vector3::new(origin.x, 0.5, origin.z);
+            let target = index.find_target(mouse, origin, direction, 12.0, dragged, 100.0);
+            assert_eq!(target, None);
+        }
+
+        // Moving the front face seen from the front: the back face is left
+        let mut index = SnapIndex::new();
+        index.add_mesh(&cube.vertices, &cube.faces, &[0, 1, 5, 4].into());
+        index.project(|v| Some(Vector2::new(v.x * 100.0, -v.y * 100.0)));
+        let mouse = Vector2::new(10.0, -10.0);
+        let origin = Vector3::new(0.1, 0.1, -10.0);
+        let dragged = Vector3::new(0.1, 0.1, -0.5);
+        let target = index.find_target(mouse, origin, Vector3::BACK, 12.0, dragged, 100.0);
+        assert_eq!(target, None);
+    }
+}
@@ -0,0 +1,158 @@
+// tools/snap.rs - Grid and increment snapping for transform tools
+//
+// Blockouts live on a metric grid: moves put the pivot on the world grid,
+// rotations snap to an angle increment and scale factors to a step. Moves
+// can also snap onto nearby geometry (see proximity.rs), which wins over
+// the grid when a target is close enough.
+// Snapping is on by default; Ctrl forces it on when disabled and holding
+// Shift bypasses it. Pure Rust - no Godot types except math types.
+
+use godot::prelude::{Basis, Vector3};
+
+use super::modal::AxisConstraint;
+
+/// Default grid size in metres.
+pub const DEFAULT_GRID_SIZE: f32 = 1.0;
+/// Default rotation increment in degrees.
+pub const DEFAULT_ROTATION_STEP_DEGREES: f32 = 15.0;
+/// Default scale factor increment.
+pub const DEFAULT_SCALE_STEP: f32 = 0.1;
+
+/// Snapping configuration shared by the transform tools.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapSettings {
+    /// Snap without holding a modifier
+    pub enabled: bool,
+    /// World grid spacing for moves, in metres
+    pub grid_size: f32,
+    /// Rotation increment, in degrees
+    pub rotation_step_degrees: f32,
+    /// Scale factor increment
+    pub scale_step: f32,
+    /// Snap moves onto nearby vertices, edges and faces
+    pub proximity: bool,
+}
+
+impl Default for SnapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            grid_size: DEFAULT_GRID_SIZE,
+            rotation_step_degrees: DEFAULT_ROTATION_STEP_DEGREES,
+            scale_step: DEFAULT_SCALE_STEP,
+            proximity: true,
+        }
+    }
+}
+
+impl SnapSettings {
+    /// Whether snapping applies with the given modifiers held:
+    /// Ctrl forces it on, Shift bypasses it.
+    pub fn is_active(&self, ctrl: bool, shift: bool) -> bool {
+        (self.enabled || ctrl) && !shift
+    }
+}
+
+/// Round `value` to the nearest multiple of `step`. Non-positive steps leave it unchanged.
+pub fn snap_to_step(value: f32, step: f32) -> f32 {
+    if step <= 0.0 {
+        return value;
+    }
+    (value / step).round() * step
+}
+
+/// Round each component of `position` to the nearest grid point.
+pub fn snap_to_grid(position: Vector3, grid_size: f32) -> Vector3 {
+    Vector3::new(
+        snap_to_step(position.x, grid_size),
+        snap_to_step(position.y, grid_size),
+        snap_to_step(position.z, grid_size),
+    )
+}
+
+/// Snap a move so the pivot lands on the grid.
+///
+/// `anchor` is the pivot before the move and `offset` the constrained move,
+/// both in world space. The snapped offset keeps the constraint: only the
+/// free axes (in `orientation`) move, so a locked axis never drifts.
+pub fn snap_translation(
+    anchor: Vector3,
+    offset: Vector3,
+    grid_size: f32,
+    constraint: AxisConstraint,
+    orientation: Basis,
+) -> Vector3 {
+    let snapped = snap_to_grid(anchor + offset, grid_size) - anchor;
+    constraint.apply_in(snapped, orientation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::modal::Axis;
+
+    #[test]
+    fn test_default_settings() {
+        let settings = SnapSettings::default();
+        assert!(settings.enabled);
+        assert_eq!(settings.grid_size, 1.0);
+        assert_eq!(settings.rotation_step_degrees, 15.0);
+        assert!(settings.proximity);
+    }
+
+    #[test]
+    fn test_modifiers() {
+        let mut settings = SnapSettings::default();
+        assert!(settings.is_active(false, false));
+        assert!(!settings.is_active(false, true));
+        assert!(!settings.is_active(true, true));
+
+        settings.enabled = false;
+        assert!(!settings.is_active(false, false));
+        assert!(settings.is_active(true, false));
+    }
+
+    #[test]
+    fn test_snap_to_step() {
+        assert_eq!(snap_to_step(1.26, 0.5), 1.5);
+        assert_eq!(snap_to_step(-0.7, 1.0), -1.0);
+        assert_eq!(snap_to_step(0.3, 0.0), 0.3);
+        assert_eq!(snap_to_step(0.3, -1.0), 0.3);
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let snapped = snap_to_grid(Vector3::new(0.4, 1.6, -2.51), 1.0);
+        assert_eq!(snapped, Vector3::new(0.0, 2.0, -3.0));
+
+        let fine = snap_to_grid(Vector3::new(0.3, 0.1, 0.0), 0.25);
+        assert_eq!(fine, Vector3::new(0.25, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_snap_translation_puts_anchor_on_grid() {
+        let anchor = Vector3::new(0.2, 0.0, 0.7);
+        let offset = Vector3::new(1.1, 0.0, 0.0);
+
+        let snapped = snap_translation(anchor, offset, 1.0, AxisConstraint::Free, Basis::IDENTITY);
+
+        assert_eq!(anchor + snapped, Vector3::new(1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_snap_translation_keeps_axis_lock() {
+        let anchor = Vector3::new(0.2, 0.3, 0.7);
+        let offset = Vector3::new(1.1, 0.0, 0.0);
+
+        let snapped = snap_translation(
+            anchor,
+            offset,
+            1.0,
+            AxisConstraint::Axis(Axis::X),
+            Basis::IDENTITY,
+        );
+
+        // Only X snaps; Y and Z stay where they were
+        assert!((snapped - Vector3::new(0.8, 0.0, 0.0)).length() < 1e-6);
+    }
+}
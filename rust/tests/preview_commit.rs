@@ -0,0 +1,60 @@
+// tests/preview_commit.rs - Integration tests for the preview → commit/cancel flow
+//
+// Mirrors what the modal move tool does: preview a drag on the geometry,
+// then either cancel it or commit it as a MoveVertices command.
+// Pure Rust - no Godot runtime required.
+
+use blockot::test_utils::{geometries_equal, unit_cube};
+use blockot::tools::commands::MoveVertices;
+use blockot::tools::modal::{Axis, AxisConstraint, MoveTool};
+use blockot::tools::Command;
+use godot::prelude::Vector3;
+
+/// Test: preview then cancel leaves the committed geometry and render untouched
+#[test]
+fn test_preview_cancel_restores_original() {
+    let mut geo = unit_cube();
+    let original = geo.clone();
+
+    let mut tool = MoveTool::new();
+    geo.begin_preview(vec![4, 5, 6, 7]);
+    for step in 1..=3 {
+        tool.set_drag_offset(Vector3::new(0.0, step as f32 * 0.5, 0.0));
+        let offset = tool.offset();
+        geo.update_preview(|_, v| v + offset);
+    }
+    geo.cancel_preview();
+
+    assert!(geometries_equal(&geo, &original));
+    assert_eq!(geo.render_vertices().as_ref(), original.vertices.as_slice());
+}
+
+/// Test: committing the previewed offset matches the preview, and undo restores
+#[test]
+fn test_preview_commit_matches_preview_and_undoes() {
+    let mut geo = unit_cube();
+    let original = geo.clone();
+
+    let mut tool = MoveTool::new();
+    tool.constraint = AxisConstraint::Axis(Axis::X);
+    tool.set_drag_offset(Vector3::new(1.5, 0.7, -0.2));
+
+    let indices = vec![1, 2, 5, 6];
+    let offset = tool.offset();
+    geo.begin_preview(indices.clone());
+    geo.update_preview(|_, v| v + offset);
+    let previewed = geo.render_vertices().into_owned();
+
+    geo.cancel_preview();
+    let cmd = MoveVertices::new(indices, offset).unwrap();
+    cmd.execute(&mut geo);
+
+    assert_eq!(geo.vertices, previewed);
+    assert_eq!(
+        geo.vertices[1],
+        original.vertices[1] + Vector3::new(1.5, 0.0, 0.0)
+    );
+
+    cmd.undo(&mut geo);
+    assert!(geometries_equal(&geo, &original));
+}
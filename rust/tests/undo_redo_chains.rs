@@ -0,0 +1,117 @@
+// tests/undo_redo_chains.rs - Integration tests for multi-command undo/redo
+//
+// Exercises heterogeneous command stacks stored as Box<dyn Command>,
+// the way the editor's undo registry holds them.
+// Pure Rust - no Godot runtime required.
+
+use blockot::test_utils::{geometries_equal, unit_cube};
+use blockot::tools::commands::{CompositeCommand, MoveVertices, RotateVertices};
+use blockot::tools::Command;
+use godot::prelude::{Basis, Vector3};
+
+/// Test: execute(cmd1) → execute(cmd2) → undo() → undo() restores the original
+#[test]
+fn test_two_command_undo_chain() {
+    let mut geo = unit_cube();
+    let original = geo.clone();
+
+    let stack: Vec<Box<dyn Command>> = vec![
+        Box::new(MoveVertices::new(vec![0, 4], Vector3::new(0.0, 0.0, -1.0)).unwrap()),
+        Box::new(MoveVertices::new(vec![4, 5], Vector3::new(0.0, 2.0, 0.0)).unwrap()),
+    ];
+
+    for cmd in &stack {
+        cmd.execute(&mut geo);
+    }
+    for cmd in stack.iter().rev() {
+        cmd.undo(&mut geo);
+    }
+
+    assert!(geometries_equal(&geo, &original));
+}
+
+/// Test: undo then redo the whole chain reproduces the executed result
+#[test]
+fn test_undo_redo_chain_is_repeatable() {
+    let mut geo = unit_cube();
+    let original = geo.clone();
+
+    let stack: Vec<Box<dyn Command>> = vec![
+        Box::new(MoveVertices::new(vec![1], Vector3::new(0.5, 0.0, 0.0)).unwrap()),
+        Box::new(
+            CompositeCommand::new(
+                "Lift Top",
+                vec![
+                    Box::new(MoveVertices::new(vec![4, 5], Vector3::UP).unwrap()),
+                    Box::new(MoveVertices::new(vec![6, 7], Vector3::UP).unwrap()),
+                ],
+            )
+            .unwrap(),
+        ),
+    ];
+
+    for cmd in &stack {
+        cmd.execute(&mut geo);
+    }
+    let executed = geo.clone();
+
+    for _ in 0..3 {
+        for cmd in stack.iter().rev() {
+            cmd.undo(&mut geo);
+        }
+        assert!(geometries_equal(&geo, &original));
+
+        for cmd in &stack {
+            cmd.execute(&mut geo);
+        }
+        assert!(geometries_equal(&geo, &executed));
+    }
+}
+
+/// Test: cloned boxed commands behave identically to the originals
+#[test]
+fn test_cloned_stack_undoes_original_execution() {
+    let mut geo = unit_cube();
+    let original = geo.clone();
+
+    let stack: Vec<Box<dyn Command>> = vec![
+        Box::new(MoveVertices::new(vec![2, 3], Vector3::new(0.0, -1.0, 0.0)).unwrap()),
+        Box::new(MoveVertices::new(vec![3], Vector3::new(1.0, 0.0, 1.0)).unwrap()),
+    ];
+    let cloned = stack.clone();
+
+    for cmd in &stack {
+        cmd.execute(&mut geo);
+    }
+    for cmd in cloned.iter().rev() {
+        cmd.undo(&mut geo);
+    }
+
+    assert!(geometries_equal(&geo, &original));
+}
+
+/// Test: move → rotate → undo() → undo() restores the original exactly
+#[test]
+fn test_move_then_rotate_undo_chain() {
+    let mut geo = unit_cube();
+    let original = geo.clone();
+
+    let top = vec![4, 5, 6, 7];
+    let lift = MoveVertices::new(top.clone(), Vector3::UP).unwrap();
+    lift.execute(&mut geo);
+
+    // Rotate captures the lifted positions, so it must be built after the move
+    let twist = RotateVertices::new(
+        &geo,
+        top,
+        Vector3::new(0.0, 1.5, 0.0),
+        Basis::from_axis_angle(Vector3::UP, 0.3),
+    )
+    .unwrap();
+    twist.execute(&mut geo);
+
+    twist.undo(&mut geo);
+    lift.undo(&mut geo);
+
+    assert!(geometries_equal(&geo, &original));
+}